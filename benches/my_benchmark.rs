@@ -1,5 +1,4 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use rust_js_ts_gen;
 use rust_js_ts_gen::data;
 use rust_js_ts_gen::to_string;
 
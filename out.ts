@@ -5,18 +5,54 @@ import { Optional, Seq, Tuple, ListTuple, unit, bool, int8, int16, int32, int64,
 
 export class ArrayItem {
 
-constructor (public expr: Expr, public spread: bool) {
+constructor (public expr: Expr, public spread: bool, public commentList: Seq<Comment>) {
 }
 
 public serialize(serializer: Serializer): void {
   this.expr.serialize(serializer);
   serializer.serializeBool(this.spread);
+  Helpers.serializeVectorComment(this.commentList, serializer);
 }
 
 static deserialize(deserializer: Deserializer): ArrayItem {
   const expr = Expr.deserialize(deserializer);
   const spread = deserializer.deserializeBool();
-  return new ArrayItem(expr,spread);
+  const commentList = Helpers.deserializeVectorComment(deserializer);
+  return new ArrayItem(expr,spread,commentList);
+}
+
+}
+export class ArrayPattern {
+
+constructor (public itemList: Seq<Optional<ArrayPatternItem>>, public rest: Optional<str>) {
+}
+
+public serialize(serializer: Serializer): void {
+  Helpers.serializeVectorOptionArrayPatternItem(this.itemList, serializer);
+  Helpers.serializeOptionStr(this.rest, serializer);
+}
+
+static deserialize(deserializer: Deserializer): ArrayPattern {
+  const itemList = Helpers.deserializeVectorOptionArrayPatternItem(deserializer);
+  const rest = Helpers.deserializeOptionStr(deserializer);
+  return new ArrayPattern(itemList,rest);
+}
+
+}
+export class ArrayPatternItem {
+
+constructor (public pattern: BindingPattern, public defaultExpr: Optional<Expr>) {
+}
+
+public serialize(serializer: Serializer): void {
+  this.pattern.serialize(serializer);
+  Helpers.serializeOptionExpr(this.defaultExpr, serializer);
+}
+
+static deserialize(deserializer: Deserializer): ArrayPatternItem {
+  const pattern = BindingPattern.deserialize(deserializer);
+  const defaultExpr = Helpers.deserializeOptionExpr(deserializer);
+  return new ArrayPatternItem(pattern,defaultExpr);
 }
 
 }
@@ -37,13 +73,21 @@ static deserialize(deserializer: Deserializer): BinaryOperator {
     case 8: return BinaryOperatorVariantUnsignedRightShift.load(deserializer);
     case 9: return BinaryOperatorVariantLessThan.load(deserializer);
     case 10: return BinaryOperatorVariantLessThanOrEqual.load(deserializer);
-    case 11: return BinaryOperatorVariantEqual.load(deserializer);
-    case 12: return BinaryOperatorVariantNotEqual.load(deserializer);
-    case 13: return BinaryOperatorVariantBitwiseAnd.load(deserializer);
-    case 14: return BinaryOperatorVariantBitwiseXOr.load(deserializer);
-    case 15: return BinaryOperatorVariantBitwiseOr.load(deserializer);
-    case 16: return BinaryOperatorVariantLogicalAnd.load(deserializer);
-    case 17: return BinaryOperatorVariantLogicalOr.load(deserializer);
+    case 11: return BinaryOperatorVariantGreaterThan.load(deserializer);
+    case 12: return BinaryOperatorVariantGreaterThanOrEqual.load(deserializer);
+    case 13: return BinaryOperatorVariantIn.load(deserializer);
+    case 14: return BinaryOperatorVariantInstanceOf.load(deserializer);
+    case 15: return BinaryOperatorVariantEqual.load(deserializer);
+    case 16: return BinaryOperatorVariantNotEqual.load(deserializer);
+    case 17: return BinaryOperatorVariantLooseEqual.load(deserializer);
+    case 18: return BinaryOperatorVariantLooseNotEqual.load(deserializer);
+    case 19: return BinaryOperatorVariantBitwiseAnd.load(deserializer);
+    case 20: return BinaryOperatorVariantBitwiseXOr.load(deserializer);
+    case 21: return BinaryOperatorVariantBitwiseOr.load(deserializer);
+    case 22: return BinaryOperatorVariantLogicalAnd.load(deserializer);
+    case 23: return BinaryOperatorVariantLogicalOr.load(deserializer);
+    case 24: return BinaryOperatorVariantNullishCoalescing.load(deserializer);
+    case 25: return BinaryOperatorVariantComma.load(deserializer);
     default: throw new Error("Unknown variant index for BinaryOperator: " + index);
   }
 }
@@ -215,7 +259,7 @@ static load(deserializer: Deserializer): BinaryOperatorVariantLessThanOrEqual {
 
 }
 
-export class BinaryOperatorVariantEqual extends BinaryOperator {
+export class BinaryOperatorVariantGreaterThan extends BinaryOperator {
 constructor () {
   super();
 }
@@ -224,13 +268,13 @@ public serialize(serializer: Serializer): void {
   serializer.serializeVariantIndex(11);
 }
 
-static load(deserializer: Deserializer): BinaryOperatorVariantEqual {
-  return new BinaryOperatorVariantEqual();
+static load(deserializer: Deserializer): BinaryOperatorVariantGreaterThan {
+  return new BinaryOperatorVariantGreaterThan();
 }
 
 }
 
-export class BinaryOperatorVariantNotEqual extends BinaryOperator {
+export class BinaryOperatorVariantGreaterThanOrEqual extends BinaryOperator {
 constructor () {
   super();
 }
@@ -239,13 +283,13 @@ public serialize(serializer: Serializer): void {
   serializer.serializeVariantIndex(12);
 }
 
-static load(deserializer: Deserializer): BinaryOperatorVariantNotEqual {
-  return new BinaryOperatorVariantNotEqual();
+static load(deserializer: Deserializer): BinaryOperatorVariantGreaterThanOrEqual {
+  return new BinaryOperatorVariantGreaterThanOrEqual();
 }
 
 }
 
-export class BinaryOperatorVariantBitwiseAnd extends BinaryOperator {
+export class BinaryOperatorVariantIn extends BinaryOperator {
 constructor () {
   super();
 }
@@ -254,13 +298,13 @@ public serialize(serializer: Serializer): void {
   serializer.serializeVariantIndex(13);
 }
 
-static load(deserializer: Deserializer): BinaryOperatorVariantBitwiseAnd {
-  return new BinaryOperatorVariantBitwiseAnd();
+static load(deserializer: Deserializer): BinaryOperatorVariantIn {
+  return new BinaryOperatorVariantIn();
 }
 
 }
 
-export class BinaryOperatorVariantBitwiseXOr extends BinaryOperator {
+export class BinaryOperatorVariantInstanceOf extends BinaryOperator {
 constructor () {
   super();
 }
@@ -269,13 +313,13 @@ public serialize(serializer: Serializer): void {
   serializer.serializeVariantIndex(14);
 }
 
-static load(deserializer: Deserializer): BinaryOperatorVariantBitwiseXOr {
-  return new BinaryOperatorVariantBitwiseXOr();
+static load(deserializer: Deserializer): BinaryOperatorVariantInstanceOf {
+  return new BinaryOperatorVariantInstanceOf();
 }
 
 }
 
-export class BinaryOperatorVariantBitwiseOr extends BinaryOperator {
+export class BinaryOperatorVariantEqual extends BinaryOperator {
 constructor () {
   super();
 }
@@ -284,13 +328,13 @@ public serialize(serializer: Serializer): void {
   serializer.serializeVariantIndex(15);
 }
 
-static load(deserializer: Deserializer): BinaryOperatorVariantBitwiseOr {
-  return new BinaryOperatorVariantBitwiseOr();
+static load(deserializer: Deserializer): BinaryOperatorVariantEqual {
+  return new BinaryOperatorVariantEqual();
 }
 
 }
 
-export class BinaryOperatorVariantLogicalAnd extends BinaryOperator {
+export class BinaryOperatorVariantNotEqual extends BinaryOperator {
 constructor () {
   super();
 }
@@ -299,13 +343,13 @@ public serialize(serializer: Serializer): void {
   serializer.serializeVariantIndex(16);
 }
 
-static load(deserializer: Deserializer): BinaryOperatorVariantLogicalAnd {
-  return new BinaryOperatorVariantLogicalAnd();
+static load(deserializer: Deserializer): BinaryOperatorVariantNotEqual {
+  return new BinaryOperatorVariantNotEqual();
 }
 
 }
 
-export class BinaryOperatorVariantLogicalOr extends BinaryOperator {
+export class BinaryOperatorVariantLooseEqual extends BinaryOperator {
 constructor () {
   super();
 }
@@ -314,955 +358,876 @@ public serialize(serializer: Serializer): void {
   serializer.serializeVariantIndex(17);
 }
 
-static load(deserializer: Deserializer): BinaryOperatorVariantLogicalOr {
-  return new BinaryOperatorVariantLogicalOr();
+static load(deserializer: Deserializer): BinaryOperatorVariantLooseEqual {
+  return new BinaryOperatorVariantLooseEqual();
 }
 
 }
-export class BinaryOperatorExpr {
 
-constructor (public operator: BinaryOperator, public left: Expr, public right: Expr) {
+export class BinaryOperatorVariantLooseNotEqual extends BinaryOperator {
+constructor () {
+  super();
 }
 
 public serialize(serializer: Serializer): void {
-  this.operator.serialize(serializer);
-  this.left.serialize(serializer);
-  this.right.serialize(serializer);
+  serializer.serializeVariantIndex(18);
 }
 
-static deserialize(deserializer: Deserializer): BinaryOperatorExpr {
-  const operator = BinaryOperator.deserialize(deserializer);
-  const left = Expr.deserialize(deserializer);
-  const right = Expr.deserialize(deserializer);
-  return new BinaryOperatorExpr(operator,left,right);
+static load(deserializer: Deserializer): BinaryOperatorVariantLooseNotEqual {
+  return new BinaryOperatorVariantLooseNotEqual();
 }
 
 }
-export class CallExpr {
 
-constructor (public expr: Expr, public parameter_list: Seq<Expr>) {
+export class BinaryOperatorVariantBitwiseAnd extends BinaryOperator {
+constructor () {
+  super();
 }
 
 public serialize(serializer: Serializer): void {
-  this.expr.serialize(serializer);
-  Helpers.serializeVectorExpr(this.parameter_list, serializer);
+  serializer.serializeVariantIndex(19);
 }
 
-static deserialize(deserializer: Deserializer): CallExpr {
-  const expr = Expr.deserialize(deserializer);
-  const parameter_list = Helpers.deserializeVectorExpr(deserializer);
-  return new CallExpr(expr,parameter_list);
+static load(deserializer: Deserializer): BinaryOperatorVariantBitwiseAnd {
+  return new BinaryOperatorVariantBitwiseAnd();
 }
 
 }
-export class Code {
 
-constructor (public export_definition_list: Seq<ExportDefinition>, public statement_list: Seq<Statement>) {
+export class BinaryOperatorVariantBitwiseXOr extends BinaryOperator {
+constructor () {
+  super();
 }
 
 public serialize(serializer: Serializer): void {
-  Helpers.serializeVectorExportDefinition(this.export_definition_list, serializer);
-  Helpers.serializeVectorStatement(this.statement_list, serializer);
-}
-
-static deserialize(deserializer: Deserializer): Code {
-  const export_definition_list = Helpers.deserializeVectorExportDefinition(deserializer);
-  const statement_list = Helpers.deserializeVectorStatement(deserializer);
-  return new Code(export_definition_list,statement_list);
+  serializer.serializeVariantIndex(20);
 }
 
+static load(deserializer: Deserializer): BinaryOperatorVariantBitwiseXOr {
+  return new BinaryOperatorVariantBitwiseXOr();
 }
-export abstract class CodeType {
-abstract serialize(serializer: Serializer): void;
 
-static deserialize(deserializer: Deserializer): CodeType {
-  const index = deserializer.deserializeVariantIndex();
-  switch (index) {
-    case 0: return CodeTypeVariantJavaScript.load(deserializer);
-    case 1: return CodeTypeVariantTypeScript.load(deserializer);
-    default: throw new Error("Unknown variant index for CodeType: " + index);
-  }
 }
-}
-
 
-export class CodeTypeVariantJavaScript extends CodeType {
+export class BinaryOperatorVariantBitwiseOr extends BinaryOperator {
 constructor () {
   super();
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeVariantIndex(0);
+  serializer.serializeVariantIndex(21);
 }
 
-static load(deserializer: Deserializer): CodeTypeVariantJavaScript {
-  return new CodeTypeVariantJavaScript();
+static load(deserializer: Deserializer): BinaryOperatorVariantBitwiseOr {
+  return new BinaryOperatorVariantBitwiseOr();
 }
 
 }
 
-export class CodeTypeVariantTypeScript extends CodeType {
+export class BinaryOperatorVariantLogicalAnd extends BinaryOperator {
 constructor () {
   super();
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeVariantIndex(1);
+  serializer.serializeVariantIndex(22);
 }
 
-static load(deserializer: Deserializer): CodeTypeVariantTypeScript {
-  return new CodeTypeVariantTypeScript();
+static load(deserializer: Deserializer): BinaryOperatorVariantLogicalAnd {
+  return new BinaryOperatorVariantLogicalAnd();
 }
 
 }
-export class ConditionalOperatorExpr {
 
-constructor (public condition: Expr, public then_expr: Expr, public else_expr: Expr) {
+export class BinaryOperatorVariantLogicalOr extends BinaryOperator {
+constructor () {
+  super();
 }
 
 public serialize(serializer: Serializer): void {
-  this.condition.serialize(serializer);
-  this.then_expr.serialize(serializer);
-  this.else_expr.serialize(serializer);
-}
-
-static deserialize(deserializer: Deserializer): ConditionalOperatorExpr {
-  const condition = Expr.deserialize(deserializer);
-  const then_expr = Expr.deserialize(deserializer);
-  const else_expr = Expr.deserialize(deserializer);
-  return new ConditionalOperatorExpr(condition,then_expr,else_expr);
+  serializer.serializeVariantIndex(23);
 }
 
+static load(deserializer: Deserializer): BinaryOperatorVariantLogicalOr {
+  return new BinaryOperatorVariantLogicalOr();
 }
-export abstract class ExportDefinition {
-abstract serialize(serializer: Serializer): void;
 
-static deserialize(deserializer: Deserializer): ExportDefinition {
-  const index = deserializer.deserializeVariantIndex();
-  switch (index) {
-    case 0: return ExportDefinitionVariantTypeAlias.load(deserializer);
-    case 1: return ExportDefinitionVariantFunction.load(deserializer);
-    case 2: return ExportDefinitionVariantVariable.load(deserializer);
-    default: throw new Error("Unknown variant index for ExportDefinition: " + index);
-  }
-}
 }
 
-
-export class ExportDefinitionVariantTypeAlias extends ExportDefinition {
-
-constructor (public value: TypeAlias) {
+export class BinaryOperatorVariantNullishCoalescing extends BinaryOperator {
+constructor () {
   super();
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeVariantIndex(0);
-  this.value.serialize(serializer);
+  serializer.serializeVariantIndex(24);
 }
 
-static load(deserializer: Deserializer): ExportDefinitionVariantTypeAlias {
-  const value = TypeAlias.deserialize(deserializer);
-  return new ExportDefinitionVariantTypeAlias(value);
+static load(deserializer: Deserializer): BinaryOperatorVariantNullishCoalescing {
+  return new BinaryOperatorVariantNullishCoalescing();
 }
 
 }
 
-export class ExportDefinitionVariantFunction extends ExportDefinition {
-
-constructor (public value: Function) {
+export class BinaryOperatorVariantComma extends BinaryOperator {
+constructor () {
   super();
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeVariantIndex(1);
-  this.value.serialize(serializer);
+  serializer.serializeVariantIndex(25);
 }
 
-static load(deserializer: Deserializer): ExportDefinitionVariantFunction {
-  const value = Function.deserialize(deserializer);
-  return new ExportDefinitionVariantFunction(value);
+static load(deserializer: Deserializer): BinaryOperatorVariantComma {
+  return new BinaryOperatorVariantComma();
 }
 
 }
+export class BinaryOperatorExpr {
 
-export class ExportDefinitionVariantVariable extends ExportDefinition {
-
-constructor (public value: Variable) {
-  super();
+constructor (public operator: BinaryOperator, public left: Expr, public right: Expr) {
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeVariantIndex(2);
-  this.value.serialize(serializer);
+  this.operator.serialize(serializer);
+  this.left.serialize(serializer);
+  this.right.serialize(serializer);
 }
 
-static load(deserializer: Deserializer): ExportDefinitionVariantVariable {
-  const value = Variable.deserialize(deserializer);
-  return new ExportDefinitionVariantVariable(value);
+static deserialize(deserializer: Deserializer): BinaryOperatorExpr {
+  const operator = BinaryOperator.deserialize(deserializer);
+  const left = Expr.deserialize(deserializer);
+  const right = Expr.deserialize(deserializer);
+  return new BinaryOperatorExpr(operator,left,right);
 }
 
 }
-export abstract class Expr {
+export abstract class BindingPattern {
 abstract serialize(serializer: Serializer): void;
 
-static deserialize(deserializer: Deserializer): Expr {
+static deserialize(deserializer: Deserializer): BindingPattern {
   const index = deserializer.deserializeVariantIndex();
   switch (index) {
-    case 0: return ExprVariantNumberLiteral.load(deserializer);
-    case 1: return ExprVariantStringLiteral.load(deserializer);
-    case 2: return ExprVariantBooleanLiteral.load(deserializer);
-    case 3: return ExprVariantNullLiteral.load(deserializer);
-    case 4: return ExprVariantUndefinedLiteral.load(deserializer);
-    case 5: return ExprVariantUnaryOperator.load(deserializer);
-    case 6: return ExprVariantBinaryOperator.load(deserializer);
-    case 7: return ExprVariantConditionalOperator.load(deserializer);
-    case 8: return ExprVariantArrayLiteral.load(deserializer);
-    case 9: return ExprVariantObjectLiteral.load(deserializer);
-    case 10: return ExprVariantLambda.load(deserializer);
-    case 11: return ExprVariantVariable.load(deserializer);
-    case 12: return ExprVariantGlobalObjects.load(deserializer);
-    case 13: return ExprVariantImportedVariable.load(deserializer);
-    case 14: return ExprVariantGet.load(deserializer);
-    case 15: return ExprVariantCall.load(deserializer);
-    case 16: return ExprVariantNew.load(deserializer);
-    case 17: return ExprVariantTypeAssertion.load(deserializer);
-    default: throw new Error("Unknown variant index for Expr: " + index);
+    case 0: return BindingPatternVariantIdentifer.load(deserializer);
+    case 1: return BindingPatternVariantObject.load(deserializer);
+    case 2: return BindingPatternVariantArray.load(deserializer);
+    default: throw new Error("Unknown variant index for BindingPattern: " + index);
   }
 }
 }
 
 
-export class ExprVariantNumberLiteral extends Expr {
+export class BindingPatternVariantIdentifer extends BindingPattern {
 
-constructor (public value: int32) {
+constructor (public value: str) {
   super();
 }
 
 public serialize(serializer: Serializer): void {
   serializer.serializeVariantIndex(0);
-  serializer.serializeI32(this.value);
+  serializer.serializeStr(this.value);
 }
 
-static load(deserializer: Deserializer): ExprVariantNumberLiteral {
-  const value = deserializer.deserializeI32();
-  return new ExprVariantNumberLiteral(value);
+static load(deserializer: Deserializer): BindingPatternVariantIdentifer {
+  const value = deserializer.deserializeStr();
+  return new BindingPatternVariantIdentifer(value);
 }
 
 }
 
-export class ExprVariantStringLiteral extends Expr {
+export class BindingPatternVariantObject extends BindingPattern {
 
-constructor (public value: str) {
+constructor (public value: ObjectPattern) {
   super();
 }
 
 public serialize(serializer: Serializer): void {
   serializer.serializeVariantIndex(1);
-  serializer.serializeStr(this.value);
+  this.value.serialize(serializer);
 }
 
-static load(deserializer: Deserializer): ExprVariantStringLiteral {
-  const value = deserializer.deserializeStr();
-  return new ExprVariantStringLiteral(value);
+static load(deserializer: Deserializer): BindingPatternVariantObject {
+  const value = ObjectPattern.deserialize(deserializer);
+  return new BindingPatternVariantObject(value);
 }
 
 }
 
-export class ExprVariantBooleanLiteral extends Expr {
+export class BindingPatternVariantArray extends BindingPattern {
 
-constructor (public value: bool) {
+constructor (public value: ArrayPattern) {
   super();
 }
 
 public serialize(serializer: Serializer): void {
   serializer.serializeVariantIndex(2);
-  serializer.serializeBool(this.value);
+  this.value.serialize(serializer);
 }
 
-static load(deserializer: Deserializer): ExprVariantBooleanLiteral {
-  const value = deserializer.deserializeBool();
-  return new ExprVariantBooleanLiteral(value);
+static load(deserializer: Deserializer): BindingPatternVariantArray {
+  const value = ArrayPattern.deserialize(deserializer);
+  return new BindingPatternVariantArray(value);
 }
 
 }
+export class CallExpr {
 
-export class ExprVariantNullLiteral extends Expr {
-constructor () {
-  super();
+constructor (public expr: Expr, public parameterList: Seq<ArrayItem>, public isOptionalChain: bool) {
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeVariantIndex(3);
+  this.expr.serialize(serializer);
+  Helpers.serializeVectorArrayItem(this.parameterList, serializer);
+  serializer.serializeBool(this.isOptionalChain);
 }
 
-static load(deserializer: Deserializer): ExprVariantNullLiteral {
-  return new ExprVariantNullLiteral();
+static deserialize(deserializer: Deserializer): CallExpr {
+  const expr = Expr.deserialize(deserializer);
+  const parameterList = Helpers.deserializeVectorArrayItem(deserializer);
+  const isOptionalChain = deserializer.deserializeBool();
+  return new CallExpr(expr,parameterList,isOptionalChain);
 }
 
 }
+export class ClassConstructor {
 
-export class ExprVariantUndefinedLiteral extends Expr {
-constructor () {
-  super();
+constructor (public parameterList: Seq<Parameter>, public superParameterList: Optional<Seq<ArrayItem>>, public statementList: Seq<Statement>) {
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeVariantIndex(4);
+  Helpers.serializeVectorParameter(this.parameterList, serializer);
+  Helpers.serializeOptionVectorArrayItem(this.superParameterList, serializer);
+  Helpers.serializeVectorStatement(this.statementList, serializer);
 }
 
-static load(deserializer: Deserializer): ExprVariantUndefinedLiteral {
-  return new ExprVariantUndefinedLiteral();
+static deserialize(deserializer: Deserializer): ClassConstructor {
+  const parameterList = Helpers.deserializeVectorParameter(deserializer);
+  const superParameterList = Helpers.deserializeOptionVectorArrayItem(deserializer);
+  const statementList = Helpers.deserializeVectorStatement(deserializer);
+  return new ClassConstructor(parameterList,superParameterList,statementList);
 }
 
 }
+export class ClassExpr {
 
-export class ExprVariantUnaryOperator extends Expr {
-
-constructor (public value: UnaryOperatorExpr) {
-  super();
+constructor (public name: Optional<str>, public extends: Optional<Expr>, public constructor: Optional<ClassConstructor>, public memberList: Seq<ClassMember>) {
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeVariantIndex(5);
-  this.value.serialize(serializer);
+  Helpers.serializeOptionStr(this.name, serializer);
+  Helpers.serializeOptionExpr(this.extends, serializer);
+  Helpers.serializeOptionClassConstructor(this.constructor, serializer);
+  Helpers.serializeVectorClassMember(this.memberList, serializer);
 }
 
-static load(deserializer: Deserializer): ExprVariantUnaryOperator {
-  const value = UnaryOperatorExpr.deserialize(deserializer);
-  return new ExprVariantUnaryOperator(value);
+static deserialize(deserializer: Deserializer): ClassExpr {
+  const name = Helpers.deserializeOptionStr(deserializer);
+  const extends = Helpers.deserializeOptionExpr(deserializer);
+  const constructor = Helpers.deserializeOptionClassConstructor(deserializer);
+  const memberList = Helpers.deserializeVectorClassMember(deserializer);
+  return new ClassExpr(name,extends,constructor,memberList);
 }
 
 }
+export class ClassField {
 
-export class ExprVariantBinaryOperator extends Expr {
-
-constructor (public value: BinaryOperatorExpr) {
-  super();
+constructor (public key: PropertyKey, public type: Type, public value: Optional<Expr>) {
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeVariantIndex(6);
-  this.value.serialize(serializer);
+  this.key.serialize(serializer);
+  this.type.serialize(serializer);
+  Helpers.serializeOptionExpr(this.value, serializer);
 }
 
-static load(deserializer: Deserializer): ExprVariantBinaryOperator {
-  const value = BinaryOperatorExpr.deserialize(deserializer);
-  return new ExprVariantBinaryOperator(value);
+static deserialize(deserializer: Deserializer): ClassField {
+  const key = PropertyKey.deserialize(deserializer);
+  const type = Type.deserialize(deserializer);
+  const value = Helpers.deserializeOptionExpr(deserializer);
+  return new ClassField(key,type,value);
 }
 
 }
+export class ClassMember {
 
-export class ExprVariantConditionalOperator extends Expr {
-
-constructor (public value: ConditionalOperatorExpr) {
-  super();
+constructor (public isStatic: bool, public kind: ClassMemberKind) {
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeVariantIndex(7);
-  this.value.serialize(serializer);
+  serializer.serializeBool(this.isStatic);
+  this.kind.serialize(serializer);
 }
 
-static load(deserializer: Deserializer): ExprVariantConditionalOperator {
-  const value = ConditionalOperatorExpr.deserialize(deserializer);
-  return new ExprVariantConditionalOperator(value);
+static deserialize(deserializer: Deserializer): ClassMember {
+  const isStatic = deserializer.deserializeBool();
+  const kind = ClassMemberKind.deserialize(deserializer);
+  return new ClassMember(isStatic,kind);
 }
 
 }
+export abstract class ClassMemberKind {
+abstract serialize(serializer: Serializer): void;
 
-export class ExprVariantArrayLiteral extends Expr {
+static deserialize(deserializer: Deserializer): ClassMemberKind {
+  const index = deserializer.deserializeVariantIndex();
+  switch (index) {
+    case 0: return ClassMemberKindVariantField.load(deserializer);
+    case 1: return ClassMemberKindVariantMethod.load(deserializer);
+    case 2: return ClassMemberKindVariantGetter.load(deserializer);
+    case 3: return ClassMemberKindVariantSetter.load(deserializer);
+    default: throw new Error("Unknown variant index for ClassMemberKind: " + index);
+  }
+}
+}
 
-constructor (public value: Seq<ArrayItem>) {
-  super();
-}
-
-public serialize(serializer: Serializer): void {
-  serializer.serializeVariantIndex(8);
-  Helpers.serializeVectorArrayItem(this.value, serializer);
-}
 
-static load(deserializer: Deserializer): ExprVariantArrayLiteral {
-  const value = Helpers.deserializeVectorArrayItem(deserializer);
-  return new ExprVariantArrayLiteral(value);
-}
-
-}
-
-export class ExprVariantObjectLiteral extends Expr {
+export class ClassMemberKindVariantField extends ClassMemberKind {
 
-constructor (public value: Seq<Member>) {
+constructor (public value: ClassField) {
   super();
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeVariantIndex(9);
-  Helpers.serializeVectorMember(this.value, serializer);
+  serializer.serializeVariantIndex(0);
+  this.value.serialize(serializer);
 }
 
-static load(deserializer: Deserializer): ExprVariantObjectLiteral {
-  const value = Helpers.deserializeVectorMember(deserializer);
-  return new ExprVariantObjectLiteral(value);
+static load(deserializer: Deserializer): ClassMemberKindVariantField {
+  const value = ClassField.deserialize(deserializer);
+  return new ClassMemberKindVariantField(value);
 }
 
 }
 
-export class ExprVariantLambda extends Expr {
+export class ClassMemberKindVariantMethod extends ClassMemberKind {
 
-constructor (public value: LambdaExpr) {
+constructor (public value: ObjectMethod) {
   super();
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeVariantIndex(10);
+  serializer.serializeVariantIndex(1);
   this.value.serialize(serializer);
 }
 
-static load(deserializer: Deserializer): ExprVariantLambda {
-  const value = LambdaExpr.deserialize(deserializer);
-  return new ExprVariantLambda(value);
+static load(deserializer: Deserializer): ClassMemberKindVariantMethod {
+  const value = ObjectMethod.deserialize(deserializer);
+  return new ClassMemberKindVariantMethod(value);
 }
 
 }
 
-export class ExprVariantVariable extends Expr {
+export class ClassMemberKindVariantGetter extends ClassMemberKind {
 
-constructor (public value: Identifer) {
+constructor (public value: ObjectGetter) {
   super();
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeVariantIndex(11);
+  serializer.serializeVariantIndex(2);
   this.value.serialize(serializer);
 }
 
-static load(deserializer: Deserializer): ExprVariantVariable {
-  const value = Identifer.deserialize(deserializer);
-  return new ExprVariantVariable(value);
+static load(deserializer: Deserializer): ClassMemberKindVariantGetter {
+  const value = ObjectGetter.deserialize(deserializer);
+  return new ClassMemberKindVariantGetter(value);
 }
 
 }
 
-export class ExprVariantGlobalObjects extends Expr {
+export class ClassMemberKindVariantSetter extends ClassMemberKind {
 
-constructor (public value: Identifer) {
+constructor (public value: ObjectSetter) {
   super();
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeVariantIndex(12);
+  serializer.serializeVariantIndex(3);
   this.value.serialize(serializer);
 }
 
-static load(deserializer: Deserializer): ExprVariantGlobalObjects {
-  const value = Identifer.deserialize(deserializer);
-  return new ExprVariantGlobalObjects(value);
+static load(deserializer: Deserializer): ClassMemberKindVariantSetter {
+  const value = ObjectSetter.deserialize(deserializer);
+  return new ClassMemberKindVariantSetter(value);
 }
 
 }
+export class Code {
 
-export class ExprVariantImportedVariable extends Expr {
-
-constructor (public value: ImportedVariable) {
-  super();
+constructor (public exportDefinitionList: Seq<ExportDefinition>, public statementList: Seq<Statement>) {
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeVariantIndex(13);
-  this.value.serialize(serializer);
+  Helpers.serializeVectorExportDefinition(this.exportDefinitionList, serializer);
+  Helpers.serializeVectorStatement(this.statementList, serializer);
 }
 
-static load(deserializer: Deserializer): ExprVariantImportedVariable {
-  const value = ImportedVariable.deserialize(deserializer);
-  return new ExprVariantImportedVariable(value);
+static deserialize(deserializer: Deserializer): Code {
+  const exportDefinitionList = Helpers.deserializeVectorExportDefinition(deserializer);
+  const statementList = Helpers.deserializeVectorStatement(deserializer);
+  return new Code(exportDefinitionList,statementList);
 }
 
 }
+export abstract class CodeType {
+abstract serialize(serializer: Serializer): void;
 
-export class ExprVariantGet extends Expr {
+static deserialize(deserializer: Deserializer): CodeType {
+  const index = deserializer.deserializeVariantIndex();
+  switch (index) {
+    case 0: return CodeTypeVariantJavaScript.load(deserializer);
+    case 1: return CodeTypeVariantTypeScript.load(deserializer);
+    case 2: return CodeTypeVariantDeclaration.load(deserializer);
+    case 3: return CodeTypeVariantJavaScriptWithJsDoc.load(deserializer);
+    default: throw new Error("Unknown variant index for CodeType: " + index);
+  }
+}
+}
 
-constructor (public value: GetExpr) {
+
+export class CodeTypeVariantJavaScript extends CodeType {
+constructor () {
   super();
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeVariantIndex(14);
-  this.value.serialize(serializer);
+  serializer.serializeVariantIndex(0);
 }
 
-static load(deserializer: Deserializer): ExprVariantGet {
-  const value = GetExpr.deserialize(deserializer);
-  return new ExprVariantGet(value);
+static load(deserializer: Deserializer): CodeTypeVariantJavaScript {
+  return new CodeTypeVariantJavaScript();
 }
 
 }
 
-export class ExprVariantCall extends Expr {
-
-constructor (public value: CallExpr) {
+export class CodeTypeVariantTypeScript extends CodeType {
+constructor () {
   super();
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeVariantIndex(15);
-  this.value.serialize(serializer);
+  serializer.serializeVariantIndex(1);
 }
 
-static load(deserializer: Deserializer): ExprVariantCall {
-  const value = CallExpr.deserialize(deserializer);
-  return new ExprVariantCall(value);
+static load(deserializer: Deserializer): CodeTypeVariantTypeScript {
+  return new CodeTypeVariantTypeScript();
 }
 
 }
 
-export class ExprVariantNew extends Expr {
-
-constructor (public value: CallExpr) {
+export class CodeTypeVariantDeclaration extends CodeType {
+constructor () {
   super();
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeVariantIndex(16);
-  this.value.serialize(serializer);
+  serializer.serializeVariantIndex(2);
 }
 
-static load(deserializer: Deserializer): ExprVariantNew {
-  const value = CallExpr.deserialize(deserializer);
-  return new ExprVariantNew(value);
+static load(deserializer: Deserializer): CodeTypeVariantDeclaration {
+  return new CodeTypeVariantDeclaration();
 }
 
 }
 
-export class ExprVariantTypeAssertion extends Expr {
-
-constructor (public value: TypeAssertion) {
+export class CodeTypeVariantJavaScriptWithJsDoc extends CodeType {
+constructor () {
   super();
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeVariantIndex(17);
-  this.value.serialize(serializer);
+  serializer.serializeVariantIndex(3);
 }
 
-static load(deserializer: Deserializer): ExprVariantTypeAssertion {
-  const value = TypeAssertion.deserialize(deserializer);
-  return new ExprVariantTypeAssertion(value);
+static load(deserializer: Deserializer): CodeTypeVariantJavaScriptWithJsDoc {
+  return new CodeTypeVariantJavaScriptWithJsDoc();
 }
 
 }
-export class ForOfStatement {
+export abstract class Comment {
+abstract serialize(serializer: Serializer): void;
 
-constructor (public element_variable_name: Identifer, public iterable_expr: Expr, public statement_list: Seq<Statement>) {
+static deserialize(deserializer: Deserializer): Comment {
+  const index = deserializer.deserializeVariantIndex();
+  switch (index) {
+    case 0: return CommentVariantLine.load(deserializer);
+    case 1: return CommentVariantBlock.load(deserializer);
+    case 2: return CommentVariantEslintDisableNextLine.load(deserializer);
+    case 3: return CommentVariantTsExpectError.load(deserializer);
+    default: throw new Error("Unknown variant index for Comment: " + index);
+  }
 }
-
-public serialize(serializer: Serializer): void {
-  this.element_variable_name.serialize(serializer);
-  this.iterable_expr.serialize(serializer);
-  Helpers.serializeVectorStatement(this.statement_list, serializer);
 }
 
-static deserialize(deserializer: Deserializer): ForOfStatement {
-  const element_variable_name = Identifer.deserialize(deserializer);
-  const iterable_expr = Expr.deserialize(deserializer);
-  const statement_list = Helpers.deserializeVectorStatement(deserializer);
-  return new ForOfStatement(element_variable_name,iterable_expr,statement_list);
-}
 
-}
-export class ForStatement {
+export class CommentVariantLine extends Comment {
 
-constructor (public counter_variable_name: Identifer, public until_expr: Expr, public statement_list: Seq<Statement>) {
+constructor (public value: str) {
+  super();
 }
 
 public serialize(serializer: Serializer): void {
-  this.counter_variable_name.serialize(serializer);
-  this.until_expr.serialize(serializer);
-  Helpers.serializeVectorStatement(this.statement_list, serializer);
+  serializer.serializeVariantIndex(0);
+  serializer.serializeStr(this.value);
 }
 
-static deserialize(deserializer: Deserializer): ForStatement {
-  const counter_variable_name = Identifer.deserialize(deserializer);
-  const until_expr = Expr.deserialize(deserializer);
-  const statement_list = Helpers.deserializeVectorStatement(deserializer);
-  return new ForStatement(counter_variable_name,until_expr,statement_list);
+static load(deserializer: Deserializer): CommentVariantLine {
+  const value = deserializer.deserializeStr();
+  return new CommentVariantLine(value);
 }
 
 }
-export class Function {
 
-constructor (public name: Identifer, public document: str, public type_parameter_list: Seq<Identifer>, public parameter_list: Seq<ParameterWithDocument>, public return_type: Type, public statement_list: Seq<Statement>) {
+export class CommentVariantBlock extends Comment {
+
+constructor (public value: str) {
+  super();
 }
 
 public serialize(serializer: Serializer): void {
-  this.name.serialize(serializer);
-  serializer.serializeStr(this.document);
-  Helpers.serializeVectorIdentifer(this.type_parameter_list, serializer);
-  Helpers.serializeVectorParameterWithDocument(this.parameter_list, serializer);
-  this.return_type.serialize(serializer);
-  Helpers.serializeVectorStatement(this.statement_list, serializer);
+  serializer.serializeVariantIndex(1);
+  serializer.serializeStr(this.value);
 }
 
-static deserialize(deserializer: Deserializer): Function {
-  const name = Identifer.deserialize(deserializer);
-  const document = deserializer.deserializeStr();
-  const type_parameter_list = Helpers.deserializeVectorIdentifer(deserializer);
-  const parameter_list = Helpers.deserializeVectorParameterWithDocument(deserializer);
-  const return_type = Type.deserialize(deserializer);
-  const statement_list = Helpers.deserializeVectorStatement(deserializer);
-  return new Function(name,document,type_parameter_list,parameter_list,return_type,statement_list);
+static load(deserializer: Deserializer): CommentVariantBlock {
+  const value = deserializer.deserializeStr();
+  return new CommentVariantBlock(value);
 }
 
 }
-export class FunctionDefinitionStatement {
 
-constructor (public name: Identifer, public type_parameter_list: Seq<Identifer>, public parameter_list: Seq<ParameterWithDocument>, public return_type: Type, public statement_list: Seq<Statement>) {
+export class CommentVariantEslintDisableNextLine extends Comment {
+
+constructor (public value: Seq<str>) {
+  super();
 }
 
 public serialize(serializer: Serializer): void {
-  this.name.serialize(serializer);
-  Helpers.serializeVectorIdentifer(this.type_parameter_list, serializer);
-  Helpers.serializeVectorParameterWithDocument(this.parameter_list, serializer);
-  this.return_type.serialize(serializer);
-  Helpers.serializeVectorStatement(this.statement_list, serializer);
+  serializer.serializeVariantIndex(2);
+  Helpers.serializeVectorStr(this.value, serializer);
 }
 
-static deserialize(deserializer: Deserializer): FunctionDefinitionStatement {
-  const name = Identifer.deserialize(deserializer);
-  const type_parameter_list = Helpers.deserializeVectorIdentifer(deserializer);
-  const parameter_list = Helpers.deserializeVectorParameterWithDocument(deserializer);
-  const return_type = Type.deserialize(deserializer);
-  const statement_list = Helpers.deserializeVectorStatement(deserializer);
-  return new FunctionDefinitionStatement(name,type_parameter_list,parameter_list,return_type,statement_list);
+static load(deserializer: Deserializer): CommentVariantEslintDisableNextLine {
+  const value = Helpers.deserializeVectorStr(deserializer);
+  return new CommentVariantEslintDisableNextLine(value);
 }
 
 }
-export class FunctionType {
 
-constructor (public type_parameter_list: Seq<Identifer>, public parameter_list: Seq<Type>, public return_type: Type) {
+export class CommentVariantTsExpectError extends Comment {
+
+constructor (public value: str) {
+  super();
 }
 
 public serialize(serializer: Serializer): void {
-  Helpers.serializeVectorIdentifer(this.type_parameter_list, serializer);
-  Helpers.serializeVectorType(this.parameter_list, serializer);
-  this.return_type.serialize(serializer);
+  serializer.serializeVariantIndex(3);
+  serializer.serializeStr(this.value);
 }
 
-static deserialize(deserializer: Deserializer): FunctionType {
-  const type_parameter_list = Helpers.deserializeVectorIdentifer(deserializer);
-  const parameter_list = Helpers.deserializeVectorType(deserializer);
-  const return_type = Type.deserialize(deserializer);
-  return new FunctionType(type_parameter_list,parameter_list,return_type);
+static load(deserializer: Deserializer): CommentVariantTsExpectError {
+  const value = deserializer.deserializeStr();
+  return new CommentVariantTsExpectError(value);
 }
 
 }
-export class GetExpr {
+export class CommentedMember {
 
-constructor (public expr: Expr, public property_expr: Expr) {
+constructor (public commentList: Seq<Comment>, public member: Member) {
 }
 
 public serialize(serializer: Serializer): void {
-  this.expr.serialize(serializer);
-  this.property_expr.serialize(serializer);
+  Helpers.serializeVectorComment(this.commentList, serializer);
+  this.member.serialize(serializer);
 }
 
-static deserialize(deserializer: Deserializer): GetExpr {
-  const expr = Expr.deserialize(deserializer);
-  const property_expr = Expr.deserialize(deserializer);
-  return new GetExpr(expr,property_expr);
+static deserialize(deserializer: Deserializer): CommentedMember {
+  const commentList = Helpers.deserializeVectorComment(deserializer);
+  const member = Member.deserialize(deserializer);
+  return new CommentedMember(commentList,member);
 }
 
 }
-export class Identifer {
+export class CommentedStatement {
 
-constructor (public value: str) {
+constructor (public leadingCommentList: Seq<Comment>, public statement: Statement, public trailingComment: Optional<str>) {
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeStr(this.value);
-}
-
-static deserialize(deserializer: Deserializer): Identifer {
-  const value = deserializer.deserializeStr();
-  return new Identifer(value);
+  Helpers.serializeVectorComment(this.leadingCommentList, serializer);
+  this.statement.serialize(serializer);
+  Helpers.serializeOptionStr(this.trailingComment, serializer);
 }
 
+static deserialize(deserializer: Deserializer): CommentedStatement {
+  const leadingCommentList = Helpers.deserializeVectorComment(deserializer);
+  const statement = Statement.deserialize(deserializer);
+  const trailingComment = Helpers.deserializeOptionStr(deserializer);
+  return new CommentedStatement(leadingCommentList,statement,trailingComment);
 }
-export class IfStatement {
 
-constructor (public condition: Expr, public then_statement_list: Seq<Statement>) {
 }
+export abstract class CommonJsDefaultExport {
+abstract serialize(serializer: Serializer): void;
 
-public serialize(serializer: Serializer): void {
-  this.condition.serialize(serializer);
-  Helpers.serializeVectorStatement(this.then_statement_list, serializer);
+static deserialize(deserializer: Deserializer): CommonJsDefaultExport {
+  const index = deserializer.deserializeVariantIndex();
+  switch (index) {
+    case 0: return CommonJsDefaultExportVariantExportsDefault.load(deserializer);
+    case 1: return CommonJsDefaultExportVariantModuleExports.load(deserializer);
+    default: throw new Error("Unknown variant index for CommonJsDefaultExport: " + index);
+  }
 }
-
-static deserialize(deserializer: Deserializer): IfStatement {
-  const condition = Expr.deserialize(deserializer);
-  const then_statement_list = Helpers.deserializeVectorStatement(deserializer);
-  return new IfStatement(condition,then_statement_list);
 }
 
-}
-export class ImportedType {
 
-constructor (public module_name: str, public name: Identifer) {
+export class CommonJsDefaultExportVariantExportsDefault extends CommonJsDefaultExport {
+constructor () {
+  super();
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeStr(this.module_name);
-  this.name.serialize(serializer);
+  serializer.serializeVariantIndex(0);
 }
 
-static deserialize(deserializer: Deserializer): ImportedType {
-  const module_name = deserializer.deserializeStr();
-  const name = Identifer.deserialize(deserializer);
-  return new ImportedType(module_name,name);
+static load(deserializer: Deserializer): CommonJsDefaultExportVariantExportsDefault {
+  return new CommonJsDefaultExportVariantExportsDefault();
 }
 
 }
-export class ImportedVariable {
 
-constructor (public module_name: str, public name: Identifer) {
+export class CommonJsDefaultExportVariantModuleExports extends CommonJsDefaultExport {
+constructor () {
+  super();
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeStr(this.module_name);
-  this.name.serialize(serializer);
+  serializer.serializeVariantIndex(1);
 }
 
-static deserialize(deserializer: Deserializer): ImportedVariable {
-  const module_name = deserializer.deserializeStr();
-  const name = Identifer.deserialize(deserializer);
-  return new ImportedVariable(module_name,name);
+static load(deserializer: Deserializer): CommonJsDefaultExportVariantModuleExports {
+  return new CommonJsDefaultExportVariantModuleExports();
 }
 
 }
-export class IntersectionType {
+export class ComputedKeyValue {
 
-constructor (public left: Type, public right: Type) {
+constructor (public keyExpr: Expr, public value: Expr) {
 }
 
 public serialize(serializer: Serializer): void {
-  this.left.serialize(serializer);
-  this.right.serialize(serializer);
+  this.keyExpr.serialize(serializer);
+  this.value.serialize(serializer);
 }
 
-static deserialize(deserializer: Deserializer): IntersectionType {
-  const left = Type.deserialize(deserializer);
-  const right = Type.deserialize(deserializer);
-  return new IntersectionType(left,right);
+static deserialize(deserializer: Deserializer): ComputedKeyValue {
+  const keyExpr = Expr.deserialize(deserializer);
+  const value = Expr.deserialize(deserializer);
+  return new ComputedKeyValue(keyExpr,value);
 }
 
 }
-export class KeyValue {
+export class ConditionalOperatorExpr {
 
-constructor (public key: str, public value: Expr) {
+constructor (public condition: Expr, public thenExpr: Expr, public elseExpr: Expr) {
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeStr(this.key);
-  this.value.serialize(serializer);
+  this.condition.serialize(serializer);
+  this.thenExpr.serialize(serializer);
+  this.elseExpr.serialize(serializer);
 }
 
-static deserialize(deserializer: Deserializer): KeyValue {
-  const key = deserializer.deserializeStr();
-  const value = Expr.deserialize(deserializer);
-  return new KeyValue(key,value);
+static deserialize(deserializer: Deserializer): ConditionalOperatorExpr {
+  const condition = Expr.deserialize(deserializer);
+  const thenExpr = Expr.deserialize(deserializer);
+  const elseExpr = Expr.deserialize(deserializer);
+  return new ConditionalOperatorExpr(condition,thenExpr,elseExpr);
 }
 
 }
-export class LambdaExpr {
+export class DefaultExport {
 
-constructor (public parameter_list: Seq<Parameter>, public type_parameter_list: Seq<Identifer>, public return_type: Type, public statement_list: Seq<Statement>) {
+constructor (public document: str, public type: Type, public expr: Expr) {
 }
 
 public serialize(serializer: Serializer): void {
-  Helpers.serializeVectorParameter(this.parameter_list, serializer);
-  Helpers.serializeVectorIdentifer(this.type_parameter_list, serializer);
-  this.return_type.serialize(serializer);
-  Helpers.serializeVectorStatement(this.statement_list, serializer);
+  serializer.serializeStr(this.document);
+  this.type.serialize(serializer);
+  this.expr.serialize(serializer);
 }
 
-static deserialize(deserializer: Deserializer): LambdaExpr {
-  const parameter_list = Helpers.deserializeVectorParameter(deserializer);
-  const type_parameter_list = Helpers.deserializeVectorIdentifer(deserializer);
-  const return_type = Type.deserialize(deserializer);
-  const statement_list = Helpers.deserializeVectorStatement(deserializer);
-  return new LambdaExpr(parameter_list,type_parameter_list,return_type,statement_list);
+static deserialize(deserializer: Deserializer): DefaultExport {
+  const document = deserializer.deserializeStr();
+  const type = Type.deserialize(deserializer);
+  const expr = Expr.deserialize(deserializer);
+  return new DefaultExport(document,type,expr);
 }
 
 }
-export abstract class Member {
+export abstract class DocumentTag {
 abstract serialize(serializer: Serializer): void;
 
-static deserialize(deserializer: Deserializer): Member {
+static deserialize(deserializer: Deserializer): DocumentTag {
   const index = deserializer.deserializeVariantIndex();
   switch (index) {
-    case 0: return MemberVariantSpread.load(deserializer);
-    case 1: return MemberVariantKeyValue.load(deserializer);
-    default: throw new Error("Unknown variant index for Member: " + index);
+    case 0: return DocumentTagVariantDeprecated.load(deserializer);
+    case 1: return DocumentTagVariantExample.load(deserializer);
+    case 2: return DocumentTagVariantSee.load(deserializer);
+    case 3: return DocumentTagVariantReturns.load(deserializer);
+    case 4: return DocumentTagVariantThrows.load(deserializer);
+    case 5: return DocumentTagVariantSince.load(deserializer);
+    default: throw new Error("Unknown variant index for DocumentTag: " + index);
   }
 }
 }
 
 
-export class MemberVariantSpread extends Member {
+export class DocumentTagVariantDeprecated extends DocumentTag {
 
-constructor (public value: Expr) {
+constructor (public value: str) {
   super();
 }
 
 public serialize(serializer: Serializer): void {
   serializer.serializeVariantIndex(0);
-  this.value.serialize(serializer);
+  serializer.serializeStr(this.value);
 }
 
-static load(deserializer: Deserializer): MemberVariantSpread {
-  const value = Expr.deserialize(deserializer);
-  return new MemberVariantSpread(value);
+static load(deserializer: Deserializer): DocumentTagVariantDeprecated {
+  const value = deserializer.deserializeStr();
+  return new DocumentTagVariantDeprecated(value);
 }
 
 }
 
-export class MemberVariantKeyValue extends Member {
+export class DocumentTagVariantExample extends DocumentTag {
 
-constructor (public value: KeyValue) {
+constructor (public value: str) {
   super();
 }
 
 public serialize(serializer: Serializer): void {
   serializer.serializeVariantIndex(1);
-  this.value.serialize(serializer);
+  serializer.serializeStr(this.value);
 }
 
-static load(deserializer: Deserializer): MemberVariantKeyValue {
-  const value = KeyValue.deserialize(deserializer);
-  return new MemberVariantKeyValue(value);
+static load(deserializer: Deserializer): DocumentTagVariantExample {
+  const value = deserializer.deserializeStr();
+  return new DocumentTagVariantExample(value);
 }
 
 }
-export class MemberType {
 
-constructor (public name: str, public required: bool, public type: Type, public document: str) {
+export class DocumentTagVariantSee extends DocumentTag {
+
+constructor (public value: str) {
+  super();
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeStr(this.name);
-  serializer.serializeBool(this.required);
-  this.type.serialize(serializer);
-  serializer.serializeStr(this.document);
+  serializer.serializeVariantIndex(2);
+  serializer.serializeStr(this.value);
 }
 
-static deserialize(deserializer: Deserializer): MemberType {
-  const name = deserializer.deserializeStr();
-  const required = deserializer.deserializeBool();
-  const type = Type.deserialize(deserializer);
-  const document = deserializer.deserializeStr();
-  return new MemberType(name,required,type,document);
+static load(deserializer: Deserializer): DocumentTagVariantSee {
+  const value = deserializer.deserializeStr();
+  return new DocumentTagVariantSee(value);
 }
 
 }
-export class Parameter {
 
-constructor (public name: Identifer, public type: Type) {
+export class DocumentTagVariantReturns extends DocumentTag {
+
+constructor (public value: str) {
+  super();
 }
 
 public serialize(serializer: Serializer): void {
-  this.name.serialize(serializer);
-  this.type.serialize(serializer);
+  serializer.serializeVariantIndex(3);
+  serializer.serializeStr(this.value);
 }
 
-static deserialize(deserializer: Deserializer): Parameter {
-  const name = Identifer.deserialize(deserializer);
-  const type = Type.deserialize(deserializer);
-  return new Parameter(name,type);
+static load(deserializer: Deserializer): DocumentTagVariantReturns {
+  const value = deserializer.deserializeStr();
+  return new DocumentTagVariantReturns(value);
 }
 
 }
-export class ParameterWithDocument {
 
-constructor (public name: Identifer, public document: str, public type: Type) {
+export class DocumentTagVariantThrows extends DocumentTag {
+
+constructor (public value: str) {
+  super();
 }
 
 public serialize(serializer: Serializer): void {
-  this.name.serialize(serializer);
-  serializer.serializeStr(this.document);
-  this.type.serialize(serializer);
+  serializer.serializeVariantIndex(4);
+  serializer.serializeStr(this.value);
 }
 
-static deserialize(deserializer: Deserializer): ParameterWithDocument {
-  const name = Identifer.deserialize(deserializer);
-  const document = deserializer.deserializeStr();
-  const type = Type.deserialize(deserializer);
-  return new ParameterWithDocument(name,document,type);
+static load(deserializer: Deserializer): DocumentTagVariantThrows {
+  const value = deserializer.deserializeStr();
+  return new DocumentTagVariantThrows(value);
 }
 
 }
-export class Pattern {
 
-constructor (public case_string: str, public statement_list: Seq<Statement>) {
+export class DocumentTagVariantSince extends DocumentTag {
+
+constructor (public value: str) {
+  super();
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeStr(this.case_string);
-  Helpers.serializeVectorStatement(this.statement_list, serializer);
+  serializer.serializeVariantIndex(5);
+  serializer.serializeStr(this.value);
 }
 
-static deserialize(deserializer: Deserializer): Pattern {
-  const case_string = deserializer.deserializeStr();
-  const statement_list = Helpers.deserializeVectorStatement(deserializer);
-  return new Pattern(case_string,statement_list);
+static load(deserializer: Deserializer): DocumentTagVariantSince {
+  const value = deserializer.deserializeStr();
+  return new DocumentTagVariantSince(value);
 }
 
 }
-export class SetStatement {
+export class ExportAll {
 
-constructor (public target: Expr, public operator_maybe: Optional<BinaryOperator>, public expr: Expr) {
+constructor (public moduleName: str, public namespace: Optional<str>) {
 }
 
 public serialize(serializer: Serializer): void {
-  this.target.serialize(serializer);
-  Helpers.serializeOptionBinaryOperator(this.operator_maybe, serializer);
-  this.expr.serialize(serializer);
+  serializer.serializeStr(this.moduleName);
+  Helpers.serializeOptionStr(this.namespace, serializer);
 }
 
-static deserialize(deserializer: Deserializer): SetStatement {
-  const target = Expr.deserialize(deserializer);
-  const operator_maybe = Helpers.deserializeOptionBinaryOperator(deserializer);
-  const expr = Expr.deserialize(deserializer);
-  return new SetStatement(target,operator_maybe,expr);
+static deserialize(deserializer: Deserializer): ExportAll {
+  const moduleName = deserializer.deserializeStr();
+  const namespace = Helpers.deserializeOptionStr(deserializer);
+  return new ExportAll(moduleName,namespace);
 }
 
 }
-export abstract class Statement {
+export abstract class ExportDefinition {
 abstract serialize(serializer: Serializer): void;
 
-static deserialize(deserializer: Deserializer): Statement {
+static deserialize(deserializer: Deserializer): ExportDefinition {
   const index = deserializer.deserializeVariantIndex();
   switch (index) {
-    case 0: return StatementVariantEvaluateExpr.load(deserializer);
-    case 1: return StatementVariantSet.load(deserializer);
-    case 2: return StatementVariantIf.load(deserializer);
-    case 3: return StatementVariantThrowError.load(deserializer);
-    case 4: return StatementVariantReturn.load(deserializer);
-    case 5: return StatementVariantReturnVoid.load(deserializer);
-    case 6: return StatementVariantContinue.load(deserializer);
-    case 7: return StatementVariantVariableDefinition.load(deserializer);
-    case 8: return StatementVariantFunctionDefinition.load(deserializer);
-    case 9: return StatementVariantFor.load(deserializer);
-    case 10: return StatementVariantForOf.load(deserializer);
-    case 11: return StatementVariantWhileTrue.load(deserializer);
-    case 12: return StatementVariantBreak.load(deserializer);
-    case 13: return StatementVariantSwitch.load(deserializer);
-    default: throw new Error("Unknown variant index for Statement: " + index);
+    case 0: return ExportDefinitionVariantTypeAlias.load(deserializer);
+    case 1: return ExportDefinitionVariantFunction.load(deserializer);
+    case 2: return ExportDefinitionVariantVariable.load(deserializer);
+    case 3: return ExportDefinitionVariantExportFrom.load(deserializer);
+    case 4: return ExportDefinitionVariantExportAll.load(deserializer);
+    case 5: return ExportDefinitionVariantDefault.load(deserializer);
+    default: throw new Error("Unknown variant index for ExportDefinition: " + index);
   }
 }
 }
 
 
-export class StatementVariantEvaluateExpr extends Statement {
+export class ExportDefinitionVariantTypeAlias extends ExportDefinition {
 
-constructor (public value: Expr) {
+constructor (public value: TypeAlias) {
   super();
 }
 
@@ -1271,16 +1236,16 @@ public serialize(serializer: Serializer): void {
   this.value.serialize(serializer);
 }
 
-static load(deserializer: Deserializer): StatementVariantEvaluateExpr {
-  const value = Expr.deserialize(deserializer);
-  return new StatementVariantEvaluateExpr(value);
+static load(deserializer: Deserializer): ExportDefinitionVariantTypeAlias {
+  const value = TypeAlias.deserialize(deserializer);
+  return new ExportDefinitionVariantTypeAlias(value);
 }
 
 }
 
-export class StatementVariantSet extends Statement {
+export class ExportDefinitionVariantFunction extends ExportDefinition {
 
-constructor (public value: SetStatement) {
+constructor (public value: Function) {
   super();
 }
 
@@ -1289,16 +1254,16 @@ public serialize(serializer: Serializer): void {
   this.value.serialize(serializer);
 }
 
-static load(deserializer: Deserializer): StatementVariantSet {
-  const value = SetStatement.deserialize(deserializer);
-  return new StatementVariantSet(value);
+static load(deserializer: Deserializer): ExportDefinitionVariantFunction {
+  const value = Function.deserialize(deserializer);
+  return new ExportDefinitionVariantFunction(value);
 }
 
 }
 
-export class StatementVariantIf extends Statement {
+export class ExportDefinitionVariantVariable extends ExportDefinition {
 
-constructor (public value: IfStatement) {
+constructor (public value: Variable) {
   super();
 }
 
@@ -1307,16 +1272,16 @@ public serialize(serializer: Serializer): void {
   this.value.serialize(serializer);
 }
 
-static load(deserializer: Deserializer): StatementVariantIf {
-  const value = IfStatement.deserialize(deserializer);
-  return new StatementVariantIf(value);
+static load(deserializer: Deserializer): ExportDefinitionVariantVariable {
+  const value = Variable.deserialize(deserializer);
+  return new ExportDefinitionVariantVariable(value);
 }
 
 }
 
-export class StatementVariantThrowError extends Statement {
+export class ExportDefinitionVariantExportFrom extends ExportDefinition {
 
-constructor (public value: Expr) {
+constructor (public value: ExportFrom) {
   super();
 }
 
@@ -1325,16 +1290,16 @@ public serialize(serializer: Serializer): void {
   this.value.serialize(serializer);
 }
 
-static load(deserializer: Deserializer): StatementVariantThrowError {
-  const value = Expr.deserialize(deserializer);
-  return new StatementVariantThrowError(value);
+static load(deserializer: Deserializer): ExportDefinitionVariantExportFrom {
+  const value = ExportFrom.deserialize(deserializer);
+  return new ExportDefinitionVariantExportFrom(value);
 }
 
 }
 
-export class StatementVariantReturn extends Statement {
+export class ExportDefinitionVariantExportAll extends ExportDefinition {
 
-constructor (public value: Expr) {
+constructor (public value: ExportAll) {
   super();
 }
 
@@ -1343,650 +1308,3389 @@ public serialize(serializer: Serializer): void {
   this.value.serialize(serializer);
 }
 
-static load(deserializer: Deserializer): StatementVariantReturn {
-  const value = Expr.deserialize(deserializer);
-  return new StatementVariantReturn(value);
+static load(deserializer: Deserializer): ExportDefinitionVariantExportAll {
+  const value = ExportAll.deserialize(deserializer);
+  return new ExportDefinitionVariantExportAll(value);
 }
 
 }
 
-export class StatementVariantReturnVoid extends Statement {
-constructor () {
+export class ExportDefinitionVariantDefault extends ExportDefinition {
+
+constructor (public value: DefaultExport) {
   super();
 }
 
 public serialize(serializer: Serializer): void {
   serializer.serializeVariantIndex(5);
+  this.value.serialize(serializer);
 }
 
-static load(deserializer: Deserializer): StatementVariantReturnVoid {
-  return new StatementVariantReturnVoid();
+static load(deserializer: Deserializer): ExportDefinitionVariantDefault {
+  const value = DefaultExport.deserialize(deserializer);
+  return new ExportDefinitionVariantDefault(value);
 }
 
 }
+export class ExportFrom {
 
-export class StatementVariantContinue extends Statement {
-constructor () {
-  super();
+constructor (public moduleName: str, public nameList: Seq<ExportName>, public isTypeOnly: bool) {
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeVariantIndex(6);
+  serializer.serializeStr(this.moduleName);
+  Helpers.serializeVectorExportName(this.nameList, serializer);
+  serializer.serializeBool(this.isTypeOnly);
 }
 
-static load(deserializer: Deserializer): StatementVariantContinue {
-  return new StatementVariantContinue();
+static deserialize(deserializer: Deserializer): ExportFrom {
+  const moduleName = deserializer.deserializeStr();
+  const nameList = Helpers.deserializeVectorExportName(deserializer);
+  const isTypeOnly = deserializer.deserializeBool();
+  return new ExportFrom(moduleName,nameList,isTypeOnly);
 }
 
 }
+export class ExportName {
 
-export class StatementVariantVariableDefinition extends Statement {
-
-constructor (public value: VariableDefinitionStatement) {
-  super();
+constructor (public name: str, public alias: Optional<str>) {
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeVariantIndex(7);
-  this.value.serialize(serializer);
+  serializer.serializeStr(this.name);
+  Helpers.serializeOptionStr(this.alias, serializer);
 }
 
-static load(deserializer: Deserializer): StatementVariantVariableDefinition {
-  const value = VariableDefinitionStatement.deserialize(deserializer);
-  return new StatementVariantVariableDefinition(value);
+static deserialize(deserializer: Deserializer): ExportName {
+  const name = deserializer.deserializeStr();
+  const alias = Helpers.deserializeOptionStr(deserializer);
+  return new ExportName(name,alias);
 }
 
 }
+export abstract class Expr {
+abstract serialize(serializer: Serializer): void;
 
-export class StatementVariantFunctionDefinition extends Statement {
+static deserialize(deserializer: Deserializer): Expr {
+  const index = deserializer.deserializeVariantIndex();
+  switch (index) {
+    case 0: return ExprVariantNumberLiteral.load(deserializer);
+    case 1: return ExprVariantStringLiteral.load(deserializer);
+    case 2: return ExprVariantBooleanLiteral.load(deserializer);
+    case 3: return ExprVariantNullLiteral.load(deserializer);
+    case 4: return ExprVariantUndefinedLiteral.load(deserializer);
+    case 5: return ExprVariantUnaryOperator.load(deserializer);
+    case 6: return ExprVariantBinaryOperator.load(deserializer);
+    case 7: return ExprVariantConditionalOperator.load(deserializer);
+    case 8: return ExprVariantArrayLiteral.load(deserializer);
+    case 9: return ExprVariantObjectLiteral.load(deserializer);
+    case 10: return ExprVariantLambda.load(deserializer);
+    case 11: return ExprVariantVariable.load(deserializer);
+    case 12: return ExprVariantGlobalObjects.load(deserializer);
+    case 13: return ExprVariantImportedVariable.load(deserializer);
+    case 14: return ExprVariantGet.load(deserializer);
+    case 15: return ExprVariantCall.load(deserializer);
+    case 16: return ExprVariantNew.load(deserializer);
+    case 17: return ExprVariantTypeAssertion.load(deserializer);
+    case 18: return ExprVariantProjectVariable.load(deserializer);
+    case 19: return ExprVariantYield.load(deserializer);
+    case 20: return ExprVariantAwait.load(deserializer);
+    case 21: return ExprVariantClass.load(deserializer);
+    case 22: return ExprVariantThis.load(deserializer);
+    case 23: return ExprVariantRegExpLiteral.load(deserializer);
+    default: throw new Error("Unknown variant index for Expr: " + index);
+  }
+}
+}
 
-constructor (public value: FunctionDefinitionStatement) {
+
+export class ExprVariantNumberLiteral extends Expr {
+
+constructor (public value: int32) {
   super();
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeVariantIndex(8);
-  this.value.serialize(serializer);
+  serializer.serializeVariantIndex(0);
+  serializer.serializeI32(this.value);
 }
 
-static load(deserializer: Deserializer): StatementVariantFunctionDefinition {
-  const value = FunctionDefinitionStatement.deserialize(deserializer);
-  return new StatementVariantFunctionDefinition(value);
+static load(deserializer: Deserializer): ExprVariantNumberLiteral {
+  const value = deserializer.deserializeI32();
+  return new ExprVariantNumberLiteral(value);
 }
 
 }
 
-export class StatementVariantFor extends Statement {
+export class ExprVariantStringLiteral extends Expr {
 
-constructor (public value: ForStatement) {
+constructor (public value: str) {
   super();
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeVariantIndex(9);
-  this.value.serialize(serializer);
+  serializer.serializeVariantIndex(1);
+  serializer.serializeStr(this.value);
 }
 
-static load(deserializer: Deserializer): StatementVariantFor {
-  const value = ForStatement.deserialize(deserializer);
-  return new StatementVariantFor(value);
+static load(deserializer: Deserializer): ExprVariantStringLiteral {
+  const value = deserializer.deserializeStr();
+  return new ExprVariantStringLiteral(value);
 }
 
 }
 
-export class StatementVariantForOf extends Statement {
+export class ExprVariantBooleanLiteral extends Expr {
 
-constructor (public value: ForOfStatement) {
+constructor (public value: bool) {
   super();
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeVariantIndex(10);
-  this.value.serialize(serializer);
+  serializer.serializeVariantIndex(2);
+  serializer.serializeBool(this.value);
 }
 
-static load(deserializer: Deserializer): StatementVariantForOf {
-  const value = ForOfStatement.deserialize(deserializer);
-  return new StatementVariantForOf(value);
+static load(deserializer: Deserializer): ExprVariantBooleanLiteral {
+  const value = deserializer.deserializeBool();
+  return new ExprVariantBooleanLiteral(value);
 }
 
 }
 
-export class StatementVariantWhileTrue extends Statement {
-
-constructor (public value: Seq<Statement>) {
+export class ExprVariantNullLiteral extends Expr {
+constructor () {
   super();
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeVariantIndex(11);
-  Helpers.serializeVectorStatement(this.value, serializer);
+  serializer.serializeVariantIndex(3);
 }
 
-static load(deserializer: Deserializer): StatementVariantWhileTrue {
-  const value = Helpers.deserializeVectorStatement(deserializer);
-  return new StatementVariantWhileTrue(value);
+static load(deserializer: Deserializer): ExprVariantNullLiteral {
+  return new ExprVariantNullLiteral();
 }
 
 }
 
-export class StatementVariantBreak extends Statement {
+export class ExprVariantUndefinedLiteral extends Expr {
 constructor () {
   super();
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeVariantIndex(12);
+  serializer.serializeVariantIndex(4);
 }
 
-static load(deserializer: Deserializer): StatementVariantBreak {
-  return new StatementVariantBreak();
+static load(deserializer: Deserializer): ExprVariantUndefinedLiteral {
+  return new ExprVariantUndefinedLiteral();
 }
 
 }
 
-export class StatementVariantSwitch extends Statement {
+export class ExprVariantUnaryOperator extends Expr {
 
-constructor (public value: SwitchStatement) {
+constructor (public value: UnaryOperatorExpr) {
   super();
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeVariantIndex(13);
+  serializer.serializeVariantIndex(5);
   this.value.serialize(serializer);
 }
 
-static load(deserializer: Deserializer): StatementVariantSwitch {
-  const value = SwitchStatement.deserialize(deserializer);
-  return new StatementVariantSwitch(value);
+static load(deserializer: Deserializer): ExprVariantUnaryOperator {
+  const value = UnaryOperatorExpr.deserialize(deserializer);
+  return new ExprVariantUnaryOperator(value);
 }
 
 }
-export class SwitchStatement {
 
-constructor (public expr: Expr, public pattern_list: Seq<Pattern>) {
+export class ExprVariantBinaryOperator extends Expr {
+
+constructor (public value: BinaryOperatorExpr) {
+  super();
 }
 
 public serialize(serializer: Serializer): void {
-  this.expr.serialize(serializer);
-  Helpers.serializeVectorPattern(this.pattern_list, serializer);
+  serializer.serializeVariantIndex(6);
+  this.value.serialize(serializer);
 }
 
-static deserialize(deserializer: Deserializer): SwitchStatement {
-  const expr = Expr.deserialize(deserializer);
-  const pattern_list = Helpers.deserializeVectorPattern(deserializer);
-  return new SwitchStatement(expr,pattern_list);
+static load(deserializer: Deserializer): ExprVariantBinaryOperator {
+  const value = BinaryOperatorExpr.deserialize(deserializer);
+  return new ExprVariantBinaryOperator(value);
 }
 
 }
-export abstract class Type {
-abstract serialize(serializer: Serializer): void;
-
-static deserialize(deserializer: Deserializer): Type {
-  const index = deserializer.deserializeVariantIndex();
-  switch (index) {
-    case 0: return TypeVariantNumber.load(deserializer);
-    case 1: return TypeVariantString.load(deserializer);
-    case 2: return TypeVariantBoolean.load(deserializer);
-    case 3: return TypeVariantUndefined.load(deserializer);
-    case 4: return TypeVariantNull.load(deserializer);
-    case 5: return TypeVariantNever.load(deserializer);
-    case 6: return TypeVariantVoid.load(deserializer);
-    case 7: return TypeVariantObject.load(deserializer);
-    case 8: return TypeVariantFunction.load(deserializer);
-    case 9: return TypeVariantWithTypeParameter.load(deserializer);
-    case 10: return TypeVariantUnion.load(deserializer);
-    case 11: return TypeVariantIntersection.load(deserializer);
-    case 12: return TypeVariantImportedType.load(deserializer);
-    case 13: return TypeVariantScopeInFile.load(deserializer);
-    case 14: return TypeVariantScopeInGlobal.load(deserializer);
-    case 15: return TypeVariantStringLiteral.load(deserializer);
-    default: throw new Error("Unknown variant index for Type: " + index);
-  }
-}
-}
 
+export class ExprVariantConditionalOperator extends Expr {
 
-export class TypeVariantNumber extends Type {
-constructor () {
+constructor (public value: ConditionalOperatorExpr) {
   super();
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeVariantIndex(0);
-}
-
-static load(deserializer: Deserializer): TypeVariantNumber {
-  return new TypeVariantNumber();
-}
-
-}
-
-export class TypeVariantString extends Type {
-constructor () {
-  super();
+  serializer.serializeVariantIndex(7);
+  this.value.serialize(serializer);
 }
 
-public serialize(serializer: Serializer): void {
-  serializer.serializeVariantIndex(1);
+static load(deserializer: Deserializer): ExprVariantConditionalOperator {
+  const value = ConditionalOperatorExpr.deserialize(deserializer);
+  return new ExprVariantConditionalOperator(value);
 }
 
-static load(deserializer: Deserializer): TypeVariantString {
-  return new TypeVariantString();
 }
 
-}
+export class ExprVariantArrayLiteral extends Expr {
 
-export class TypeVariantBoolean extends Type {
-constructor () {
+constructor (public value: Seq<ArrayItem>) {
   super();
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeVariantIndex(2);
+  serializer.serializeVariantIndex(8);
+  Helpers.serializeVectorArrayItem(this.value, serializer);
 }
 
-static load(deserializer: Deserializer): TypeVariantBoolean {
-  return new TypeVariantBoolean();
+static load(deserializer: Deserializer): ExprVariantArrayLiteral {
+  const value = Helpers.deserializeVectorArrayItem(deserializer);
+  return new ExprVariantArrayLiteral(value);
 }
 
 }
 
-export class TypeVariantUndefined extends Type {
-constructor () {
+export class ExprVariantObjectLiteral extends Expr {
+
+constructor (public value: Seq<Member>) {
   super();
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeVariantIndex(3);
+  serializer.serializeVariantIndex(9);
+  Helpers.serializeVectorMember(this.value, serializer);
 }
 
-static load(deserializer: Deserializer): TypeVariantUndefined {
-  return new TypeVariantUndefined();
+static load(deserializer: Deserializer): ExprVariantObjectLiteral {
+  const value = Helpers.deserializeVectorMember(deserializer);
+  return new ExprVariantObjectLiteral(value);
 }
 
 }
 
-export class TypeVariantNull extends Type {
-constructor () {
+export class ExprVariantLambda extends Expr {
+
+constructor (public value: LambdaExpr) {
   super();
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeVariantIndex(4);
+  serializer.serializeVariantIndex(10);
+  this.value.serialize(serializer);
 }
 
-static load(deserializer: Deserializer): TypeVariantNull {
-  return new TypeVariantNull();
+static load(deserializer: Deserializer): ExprVariantLambda {
+  const value = LambdaExpr.deserialize(deserializer);
+  return new ExprVariantLambda(value);
 }
 
 }
 
-export class TypeVariantNever extends Type {
-constructor () {
+export class ExprVariantVariable extends Expr {
+
+constructor (public value: str) {
   super();
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeVariantIndex(5);
+  serializer.serializeVariantIndex(11);
+  serializer.serializeStr(this.value);
 }
 
-static load(deserializer: Deserializer): TypeVariantNever {
-  return new TypeVariantNever();
+static load(deserializer: Deserializer): ExprVariantVariable {
+  const value = deserializer.deserializeStr();
+  return new ExprVariantVariable(value);
 }
 
 }
 
-export class TypeVariantVoid extends Type {
-constructor () {
+export class ExprVariantGlobalObjects extends Expr {
+
+constructor (public value: str) {
   super();
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeVariantIndex(6);
+  serializer.serializeVariantIndex(12);
+  serializer.serializeStr(this.value);
 }
 
-static load(deserializer: Deserializer): TypeVariantVoid {
-  return new TypeVariantVoid();
+static load(deserializer: Deserializer): ExprVariantGlobalObjects {
+  const value = deserializer.deserializeStr();
+  return new ExprVariantGlobalObjects(value);
 }
 
 }
 
-export class TypeVariantObject extends Type {
+export class ExprVariantImportedVariable extends Expr {
 
-constructor (public value: Seq<MemberType>) {
+constructor (public value: ImportedVariable) {
   super();
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeVariantIndex(7);
-  Helpers.serializeVectorMemberType(this.value, serializer);
+  serializer.serializeVariantIndex(13);
+  this.value.serialize(serializer);
 }
 
-static load(deserializer: Deserializer): TypeVariantObject {
-  const value = Helpers.deserializeVectorMemberType(deserializer);
-  return new TypeVariantObject(value);
+static load(deserializer: Deserializer): ExprVariantImportedVariable {
+  const value = ImportedVariable.deserialize(deserializer);
+  return new ExprVariantImportedVariable(value);
 }
 
 }
 
-export class TypeVariantFunction extends Type {
+export class ExprVariantGet extends Expr {
 
-constructor (public value: FunctionType) {
+constructor (public value: GetExpr) {
   super();
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeVariantIndex(8);
+  serializer.serializeVariantIndex(14);
   this.value.serialize(serializer);
 }
 
-static load(deserializer: Deserializer): TypeVariantFunction {
-  const value = FunctionType.deserialize(deserializer);
-  return new TypeVariantFunction(value);
+static load(deserializer: Deserializer): ExprVariantGet {
+  const value = GetExpr.deserialize(deserializer);
+  return new ExprVariantGet(value);
 }
 
 }
 
-export class TypeVariantWithTypeParameter extends Type {
+export class ExprVariantCall extends Expr {
 
-constructor (public value: TypeWithTypeParameter) {
+constructor (public value: CallExpr) {
   super();
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeVariantIndex(9);
+  serializer.serializeVariantIndex(15);
   this.value.serialize(serializer);
 }
 
-static load(deserializer: Deserializer): TypeVariantWithTypeParameter {
-  const value = TypeWithTypeParameter.deserialize(deserializer);
-  return new TypeVariantWithTypeParameter(value);
+static load(deserializer: Deserializer): ExprVariantCall {
+  const value = CallExpr.deserialize(deserializer);
+  return new ExprVariantCall(value);
 }
 
 }
 
-export class TypeVariantUnion extends Type {
+export class ExprVariantNew extends Expr {
 
-constructor (public value: Seq<Type>) {
+constructor (public value: CallExpr) {
   super();
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeVariantIndex(10);
-  Helpers.serializeVectorType(this.value, serializer);
+  serializer.serializeVariantIndex(16);
+  this.value.serialize(serializer);
 }
 
-static load(deserializer: Deserializer): TypeVariantUnion {
-  const value = Helpers.deserializeVectorType(deserializer);
-  return new TypeVariantUnion(value);
+static load(deserializer: Deserializer): ExprVariantNew {
+  const value = CallExpr.deserialize(deserializer);
+  return new ExprVariantNew(value);
 }
 
 }
 
-export class TypeVariantIntersection extends Type {
+export class ExprVariantTypeAssertion extends Expr {
 
-constructor (public value: IntersectionType) {
+constructor (public value: TypeAssertion) {
   super();
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeVariantIndex(11);
+  serializer.serializeVariantIndex(17);
   this.value.serialize(serializer);
 }
 
-static load(deserializer: Deserializer): TypeVariantIntersection {
-  const value = IntersectionType.deserialize(deserializer);
-  return new TypeVariantIntersection(value);
+static load(deserializer: Deserializer): ExprVariantTypeAssertion {
+  const value = TypeAssertion.deserialize(deserializer);
+  return new ExprVariantTypeAssertion(value);
 }
 
 }
 
-export class TypeVariantImportedType extends Type {
+export class ExprVariantProjectVariable extends Expr {
 
-constructor (public value: ImportedType) {
+constructor (public value: ProjectReference) {
   super();
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeVariantIndex(12);
+  serializer.serializeVariantIndex(18);
   this.value.serialize(serializer);
 }
 
-static load(deserializer: Deserializer): TypeVariantImportedType {
-  const value = ImportedType.deserialize(deserializer);
-  return new TypeVariantImportedType(value);
+static load(deserializer: Deserializer): ExprVariantProjectVariable {
+  const value = ProjectReference.deserialize(deserializer);
+  return new ExprVariantProjectVariable(value);
 }
 
 }
 
-export class TypeVariantScopeInFile extends Type {
+export class ExprVariantYield extends Expr {
 
-constructor (public value: Identifer) {
+constructor (public value: YieldExpr) {
   super();
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeVariantIndex(13);
+  serializer.serializeVariantIndex(19);
   this.value.serialize(serializer);
 }
 
-static load(deserializer: Deserializer): TypeVariantScopeInFile {
-  const value = Identifer.deserialize(deserializer);
-  return new TypeVariantScopeInFile(value);
+static load(deserializer: Deserializer): ExprVariantYield {
+  const value = YieldExpr.deserialize(deserializer);
+  return new ExprVariantYield(value);
 }
 
 }
 
-export class TypeVariantScopeInGlobal extends Type {
+export class ExprVariantAwait extends Expr {
 
-constructor (public value: Identifer) {
+constructor (public value: Expr) {
   super();
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeVariantIndex(14);
+  serializer.serializeVariantIndex(20);
   this.value.serialize(serializer);
 }
 
-static load(deserializer: Deserializer): TypeVariantScopeInGlobal {
-  const value = Identifer.deserialize(deserializer);
-  return new TypeVariantScopeInGlobal(value);
+static load(deserializer: Deserializer): ExprVariantAwait {
+  const value = Expr.deserialize(deserializer);
+  return new ExprVariantAwait(value);
 }
 
 }
 
-export class TypeVariantStringLiteral extends Type {
+export class ExprVariantClass extends Expr {
 
-constructor (public value: str) {
+constructor (public value: ClassExpr) {
   super();
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeVariantIndex(15);
-  serializer.serializeStr(this.value);
+  serializer.serializeVariantIndex(21);
+  this.value.serialize(serializer);
 }
 
-static load(deserializer: Deserializer): TypeVariantStringLiteral {
-  const value = deserializer.deserializeStr();
-  return new TypeVariantStringLiteral(value);
+static load(deserializer: Deserializer): ExprVariantClass {
+  const value = ClassExpr.deserialize(deserializer);
+  return new ExprVariantClass(value);
 }
 
 }
-export class TypeAlias {
 
-constructor (public name: Identifer, public type_parameter_list: Seq<Identifer>, public document: str, public type: Type) {
+export class ExprVariantThis extends Expr {
+constructor () {
+  super();
 }
 
 public serialize(serializer: Serializer): void {
-  this.name.serialize(serializer);
-  Helpers.serializeVectorIdentifer(this.type_parameter_list, serializer);
-  serializer.serializeStr(this.document);
-  this.type.serialize(serializer);
+  serializer.serializeVariantIndex(22);
 }
 
-static deserialize(deserializer: Deserializer): TypeAlias {
-  const name = Identifer.deserialize(deserializer);
-  const type_parameter_list = Helpers.deserializeVectorIdentifer(deserializer);
-  const document = deserializer.deserializeStr();
-  const type = Type.deserialize(deserializer);
-  return new TypeAlias(name,type_parameter_list,document,type);
+static load(deserializer: Deserializer): ExprVariantThis {
+  return new ExprVariantThis();
 }
 
 }
-export class TypeAssertion {
 
-constructor (public expr: Expr, public type: Type) {
+export class ExprVariantRegExpLiteral extends Expr {
+
+constructor (public value: RegExpLiteral) {
+  super();
 }
 
 public serialize(serializer: Serializer): void {
-  this.expr.serialize(serializer);
-  this.type.serialize(serializer);
+  serializer.serializeVariantIndex(23);
+  this.value.serialize(serializer);
 }
 
-static deserialize(deserializer: Deserializer): TypeAssertion {
-  const expr = Expr.deserialize(deserializer);
-  const type = Type.deserialize(deserializer);
-  return new TypeAssertion(expr,type);
+static load(deserializer: Deserializer): ExprVariantRegExpLiteral {
+  const value = RegExpLiteral.deserialize(deserializer);
+  return new ExprVariantRegExpLiteral(value);
 }
 
 }
-export class TypeWithTypeParameter {
+export class ForInStatement {
 
-constructor (public type: Type, public type_parameter_list: Seq<Type>) {
+constructor (public keyVariableName: str, public objectExpr: Expr, public statementList: Seq<Statement>) {
 }
 
 public serialize(serializer: Serializer): void {
-  this.type.serialize(serializer);
-  Helpers.serializeVectorType(this.type_parameter_list, serializer);
+  serializer.serializeStr(this.keyVariableName);
+  this.objectExpr.serialize(serializer);
+  Helpers.serializeVectorStatement(this.statementList, serializer);
 }
 
-static deserialize(deserializer: Deserializer): TypeWithTypeParameter {
-  const type = Type.deserialize(deserializer);
-  const type_parameter_list = Helpers.deserializeVectorType(deserializer);
-  return new TypeWithTypeParameter(type,type_parameter_list);
+static deserialize(deserializer: Deserializer): ForInStatement {
+  const keyVariableName = deserializer.deserializeStr();
+  const objectExpr = Expr.deserialize(deserializer);
+  const statementList = Helpers.deserializeVectorStatement(deserializer);
+  return new ForInStatement(keyVariableName,objectExpr,statementList);
 }
 
 }
-export abstract class UnaryOperator {
+export abstract class ForInit {
 abstract serialize(serializer: Serializer): void;
 
-static deserialize(deserializer: Deserializer): UnaryOperator {
+static deserialize(deserializer: Deserializer): ForInit {
   const index = deserializer.deserializeVariantIndex();
   switch (index) {
-    case 0: return UnaryOperatorVariantMinus.load(deserializer);
-    case 1: return UnaryOperatorVariantBitwiseNot.load(deserializer);
-    case 2: return UnaryOperatorVariantLogicalNot.load(deserializer);
-    default: throw new Error("Unknown variant index for UnaryOperator: " + index);
+    case 0: return ForInitVariantVariableDefinition.load(deserializer);
+    case 1: return ForInitVariantSet.load(deserializer);
+    case 2: return ForInitVariantExpr.load(deserializer);
+    default: throw new Error("Unknown variant index for ForInit: " + index);
   }
 }
 }
 
 
-export class UnaryOperatorVariantMinus extends UnaryOperator {
-constructor () {
+export class ForInitVariantVariableDefinition extends ForInit {
+
+constructor (public value: VariableDefinitionStatement) {
   super();
 }
 
 public serialize(serializer: Serializer): void {
   serializer.serializeVariantIndex(0);
+  this.value.serialize(serializer);
 }
 
-static load(deserializer: Deserializer): UnaryOperatorVariantMinus {
-  return new UnaryOperatorVariantMinus();
+static load(deserializer: Deserializer): ForInitVariantVariableDefinition {
+  const value = VariableDefinitionStatement.deserialize(deserializer);
+  return new ForInitVariantVariableDefinition(value);
 }
 
 }
 
-export class UnaryOperatorVariantBitwiseNot extends UnaryOperator {
-constructor () {
+export class ForInitVariantSet extends ForInit {
+
+constructor (public value: SetStatement) {
   super();
 }
 
 public serialize(serializer: Serializer): void {
   serializer.serializeVariantIndex(1);
+  this.value.serialize(serializer);
 }
 
-static load(deserializer: Deserializer): UnaryOperatorVariantBitwiseNot {
-  return new UnaryOperatorVariantBitwiseNot();
+static load(deserializer: Deserializer): ForInitVariantSet {
+  const value = SetStatement.deserialize(deserializer);
+  return new ForInitVariantSet(value);
 }
 
 }
 
-export class UnaryOperatorVariantLogicalNot extends UnaryOperator {
-constructor () {
+export class ForInitVariantExpr extends ForInit {
+
+constructor (public value: Expr) {
   super();
 }
 
 public serialize(serializer: Serializer): void {
   serializer.serializeVariantIndex(2);
+  this.value.serialize(serializer);
 }
 
-static load(deserializer: Deserializer): UnaryOperatorVariantLogicalNot {
-  return new UnaryOperatorVariantLogicalNot();
+static load(deserializer: Deserializer): ForInitVariantExpr {
+  const value = Expr.deserialize(deserializer);
+  return new ForInitVariantExpr(value);
 }
 
 }
-export class UnaryOperatorExpr {
+export class ForLoopStatement {
 
-constructor (public operator: UnaryOperator, public expr: Expr) {
+constructor (public init: Optional<ForInit>, public test: Optional<Expr>, public update: Optional<ForUpdate>, public statementList: Seq<Statement>) {
 }
 
 public serialize(serializer: Serializer): void {
-  this.operator.serialize(serializer);
-  this.expr.serialize(serializer);
+  Helpers.serializeOptionForInit(this.init, serializer);
+  Helpers.serializeOptionExpr(this.test, serializer);
+  Helpers.serializeOptionForUpdate(this.update, serializer);
+  Helpers.serializeVectorStatement(this.statementList, serializer);
 }
 
-static deserialize(deserializer: Deserializer): UnaryOperatorExpr {
-  const operator = UnaryOperator.deserialize(deserializer);
-  const expr = Expr.deserialize(deserializer);
-  return new UnaryOperatorExpr(operator,expr);
+static deserialize(deserializer: Deserializer): ForLoopStatement {
+  const init = Helpers.deserializeOptionForInit(deserializer);
+  const test = Helpers.deserializeOptionExpr(deserializer);
+  const update = Helpers.deserializeOptionForUpdate(deserializer);
+  const statementList = Helpers.deserializeVectorStatement(deserializer);
+  return new ForLoopStatement(init,test,update,statementList);
 }
 
 }
-export class Variable {
+export class ForOfStatement {
 
-constructor (public name: Identifer, public document: str, public type: Type, public expr: Expr) {
+constructor (public elementPattern: BindingPattern, public iterableExpr: Expr, public statementList: Seq<Statement>, public isAwait: bool) {
 }
 
 public serialize(serializer: Serializer): void {
-  this.name.serialize(serializer);
-  serializer.serializeStr(this.document);
-  this.type.serialize(serializer);
-  this.expr.serialize(serializer);
+  this.elementPattern.serialize(serializer);
+  this.iterableExpr.serialize(serializer);
+  Helpers.serializeVectorStatement(this.statementList, serializer);
+  serializer.serializeBool(this.isAwait);
 }
 
-static deserialize(deserializer: Deserializer): Variable {
-  const name = Identifer.deserialize(deserializer);
-  const document = deserializer.deserializeStr();
-  const type = Type.deserialize(deserializer);
-  const expr = Expr.deserialize(deserializer);
-  return new Variable(name,document,type,expr);
+static deserialize(deserializer: Deserializer): ForOfStatement {
+  const elementPattern = BindingPattern.deserialize(deserializer);
+  const iterableExpr = Expr.deserialize(deserializer);
+  const statementList = Helpers.deserializeVectorStatement(deserializer);
+  const isAwait = deserializer.deserializeBool();
+  return new ForOfStatement(elementPattern,iterableExpr,statementList,isAwait);
 }
 
 }
-export class VariableDefinitionStatement {
+export class ForStatement {
 
-constructor (public name: Identifer, public type: Type, public expr: Expr, public is_const: bool) {
+constructor (public counterVariableName: str, public untilExpr: Expr, public statementList: Seq<Statement>) {
 }
 
 public serialize(serializer: Serializer): void {
-  this.name.serialize(serializer);
-  this.type.serialize(serializer);
-  this.expr.serialize(serializer);
-  serializer.serializeBool(this.is_const);
+  serializer.serializeStr(this.counterVariableName);
+  this.untilExpr.serialize(serializer);
+  Helpers.serializeVectorStatement(this.statementList, serializer);
 }
 
-static deserialize(deserializer: Deserializer): VariableDefinitionStatement {
-  const name = Identifer.deserialize(deserializer);
-  const type = Type.deserialize(deserializer);
+static deserialize(deserializer: Deserializer): ForStatement {
+  const counterVariableName = deserializer.deserializeStr();
+  const untilExpr = Expr.deserialize(deserializer);
+  const statementList = Helpers.deserializeVectorStatement(deserializer);
+  return new ForStatement(counterVariableName,untilExpr,statementList);
+}
+
+}
+export abstract class ForUpdate {
+abstract serialize(serializer: Serializer): void;
+
+static deserialize(deserializer: Deserializer): ForUpdate {
+  const index = deserializer.deserializeVariantIndex();
+  switch (index) {
+    case 0: return ForUpdateVariantSet.load(deserializer);
+    case 1: return ForUpdateVariantExpr.load(deserializer);
+    default: throw new Error("Unknown variant index for ForUpdate: " + index);
+  }
+}
+}
+
+
+export class ForUpdateVariantSet extends ForUpdate {
+
+constructor (public value: SetStatement) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(0);
+  this.value.serialize(serializer);
+}
+
+static load(deserializer: Deserializer): ForUpdateVariantSet {
+  const value = SetStatement.deserialize(deserializer);
+  return new ForUpdateVariantSet(value);
+}
+
+}
+
+export class ForUpdateVariantExpr extends ForUpdate {
+
+constructor (public value: Expr) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(1);
+  this.value.serialize(serializer);
+}
+
+static load(deserializer: Deserializer): ForUpdateVariantExpr {
+  const value = Expr.deserialize(deserializer);
+  return new ForUpdateVariantExpr(value);
+}
+
+}
+export class Function {
+
+constructor (public name: str, public document: str, public documentTagList: Seq<DocumentTag>, public typeParameterList: Seq<str>, public parameterList: Seq<ParameterWithDocument>, public returnType: Type, public statementList: Seq<Statement>, public visibility: Visibility, public style: Optional<FunctionStyle>, public isAsync: bool, public isGenerator: bool) {
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeStr(this.name);
+  serializer.serializeStr(this.document);
+  Helpers.serializeVectorDocumentTag(this.documentTagList, serializer);
+  Helpers.serializeVectorStr(this.typeParameterList, serializer);
+  Helpers.serializeVectorParameterWithDocument(this.parameterList, serializer);
+  this.returnType.serialize(serializer);
+  Helpers.serializeVectorStatement(this.statementList, serializer);
+  this.visibility.serialize(serializer);
+  Helpers.serializeOptionFunctionStyle(this.style, serializer);
+  serializer.serializeBool(this.isAsync);
+  serializer.serializeBool(this.isGenerator);
+}
+
+static deserialize(deserializer: Deserializer): Function {
+  const name = deserializer.deserializeStr();
+  const document = deserializer.deserializeStr();
+  const documentTagList = Helpers.deserializeVectorDocumentTag(deserializer);
+  const typeParameterList = Helpers.deserializeVectorStr(deserializer);
+  const parameterList = Helpers.deserializeVectorParameterWithDocument(deserializer);
+  const returnType = Type.deserialize(deserializer);
+  const statementList = Helpers.deserializeVectorStatement(deserializer);
+  const visibility = Visibility.deserialize(deserializer);
+  const style = Helpers.deserializeOptionFunctionStyle(deserializer);
+  const isAsync = deserializer.deserializeBool();
+  const isGenerator = deserializer.deserializeBool();
+  return new Function(name,document,documentTagList,typeParameterList,parameterList,returnType,statementList,visibility,style,isAsync,isGenerator);
+}
+
+}
+export class FunctionDefinitionStatement {
+
+constructor (public name: str, public typeParameterList: Seq<str>, public parameterList: Seq<ParameterWithDocument>, public returnType: Type, public statementList: Seq<Statement>, public isAsync: bool, public isGenerator: bool, public style: Optional<FunctionStyle>) {
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeStr(this.name);
+  Helpers.serializeVectorStr(this.typeParameterList, serializer);
+  Helpers.serializeVectorParameterWithDocument(this.parameterList, serializer);
+  this.returnType.serialize(serializer);
+  Helpers.serializeVectorStatement(this.statementList, serializer);
+  serializer.serializeBool(this.isAsync);
+  serializer.serializeBool(this.isGenerator);
+  Helpers.serializeOptionFunctionStyle(this.style, serializer);
+}
+
+static deserialize(deserializer: Deserializer): FunctionDefinitionStatement {
+  const name = deserializer.deserializeStr();
+  const typeParameterList = Helpers.deserializeVectorStr(deserializer);
+  const parameterList = Helpers.deserializeVectorParameterWithDocument(deserializer);
+  const returnType = Type.deserialize(deserializer);
+  const statementList = Helpers.deserializeVectorStatement(deserializer);
+  const isAsync = deserializer.deserializeBool();
+  const isGenerator = deserializer.deserializeBool();
+  const style = Helpers.deserializeOptionFunctionStyle(deserializer);
+  return new FunctionDefinitionStatement(name,typeParameterList,parameterList,returnType,statementList,isAsync,isGenerator,style);
+}
+
+}
+export abstract class FunctionStyle {
+abstract serialize(serializer: Serializer): void;
+
+static deserialize(deserializer: Deserializer): FunctionStyle {
+  const index = deserializer.deserializeVariantIndex();
+  switch (index) {
+    case 0: return FunctionStyleVariantArrow.load(deserializer);
+    case 1: return FunctionStyleVariantDeclaration.load(deserializer);
+    default: throw new Error("Unknown variant index for FunctionStyle: " + index);
+  }
+}
+}
+
+
+export class FunctionStyleVariantArrow extends FunctionStyle {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(0);
+}
+
+static load(deserializer: Deserializer): FunctionStyleVariantArrow {
+  return new FunctionStyleVariantArrow();
+}
+
+}
+
+export class FunctionStyleVariantDeclaration extends FunctionStyle {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(1);
+}
+
+static load(deserializer: Deserializer): FunctionStyleVariantDeclaration {
+  return new FunctionStyleVariantDeclaration();
+}
+
+}
+export class FunctionType {
+
+constructor (public typeParameterList: Seq<str>, public parameterList: Seq<Type>, public optionalParameterList: Seq<Type>, public restParameter: Optional<Type>, public returnType: Type) {
+}
+
+public serialize(serializer: Serializer): void {
+  Helpers.serializeVectorStr(this.typeParameterList, serializer);
+  Helpers.serializeVectorType(this.parameterList, serializer);
+  Helpers.serializeVectorType(this.optionalParameterList, serializer);
+  Helpers.serializeOptionType(this.restParameter, serializer);
+  this.returnType.serialize(serializer);
+}
+
+static deserialize(deserializer: Deserializer): FunctionType {
+  const typeParameterList = Helpers.deserializeVectorStr(deserializer);
+  const parameterList = Helpers.deserializeVectorType(deserializer);
+  const optionalParameterList = Helpers.deserializeVectorType(deserializer);
+  const restParameter = Helpers.deserializeOptionType(deserializer);
+  const returnType = Type.deserialize(deserializer);
+  return new FunctionType(typeParameterList,parameterList,optionalParameterList,restParameter,returnType);
+}
+
+}
+export abstract class GenerateError {
+abstract serialize(serializer: Serializer): void;
+
+static deserialize(deserializer: Deserializer): GenerateError {
+  const index = deserializer.deserializeVariantIndex();
+  switch (index) {
+    case 0: return GenerateErrorVariantDecodeCode.load(deserializer);
+    case 1: return GenerateErrorVariantDecodeOption.load(deserializer);
+    case 2: return GenerateErrorVariantValidation.load(deserializer);
+    default: throw new Error("Unknown variant index for GenerateError: " + index);
+  }
+}
+}
+
+
+export class GenerateErrorVariantDecodeCode extends GenerateError {
+
+constructor (public value: str) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(0);
+  serializer.serializeStr(this.value);
+}
+
+static load(deserializer: Deserializer): GenerateErrorVariantDecodeCode {
+  const value = deserializer.deserializeStr();
+  return new GenerateErrorVariantDecodeCode(value);
+}
+
+}
+
+export class GenerateErrorVariantDecodeOption extends GenerateError {
+
+constructor (public value: str) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(1);
+  serializer.serializeStr(this.value);
+}
+
+static load(deserializer: Deserializer): GenerateErrorVariantDecodeOption {
+  const value = deserializer.deserializeStr();
+  return new GenerateErrorVariantDecodeOption(value);
+}
+
+}
+
+export class GenerateErrorVariantValidation extends GenerateError {
+
+constructor (public value: Seq<ValidationError>) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(2);
+  Helpers.serializeVectorValidationError(this.value, serializer);
+}
+
+static load(deserializer: Deserializer): GenerateErrorVariantValidation {
+  const value = Helpers.deserializeVectorValidationError(deserializer);
+  return new GenerateErrorVariantValidation(value);
+}
+
+}
+export class GenerateOption {
+
+constructor (public codeType: CodeType, public moduleFormat: ModuleFormat, public functionStyle: FunctionStyle, public isAsciiOnly: bool, public commonJsDefaultExport: CommonJsDefaultExport) {
+}
+
+public serialize(serializer: Serializer): void {
+  this.codeType.serialize(serializer);
+  this.moduleFormat.serialize(serializer);
+  this.functionStyle.serialize(serializer);
+  serializer.serializeBool(this.isAsciiOnly);
+  this.commonJsDefaultExport.serialize(serializer);
+}
+
+static deserialize(deserializer: Deserializer): GenerateOption {
+  const codeType = CodeType.deserialize(deserializer);
+  const moduleFormat = ModuleFormat.deserialize(deserializer);
+  const functionStyle = FunctionStyle.deserialize(deserializer);
+  const isAsciiOnly = deserializer.deserializeBool();
+  const commonJsDefaultExport = CommonJsDefaultExport.deserialize(deserializer);
+  return new GenerateOption(codeType,moduleFormat,functionStyle,isAsciiOnly,commonJsDefaultExport);
+}
+
+}
+export abstract class GenerateResult {
+abstract serialize(serializer: Serializer): void;
+
+static deserialize(deserializer: Deserializer): GenerateResult {
+  const index = deserializer.deserializeVariantIndex();
+  switch (index) {
+    case 0: return GenerateResultVariantOk.load(deserializer);
+    case 1: return GenerateResultVariantError.load(deserializer);
+    default: throw new Error("Unknown variant index for GenerateResult: " + index);
+  }
+}
+}
+
+
+export class GenerateResultVariantOk extends GenerateResult {
+
+constructor (public value: str) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(0);
+  serializer.serializeStr(this.value);
+}
+
+static load(deserializer: Deserializer): GenerateResultVariantOk {
+  const value = deserializer.deserializeStr();
+  return new GenerateResultVariantOk(value);
+}
+
+}
+
+export class GenerateResultVariantError extends GenerateResult {
+
+constructor (public value: GenerateError) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(1);
+  this.value.serialize(serializer);
+}
+
+static load(deserializer: Deserializer): GenerateResultVariantError {
+  const value = GenerateError.deserialize(deserializer);
+  return new GenerateResultVariantError(value);
+}
+
+}
+export class GetExpr {
+
+constructor (public expr: Expr, public propertyExpr: Expr, public isOptionalChain: bool) {
+}
+
+public serialize(serializer: Serializer): void {
+  this.expr.serialize(serializer);
+  this.propertyExpr.serialize(serializer);
+  serializer.serializeBool(this.isOptionalChain);
+}
+
+static deserialize(deserializer: Deserializer): GetExpr {
+  const expr = Expr.deserialize(deserializer);
+  const propertyExpr = Expr.deserialize(deserializer);
+  const isOptionalChain = deserializer.deserializeBool();
+  return new GetExpr(expr,propertyExpr,isOptionalChain);
+}
+
+}
+export class IfStatement {
+
+constructor (public condition: Expr, public thenStatementList: Seq<Statement>) {
+}
+
+public serialize(serializer: Serializer): void {
+  this.condition.serialize(serializer);
+  Helpers.serializeVectorStatement(this.thenStatementList, serializer);
+}
+
+static deserialize(deserializer: Deserializer): IfStatement {
+  const condition = Expr.deserialize(deserializer);
+  const thenStatementList = Helpers.deserializeVectorStatement(deserializer);
+  return new IfStatement(condition,thenStatementList);
+}
+
+}
+export class ImportedType {
+
+constructor (public moduleName: str, public name: str) {
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeStr(this.moduleName);
+  serializer.serializeStr(this.name);
+}
+
+static deserialize(deserializer: Deserializer): ImportedType {
+  const moduleName = deserializer.deserializeStr();
+  const name = deserializer.deserializeStr();
+  return new ImportedType(moduleName,name);
+}
+
+}
+export class ImportedVariable {
+
+constructor (public moduleName: str, public name: str) {
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeStr(this.moduleName);
+  serializer.serializeStr(this.name);
+}
+
+static deserialize(deserializer: Deserializer): ImportedVariable {
+  const moduleName = deserializer.deserializeStr();
+  const name = deserializer.deserializeStr();
+  return new ImportedVariable(moduleName,name);
+}
+
+}
+export class IntersectionType {
+
+constructor (public left: Type, public right: Type) {
+}
+
+public serialize(serializer: Serializer): void {
+  this.left.serialize(serializer);
+  this.right.serialize(serializer);
+}
+
+static deserialize(deserializer: Deserializer): IntersectionType {
+  const left = Type.deserialize(deserializer);
+  const right = Type.deserialize(deserializer);
+  return new IntersectionType(left,right);
+}
+
+}
+export class KeyValue {
+
+constructor (public key: str, public value: Expr) {
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeStr(this.key);
+  this.value.serialize(serializer);
+}
+
+static deserialize(deserializer: Deserializer): KeyValue {
+  const key = deserializer.deserializeStr();
+  const value = Expr.deserialize(deserializer);
+  return new KeyValue(key,value);
+}
+
+}
+export class LabeledStatement {
+
+constructor (public label: str, public statement: Statement) {
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeStr(this.label);
+  this.statement.serialize(serializer);
+}
+
+static deserialize(deserializer: Deserializer): LabeledStatement {
+  const label = deserializer.deserializeStr();
+  const statement = Statement.deserialize(deserializer);
+  return new LabeledStatement(label,statement);
+}
+
+}
+export class LambdaExpr {
+
+constructor (public parameterList: Seq<Parameter>, public typeParameterList: Seq<str>, public returnType: Type, public statementList: Seq<Statement>, public isAsync: bool, public isGenerator: bool) {
+}
+
+public serialize(serializer: Serializer): void {
+  Helpers.serializeVectorParameter(this.parameterList, serializer);
+  Helpers.serializeVectorStr(this.typeParameterList, serializer);
+  this.returnType.serialize(serializer);
+  Helpers.serializeVectorStatement(this.statementList, serializer);
+  serializer.serializeBool(this.isAsync);
+  serializer.serializeBool(this.isGenerator);
+}
+
+static deserialize(deserializer: Deserializer): LambdaExpr {
+  const parameterList = Helpers.deserializeVectorParameter(deserializer);
+  const typeParameterList = Helpers.deserializeVectorStr(deserializer);
+  const returnType = Type.deserialize(deserializer);
+  const statementList = Helpers.deserializeVectorStatement(deserializer);
+  const isAsync = deserializer.deserializeBool();
+  const isGenerator = deserializer.deserializeBool();
+  return new LambdaExpr(parameterList,typeParameterList,returnType,statementList,isAsync,isGenerator);
+}
+
+}
+export abstract class Member {
+abstract serialize(serializer: Serializer): void;
+
+static deserialize(deserializer: Deserializer): Member {
+  const index = deserializer.deserializeVariantIndex();
+  switch (index) {
+    case 0: return MemberVariantSpread.load(deserializer);
+    case 1: return MemberVariantKeyValue.load(deserializer);
+    case 2: return MemberVariantComputedKeyValue.load(deserializer);
+    case 3: return MemberVariantMethod.load(deserializer);
+    case 4: return MemberVariantGetter.load(deserializer);
+    case 5: return MemberVariantSetter.load(deserializer);
+    case 6: return MemberVariantCommented.load(deserializer);
+    default: throw new Error("Unknown variant index for Member: " + index);
+  }
+}
+}
+
+
+export class MemberVariantSpread extends Member {
+
+constructor (public value: Expr) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(0);
+  this.value.serialize(serializer);
+}
+
+static load(deserializer: Deserializer): MemberVariantSpread {
+  const value = Expr.deserialize(deserializer);
+  return new MemberVariantSpread(value);
+}
+
+}
+
+export class MemberVariantKeyValue extends Member {
+
+constructor (public value: KeyValue) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(1);
+  this.value.serialize(serializer);
+}
+
+static load(deserializer: Deserializer): MemberVariantKeyValue {
+  const value = KeyValue.deserialize(deserializer);
+  return new MemberVariantKeyValue(value);
+}
+
+}
+
+export class MemberVariantComputedKeyValue extends Member {
+
+constructor (public value: ComputedKeyValue) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(2);
+  this.value.serialize(serializer);
+}
+
+static load(deserializer: Deserializer): MemberVariantComputedKeyValue {
+  const value = ComputedKeyValue.deserialize(deserializer);
+  return new MemberVariantComputedKeyValue(value);
+}
+
+}
+
+export class MemberVariantMethod extends Member {
+
+constructor (public value: ObjectMethod) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(3);
+  this.value.serialize(serializer);
+}
+
+static load(deserializer: Deserializer): MemberVariantMethod {
+  const value = ObjectMethod.deserialize(deserializer);
+  return new MemberVariantMethod(value);
+}
+
+}
+
+export class MemberVariantGetter extends Member {
+
+constructor (public value: ObjectGetter) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(4);
+  this.value.serialize(serializer);
+}
+
+static load(deserializer: Deserializer): MemberVariantGetter {
+  const value = ObjectGetter.deserialize(deserializer);
+  return new MemberVariantGetter(value);
+}
+
+}
+
+export class MemberVariantSetter extends Member {
+
+constructor (public value: ObjectSetter) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(5);
+  this.value.serialize(serializer);
+}
+
+static load(deserializer: Deserializer): MemberVariantSetter {
+  const value = ObjectSetter.deserialize(deserializer);
+  return new MemberVariantSetter(value);
+}
+
+}
+
+export class MemberVariantCommented extends Member {
+
+constructor (public value: CommentedMember) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(6);
+  this.value.serialize(serializer);
+}
+
+static load(deserializer: Deserializer): MemberVariantCommented {
+  const value = CommentedMember.deserialize(deserializer);
+  return new MemberVariantCommented(value);
+}
+
+}
+export class MemberType {
+
+constructor (public name: str, public required: bool, public type: Type, public document: str, public documentTagList: Seq<DocumentTag>) {
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeStr(this.name);
+  serializer.serializeBool(this.required);
+  this.type.serialize(serializer);
+  serializer.serializeStr(this.document);
+  Helpers.serializeVectorDocumentTag(this.documentTagList, serializer);
+}
+
+static deserialize(deserializer: Deserializer): MemberType {
+  const name = deserializer.deserializeStr();
+  const required = deserializer.deserializeBool();
+  const type = Type.deserialize(deserializer);
+  const document = deserializer.deserializeStr();
+  const documentTagList = Helpers.deserializeVectorDocumentTag(deserializer);
+  return new MemberType(name,required,type,document,documentTagList);
+}
+
+}
+export abstract class ModuleFormat {
+abstract serialize(serializer: Serializer): void;
+
+static deserialize(deserializer: Deserializer): ModuleFormat {
+  const index = deserializer.deserializeVariantIndex();
+  switch (index) {
+    case 0: return ModuleFormatVariantEsModule.load(deserializer);
+    case 1: return ModuleFormatVariantCommonJs.load(deserializer);
+    case 2: return ModuleFormatVariantUmd.load(deserializer);
+    default: throw new Error("Unknown variant index for ModuleFormat: " + index);
+  }
+}
+}
+
+
+export class ModuleFormatVariantEsModule extends ModuleFormat {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(0);
+}
+
+static load(deserializer: Deserializer): ModuleFormatVariantEsModule {
+  return new ModuleFormatVariantEsModule();
+}
+
+}
+
+export class ModuleFormatVariantCommonJs extends ModuleFormat {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(1);
+}
+
+static load(deserializer: Deserializer): ModuleFormatVariantCommonJs {
+  return new ModuleFormatVariantCommonJs();
+}
+
+}
+
+export class ModuleFormatVariantUmd extends ModuleFormat {
+
+constructor (public value: str) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(2);
+  serializer.serializeStr(this.value);
+}
+
+static load(deserializer: Deserializer): ModuleFormatVariantUmd {
+  const value = deserializer.deserializeStr();
+  return new ModuleFormatVariantUmd(value);
+}
+
+}
+export class ObjectGetter {
+
+constructor (public key: PropertyKey, public returnType: Type, public statementList: Seq<Statement>) {
+}
+
+public serialize(serializer: Serializer): void {
+  this.key.serialize(serializer);
+  this.returnType.serialize(serializer);
+  Helpers.serializeVectorStatement(this.statementList, serializer);
+}
+
+static deserialize(deserializer: Deserializer): ObjectGetter {
+  const key = PropertyKey.deserialize(deserializer);
+  const returnType = Type.deserialize(deserializer);
+  const statementList = Helpers.deserializeVectorStatement(deserializer);
+  return new ObjectGetter(key,returnType,statementList);
+}
+
+}
+export class ObjectMethod {
+
+constructor (public key: PropertyKey, public typeParameterList: Seq<str>, public parameterList: Seq<Parameter>, public returnType: Type, public statementList: Seq<Statement>, public isAsync: bool, public isGenerator: bool) {
+}
+
+public serialize(serializer: Serializer): void {
+  this.key.serialize(serializer);
+  Helpers.serializeVectorStr(this.typeParameterList, serializer);
+  Helpers.serializeVectorParameter(this.parameterList, serializer);
+  this.returnType.serialize(serializer);
+  Helpers.serializeVectorStatement(this.statementList, serializer);
+  serializer.serializeBool(this.isAsync);
+  serializer.serializeBool(this.isGenerator);
+}
+
+static deserialize(deserializer: Deserializer): ObjectMethod {
+  const key = PropertyKey.deserialize(deserializer);
+  const typeParameterList = Helpers.deserializeVectorStr(deserializer);
+  const parameterList = Helpers.deserializeVectorParameter(deserializer);
+  const returnType = Type.deserialize(deserializer);
+  const statementList = Helpers.deserializeVectorStatement(deserializer);
+  const isAsync = deserializer.deserializeBool();
+  const isGenerator = deserializer.deserializeBool();
+  return new ObjectMethod(key,typeParameterList,parameterList,returnType,statementList,isAsync,isGenerator);
+}
+
+}
+export class ObjectPattern {
+
+constructor (public memberList: Seq<ObjectPatternMember>, public rest: Optional<str>) {
+}
+
+public serialize(serializer: Serializer): void {
+  Helpers.serializeVectorObjectPatternMember(this.memberList, serializer);
+  Helpers.serializeOptionStr(this.rest, serializer);
+}
+
+static deserialize(deserializer: Deserializer): ObjectPattern {
+  const memberList = Helpers.deserializeVectorObjectPatternMember(deserializer);
+  const rest = Helpers.deserializeOptionStr(deserializer);
+  return new ObjectPattern(memberList,rest);
+}
+
+}
+export class ObjectPatternMember {
+
+constructor (public key: str, public pattern: BindingPattern, public defaultExpr: Optional<Expr>) {
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeStr(this.key);
+  this.pattern.serialize(serializer);
+  Helpers.serializeOptionExpr(this.defaultExpr, serializer);
+}
+
+static deserialize(deserializer: Deserializer): ObjectPatternMember {
+  const key = deserializer.deserializeStr();
+  const pattern = BindingPattern.deserialize(deserializer);
+  const defaultExpr = Helpers.deserializeOptionExpr(deserializer);
+  return new ObjectPatternMember(key,pattern,defaultExpr);
+}
+
+}
+export class ObjectSetter {
+
+constructor (public key: PropertyKey, public parameter: Parameter, public statementList: Seq<Statement>) {
+}
+
+public serialize(serializer: Serializer): void {
+  this.key.serialize(serializer);
+  this.parameter.serialize(serializer);
+  Helpers.serializeVectorStatement(this.statementList, serializer);
+}
+
+static deserialize(deserializer: Deserializer): ObjectSetter {
+  const key = PropertyKey.deserialize(deserializer);
+  const parameter = Parameter.deserialize(deserializer);
+  const statementList = Helpers.deserializeVectorStatement(deserializer);
+  return new ObjectSetter(key,parameter,statementList);
+}
+
+}
+export class Parameter {
+
+constructor (public pattern: BindingPattern, public type: Type, public kind: ParameterKind) {
+}
+
+public serialize(serializer: Serializer): void {
+  this.pattern.serialize(serializer);
+  this.type.serialize(serializer);
+  this.kind.serialize(serializer);
+}
+
+static deserialize(deserializer: Deserializer): Parameter {
+  const pattern = BindingPattern.deserialize(deserializer);
+  const type = Type.deserialize(deserializer);
+  const kind = ParameterKind.deserialize(deserializer);
+  return new Parameter(pattern,type,kind);
+}
+
+}
+export abstract class ParameterKind {
+abstract serialize(serializer: Serializer): void;
+
+static deserialize(deserializer: Deserializer): ParameterKind {
+  const index = deserializer.deserializeVariantIndex();
+  switch (index) {
+    case 0: return ParameterKindVariantRequired.load(deserializer);
+    case 1: return ParameterKindVariantOptional.load(deserializer);
+    case 2: return ParameterKindVariantDefault.load(deserializer);
+    case 3: return ParameterKindVariantRest.load(deserializer);
+    default: throw new Error("Unknown variant index for ParameterKind: " + index);
+  }
+}
+}
+
+
+export class ParameterKindVariantRequired extends ParameterKind {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(0);
+}
+
+static load(deserializer: Deserializer): ParameterKindVariantRequired {
+  return new ParameterKindVariantRequired();
+}
+
+}
+
+export class ParameterKindVariantOptional extends ParameterKind {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(1);
+}
+
+static load(deserializer: Deserializer): ParameterKindVariantOptional {
+  return new ParameterKindVariantOptional();
+}
+
+}
+
+export class ParameterKindVariantDefault extends ParameterKind {
+
+constructor (public value: Expr) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(2);
+  this.value.serialize(serializer);
+}
+
+static load(deserializer: Deserializer): ParameterKindVariantDefault {
+  const value = Expr.deserialize(deserializer);
+  return new ParameterKindVariantDefault(value);
+}
+
+}
+
+export class ParameterKindVariantRest extends ParameterKind {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(3);
+}
+
+static load(deserializer: Deserializer): ParameterKindVariantRest {
+  return new ParameterKindVariantRest();
+}
+
+}
+export class ParameterWithDocument {
+
+constructor (public name: str, public document: str, public type: Type, public kind: ParameterKind) {
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeStr(this.name);
+  serializer.serializeStr(this.document);
+  this.type.serialize(serializer);
+  this.kind.serialize(serializer);
+}
+
+static deserialize(deserializer: Deserializer): ParameterWithDocument {
+  const name = deserializer.deserializeStr();
+  const document = deserializer.deserializeStr();
+  const type = Type.deserialize(deserializer);
+  const kind = ParameterKind.deserialize(deserializer);
+  return new ParameterWithDocument(name,document,type,kind);
+}
+
+}
+export class Pattern {
+
+constructor (public caseList: Seq<Expr>, public statementList: Seq<Statement>) {
+}
+
+public serialize(serializer: Serializer): void {
+  Helpers.serializeVectorExpr(this.caseList, serializer);
+  Helpers.serializeVectorStatement(this.statementList, serializer);
+}
+
+static deserialize(deserializer: Deserializer): Pattern {
+  const caseList = Helpers.deserializeVectorExpr(deserializer);
+  const statementList = Helpers.deserializeVectorStatement(deserializer);
+  return new Pattern(caseList,statementList);
+}
+
+}
+export class Project {
+
+constructor (public fileList: Seq<ProjectFile>) {
+}
+
+public serialize(serializer: Serializer): void {
+  Helpers.serializeVectorProjectFile(this.fileList, serializer);
+}
+
+static deserialize(deserializer: Deserializer): Project {
+  const fileList = Helpers.deserializeVectorProjectFile(deserializer);
+  return new Project(fileList);
+}
+
+}
+export class ProjectFile {
+
+constructor (public filePath: str, public code: Code) {
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeStr(this.filePath);
+  this.code.serialize(serializer);
+}
+
+static deserialize(deserializer: Deserializer): ProjectFile {
+  const filePath = deserializer.deserializeStr();
+  const code = Code.deserialize(deserializer);
+  return new ProjectFile(filePath,code);
+}
+
+}
+export class ProjectReference {
+
+constructor (public filePath: str, public name: str) {
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeStr(this.filePath);
+  serializer.serializeStr(this.name);
+}
+
+static deserialize(deserializer: Deserializer): ProjectReference {
+  const filePath = deserializer.deserializeStr();
+  const name = deserializer.deserializeStr();
+  return new ProjectReference(filePath,name);
+}
+
+}
+export abstract class PropertyKey {
+abstract serialize(serializer: Serializer): void;
+
+static deserialize(deserializer: Deserializer): PropertyKey {
+  const index = deserializer.deserializeVariantIndex();
+  switch (index) {
+    case 0: return PropertyKeyVariantName.load(deserializer);
+    case 1: return PropertyKeyVariantComputed.load(deserializer);
+    default: throw new Error("Unknown variant index for PropertyKey: " + index);
+  }
+}
+}
+
+
+export class PropertyKeyVariantName extends PropertyKey {
+
+constructor (public value: str) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(0);
+  serializer.serializeStr(this.value);
+}
+
+static load(deserializer: Deserializer): PropertyKeyVariantName {
+  const value = deserializer.deserializeStr();
+  return new PropertyKeyVariantName(value);
+}
+
+}
+
+export class PropertyKeyVariantComputed extends PropertyKey {
+
+constructor (public value: Expr) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(1);
+  this.value.serialize(serializer);
+}
+
+static load(deserializer: Deserializer): PropertyKeyVariantComputed {
+  const value = Expr.deserialize(deserializer);
+  return new PropertyKeyVariantComputed(value);
+}
+
+}
+export class RegExpLiteral {
+
+constructor (public pattern: str, public flags: str) {
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeStr(this.pattern);
+  serializer.serializeStr(this.flags);
+}
+
+static deserialize(deserializer: Deserializer): RegExpLiteral {
+  const pattern = deserializer.deserializeStr();
+  const flags = deserializer.deserializeStr();
+  return new RegExpLiteral(pattern,flags);
+}
+
+}
+export class SetStatement {
+
+constructor (public target: Expr, public operatorMaybe: Optional<BinaryOperator>, public expr: Expr) {
+}
+
+public serialize(serializer: Serializer): void {
+  this.target.serialize(serializer);
+  Helpers.serializeOptionBinaryOperator(this.operatorMaybe, serializer);
+  this.expr.serialize(serializer);
+}
+
+static deserialize(deserializer: Deserializer): SetStatement {
+  const target = Expr.deserialize(deserializer);
+  const operatorMaybe = Helpers.deserializeOptionBinaryOperator(deserializer);
+  const expr = Expr.deserialize(deserializer);
+  return new SetStatement(target,operatorMaybe,expr);
+}
+
+}
+export abstract class Statement {
+abstract serialize(serializer: Serializer): void;
+
+static deserialize(deserializer: Deserializer): Statement {
+  const index = deserializer.deserializeVariantIndex();
+  switch (index) {
+    case 0: return StatementVariantEvaluateExpr.load(deserializer);
+    case 1: return StatementVariantSet.load(deserializer);
+    case 2: return StatementVariantIf.load(deserializer);
+    case 3: return StatementVariantThrowError.load(deserializer);
+    case 4: return StatementVariantReturn.load(deserializer);
+    case 5: return StatementVariantReturnVoid.load(deserializer);
+    case 6: return StatementVariantContinue.load(deserializer);
+    case 7: return StatementVariantVariableDefinition.load(deserializer);
+    case 8: return StatementVariantFunctionDefinition.load(deserializer);
+    case 9: return StatementVariantFor.load(deserializer);
+    case 10: return StatementVariantForOf.load(deserializer);
+    case 11: return StatementVariantWhileTrue.load(deserializer);
+    case 12: return StatementVariantBreak.load(deserializer);
+    case 13: return StatementVariantSwitch.load(deserializer);
+    case 14: return StatementVariantForLoop.load(deserializer);
+    case 15: return StatementVariantWhile.load(deserializer);
+    case 16: return StatementVariantDoWhile.load(deserializer);
+    case 17: return StatementVariantForIn.load(deserializer);
+    case 18: return StatementVariantLabeled.load(deserializer);
+    case 19: return StatementVariantLabeledBreak.load(deserializer);
+    case 20: return StatementVariantLabeledContinue.load(deserializer);
+    case 21: return StatementVariantCommented.load(deserializer);
+    default: throw new Error("Unknown variant index for Statement: " + index);
+  }
+}
+}
+
+
+export class StatementVariantEvaluateExpr extends Statement {
+
+constructor (public value: Expr) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(0);
+  this.value.serialize(serializer);
+}
+
+static load(deserializer: Deserializer): StatementVariantEvaluateExpr {
+  const value = Expr.deserialize(deserializer);
+  return new StatementVariantEvaluateExpr(value);
+}
+
+}
+
+export class StatementVariantSet extends Statement {
+
+constructor (public value: SetStatement) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(1);
+  this.value.serialize(serializer);
+}
+
+static load(deserializer: Deserializer): StatementVariantSet {
+  const value = SetStatement.deserialize(deserializer);
+  return new StatementVariantSet(value);
+}
+
+}
+
+export class StatementVariantIf extends Statement {
+
+constructor (public value: IfStatement) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(2);
+  this.value.serialize(serializer);
+}
+
+static load(deserializer: Deserializer): StatementVariantIf {
+  const value = IfStatement.deserialize(deserializer);
+  return new StatementVariantIf(value);
+}
+
+}
+
+export class StatementVariantThrowError extends Statement {
+
+constructor (public value: Expr) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(3);
+  this.value.serialize(serializer);
+}
+
+static load(deserializer: Deserializer): StatementVariantThrowError {
+  const value = Expr.deserialize(deserializer);
+  return new StatementVariantThrowError(value);
+}
+
+}
+
+export class StatementVariantReturn extends Statement {
+
+constructor (public value: Expr) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(4);
+  this.value.serialize(serializer);
+}
+
+static load(deserializer: Deserializer): StatementVariantReturn {
+  const value = Expr.deserialize(deserializer);
+  return new StatementVariantReturn(value);
+}
+
+}
+
+export class StatementVariantReturnVoid extends Statement {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(5);
+}
+
+static load(deserializer: Deserializer): StatementVariantReturnVoid {
+  return new StatementVariantReturnVoid();
+}
+
+}
+
+export class StatementVariantContinue extends Statement {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(6);
+}
+
+static load(deserializer: Deserializer): StatementVariantContinue {
+  return new StatementVariantContinue();
+}
+
+}
+
+export class StatementVariantVariableDefinition extends Statement {
+
+constructor (public value: VariableDefinitionStatement) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(7);
+  this.value.serialize(serializer);
+}
+
+static load(deserializer: Deserializer): StatementVariantVariableDefinition {
+  const value = VariableDefinitionStatement.deserialize(deserializer);
+  return new StatementVariantVariableDefinition(value);
+}
+
+}
+
+export class StatementVariantFunctionDefinition extends Statement {
+
+constructor (public value: FunctionDefinitionStatement) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(8);
+  this.value.serialize(serializer);
+}
+
+static load(deserializer: Deserializer): StatementVariantFunctionDefinition {
+  const value = FunctionDefinitionStatement.deserialize(deserializer);
+  return new StatementVariantFunctionDefinition(value);
+}
+
+}
+
+export class StatementVariantFor extends Statement {
+
+constructor (public value: ForStatement) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(9);
+  this.value.serialize(serializer);
+}
+
+static load(deserializer: Deserializer): StatementVariantFor {
+  const value = ForStatement.deserialize(deserializer);
+  return new StatementVariantFor(value);
+}
+
+}
+
+export class StatementVariantForOf extends Statement {
+
+constructor (public value: ForOfStatement) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(10);
+  this.value.serialize(serializer);
+}
+
+static load(deserializer: Deserializer): StatementVariantForOf {
+  const value = ForOfStatement.deserialize(deserializer);
+  return new StatementVariantForOf(value);
+}
+
+}
+
+export class StatementVariantWhileTrue extends Statement {
+
+constructor (public value: Seq<Statement>) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(11);
+  Helpers.serializeVectorStatement(this.value, serializer);
+}
+
+static load(deserializer: Deserializer): StatementVariantWhileTrue {
+  const value = Helpers.deserializeVectorStatement(deserializer);
+  return new StatementVariantWhileTrue(value);
+}
+
+}
+
+export class StatementVariantBreak extends Statement {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(12);
+}
+
+static load(deserializer: Deserializer): StatementVariantBreak {
+  return new StatementVariantBreak();
+}
+
+}
+
+export class StatementVariantSwitch extends Statement {
+
+constructor (public value: SwitchStatement) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(13);
+  this.value.serialize(serializer);
+}
+
+static load(deserializer: Deserializer): StatementVariantSwitch {
+  const value = SwitchStatement.deserialize(deserializer);
+  return new StatementVariantSwitch(value);
+}
+
+}
+
+export class StatementVariantForLoop extends Statement {
+
+constructor (public value: ForLoopStatement) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(14);
+  this.value.serialize(serializer);
+}
+
+static load(deserializer: Deserializer): StatementVariantForLoop {
+  const value = ForLoopStatement.deserialize(deserializer);
+  return new StatementVariantForLoop(value);
+}
+
+}
+
+export class StatementVariantWhile extends Statement {
+
+constructor (public value: WhileStatement) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(15);
+  this.value.serialize(serializer);
+}
+
+static load(deserializer: Deserializer): StatementVariantWhile {
+  const value = WhileStatement.deserialize(deserializer);
+  return new StatementVariantWhile(value);
+}
+
+}
+
+export class StatementVariantDoWhile extends Statement {
+
+constructor (public value: WhileStatement) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(16);
+  this.value.serialize(serializer);
+}
+
+static load(deserializer: Deserializer): StatementVariantDoWhile {
+  const value = WhileStatement.deserialize(deserializer);
+  return new StatementVariantDoWhile(value);
+}
+
+}
+
+export class StatementVariantForIn extends Statement {
+
+constructor (public value: ForInStatement) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(17);
+  this.value.serialize(serializer);
+}
+
+static load(deserializer: Deserializer): StatementVariantForIn {
+  const value = ForInStatement.deserialize(deserializer);
+  return new StatementVariantForIn(value);
+}
+
+}
+
+export class StatementVariantLabeled extends Statement {
+
+constructor (public value: LabeledStatement) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(18);
+  this.value.serialize(serializer);
+}
+
+static load(deserializer: Deserializer): StatementVariantLabeled {
+  const value = LabeledStatement.deserialize(deserializer);
+  return new StatementVariantLabeled(value);
+}
+
+}
+
+export class StatementVariantLabeledBreak extends Statement {
+
+constructor (public value: str) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(19);
+  serializer.serializeStr(this.value);
+}
+
+static load(deserializer: Deserializer): StatementVariantLabeledBreak {
+  const value = deserializer.deserializeStr();
+  return new StatementVariantLabeledBreak(value);
+}
+
+}
+
+export class StatementVariantLabeledContinue extends Statement {
+
+constructor (public value: str) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(20);
+  serializer.serializeStr(this.value);
+}
+
+static load(deserializer: Deserializer): StatementVariantLabeledContinue {
+  const value = deserializer.deserializeStr();
+  return new StatementVariantLabeledContinue(value);
+}
+
+}
+
+export class StatementVariantCommented extends Statement {
+
+constructor (public value: CommentedStatement) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(21);
+  this.value.serialize(serializer);
+}
+
+static load(deserializer: Deserializer): StatementVariantCommented {
+  const value = CommentedStatement.deserialize(deserializer);
+  return new StatementVariantCommented(value);
+}
+
+}
+export class SwitchStatement {
+
+constructor (public expr: Expr, public patternList: Seq<Pattern>, public defaultStatementList: Optional<Seq<Statement>>, public exhaustiveType: Optional<Type>) {
+}
+
+public serialize(serializer: Serializer): void {
+  this.expr.serialize(serializer);
+  Helpers.serializeVectorPattern(this.patternList, serializer);
+  Helpers.serializeOptionVectorStatement(this.defaultStatementList, serializer);
+  Helpers.serializeOptionType(this.exhaustiveType, serializer);
+}
+
+static deserialize(deserializer: Deserializer): SwitchStatement {
+  const expr = Expr.deserialize(deserializer);
+  const patternList = Helpers.deserializeVectorPattern(deserializer);
+  const defaultStatementList = Helpers.deserializeOptionVectorStatement(deserializer);
+  const exhaustiveType = Helpers.deserializeOptionType(deserializer);
+  return new SwitchStatement(expr,patternList,defaultStatementList,exhaustiveType);
+}
+
+}
+export abstract class Type {
+abstract serialize(serializer: Serializer): void;
+
+static deserialize(deserializer: Deserializer): Type {
+  const index = deserializer.deserializeVariantIndex();
+  switch (index) {
+    case 0: return TypeVariantNumber.load(deserializer);
+    case 1: return TypeVariantString.load(deserializer);
+    case 2: return TypeVariantBoolean.load(deserializer);
+    case 3: return TypeVariantUndefined.load(deserializer);
+    case 4: return TypeVariantNull.load(deserializer);
+    case 5: return TypeVariantNever.load(deserializer);
+    case 6: return TypeVariantVoid.load(deserializer);
+    case 7: return TypeVariantObject.load(deserializer);
+    case 8: return TypeVariantFunction.load(deserializer);
+    case 9: return TypeVariantWithTypeParameter.load(deserializer);
+    case 10: return TypeVariantUnion.load(deserializer);
+    case 11: return TypeVariantIntersection.load(deserializer);
+    case 12: return TypeVariantImportedType.load(deserializer);
+    case 13: return TypeVariantScopeInFile.load(deserializer);
+    case 14: return TypeVariantScopeInGlobal.load(deserializer);
+    case 15: return TypeVariantStringLiteral.load(deserializer);
+    case 16: return TypeVariantProjectType.load(deserializer);
+    default: throw new Error("Unknown variant index for Type: " + index);
+  }
+}
+}
+
+
+export class TypeVariantNumber extends Type {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(0);
+}
+
+static load(deserializer: Deserializer): TypeVariantNumber {
+  return new TypeVariantNumber();
+}
+
+}
+
+export class TypeVariantString extends Type {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(1);
+}
+
+static load(deserializer: Deserializer): TypeVariantString {
+  return new TypeVariantString();
+}
+
+}
+
+export class TypeVariantBoolean extends Type {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(2);
+}
+
+static load(deserializer: Deserializer): TypeVariantBoolean {
+  return new TypeVariantBoolean();
+}
+
+}
+
+export class TypeVariantUndefined extends Type {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(3);
+}
+
+static load(deserializer: Deserializer): TypeVariantUndefined {
+  return new TypeVariantUndefined();
+}
+
+}
+
+export class TypeVariantNull extends Type {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(4);
+}
+
+static load(deserializer: Deserializer): TypeVariantNull {
+  return new TypeVariantNull();
+}
+
+}
+
+export class TypeVariantNever extends Type {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(5);
+}
+
+static load(deserializer: Deserializer): TypeVariantNever {
+  return new TypeVariantNever();
+}
+
+}
+
+export class TypeVariantVoid extends Type {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(6);
+}
+
+static load(deserializer: Deserializer): TypeVariantVoid {
+  return new TypeVariantVoid();
+}
+
+}
+
+export class TypeVariantObject extends Type {
+
+constructor (public value: Seq<MemberType>) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(7);
+  Helpers.serializeVectorMemberType(this.value, serializer);
+}
+
+static load(deserializer: Deserializer): TypeVariantObject {
+  const value = Helpers.deserializeVectorMemberType(deserializer);
+  return new TypeVariantObject(value);
+}
+
+}
+
+export class TypeVariantFunction extends Type {
+
+constructor (public value: FunctionType) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(8);
+  this.value.serialize(serializer);
+}
+
+static load(deserializer: Deserializer): TypeVariantFunction {
+  const value = FunctionType.deserialize(deserializer);
+  return new TypeVariantFunction(value);
+}
+
+}
+
+export class TypeVariantWithTypeParameter extends Type {
+
+constructor (public value: TypeWithTypeParameter) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(9);
+  this.value.serialize(serializer);
+}
+
+static load(deserializer: Deserializer): TypeVariantWithTypeParameter {
+  const value = TypeWithTypeParameter.deserialize(deserializer);
+  return new TypeVariantWithTypeParameter(value);
+}
+
+}
+
+export class TypeVariantUnion extends Type {
+
+constructor (public value: Seq<Type>) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(10);
+  Helpers.serializeVectorType(this.value, serializer);
+}
+
+static load(deserializer: Deserializer): TypeVariantUnion {
+  const value = Helpers.deserializeVectorType(deserializer);
+  return new TypeVariantUnion(value);
+}
+
+}
+
+export class TypeVariantIntersection extends Type {
+
+constructor (public value: IntersectionType) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(11);
+  this.value.serialize(serializer);
+}
+
+static load(deserializer: Deserializer): TypeVariantIntersection {
+  const value = IntersectionType.deserialize(deserializer);
+  return new TypeVariantIntersection(value);
+}
+
+}
+
+export class TypeVariantImportedType extends Type {
+
+constructor (public value: ImportedType) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(12);
+  this.value.serialize(serializer);
+}
+
+static load(deserializer: Deserializer): TypeVariantImportedType {
+  const value = ImportedType.deserialize(deserializer);
+  return new TypeVariantImportedType(value);
+}
+
+}
+
+export class TypeVariantScopeInFile extends Type {
+
+constructor (public value: str) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(13);
+  serializer.serializeStr(this.value);
+}
+
+static load(deserializer: Deserializer): TypeVariantScopeInFile {
+  const value = deserializer.deserializeStr();
+  return new TypeVariantScopeInFile(value);
+}
+
+}
+
+export class TypeVariantScopeInGlobal extends Type {
+
+constructor (public value: str) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(14);
+  serializer.serializeStr(this.value);
+}
+
+static load(deserializer: Deserializer): TypeVariantScopeInGlobal {
+  const value = deserializer.deserializeStr();
+  return new TypeVariantScopeInGlobal(value);
+}
+
+}
+
+export class TypeVariantStringLiteral extends Type {
+
+constructor (public value: str) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(15);
+  serializer.serializeStr(this.value);
+}
+
+static load(deserializer: Deserializer): TypeVariantStringLiteral {
+  const value = deserializer.deserializeStr();
+  return new TypeVariantStringLiteral(value);
+}
+
+}
+
+export class TypeVariantProjectType extends Type {
+
+constructor (public value: ProjectReference) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(16);
+  this.value.serialize(serializer);
+}
+
+static load(deserializer: Deserializer): TypeVariantProjectType {
+  const value = ProjectReference.deserialize(deserializer);
+  return new TypeVariantProjectType(value);
+}
+
+}
+export class TypeAlias {
+
+constructor (public name: str, public typeParameterList: Seq<str>, public document: str, public documentTagList: Seq<DocumentTag>, public type: Type, public visibility: Visibility) {
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeStr(this.name);
+  Helpers.serializeVectorStr(this.typeParameterList, serializer);
+  serializer.serializeStr(this.document);
+  Helpers.serializeVectorDocumentTag(this.documentTagList, serializer);
+  this.type.serialize(serializer);
+  this.visibility.serialize(serializer);
+}
+
+static deserialize(deserializer: Deserializer): TypeAlias {
+  const name = deserializer.deserializeStr();
+  const typeParameterList = Helpers.deserializeVectorStr(deserializer);
+  const document = deserializer.deserializeStr();
+  const documentTagList = Helpers.deserializeVectorDocumentTag(deserializer);
+  const type = Type.deserialize(deserializer);
+  const visibility = Visibility.deserialize(deserializer);
+  return new TypeAlias(name,typeParameterList,document,documentTagList,type,visibility);
+}
+
+}
+export class TypeAssertion {
+
+constructor (public expr: Expr, public type: Type) {
+}
+
+public serialize(serializer: Serializer): void {
+  this.expr.serialize(serializer);
+  this.type.serialize(serializer);
+}
+
+static deserialize(deserializer: Deserializer): TypeAssertion {
+  const expr = Expr.deserialize(deserializer);
+  const type = Type.deserialize(deserializer);
+  return new TypeAssertion(expr,type);
+}
+
+}
+export class TypeWithTypeParameter {
+
+constructor (public type: Type, public typeParameterList: Seq<Type>) {
+}
+
+public serialize(serializer: Serializer): void {
+  this.type.serialize(serializer);
+  Helpers.serializeVectorType(this.typeParameterList, serializer);
+}
+
+static deserialize(deserializer: Deserializer): TypeWithTypeParameter {
+  const type = Type.deserialize(deserializer);
+  const typeParameterList = Helpers.deserializeVectorType(deserializer);
+  return new TypeWithTypeParameter(type,typeParameterList);
+}
+
+}
+export abstract class UnaryOperator {
+abstract serialize(serializer: Serializer): void;
+
+static deserialize(deserializer: Deserializer): UnaryOperator {
+  const index = deserializer.deserializeVariantIndex();
+  switch (index) {
+    case 0: return UnaryOperatorVariantMinus.load(deserializer);
+    case 1: return UnaryOperatorVariantBitwiseNot.load(deserializer);
+    case 2: return UnaryOperatorVariantLogicalNot.load(deserializer);
+    case 3: return UnaryOperatorVariantPlus.load(deserializer);
+    case 4: return UnaryOperatorVariantTypeOf.load(deserializer);
+    case 5: return UnaryOperatorVariantVoid.load(deserializer);
+    case 6: return UnaryOperatorVariantDelete.load(deserializer);
+    case 7: return UnaryOperatorVariantPrefixIncrement.load(deserializer);
+    case 8: return UnaryOperatorVariantPrefixDecrement.load(deserializer);
+    case 9: return UnaryOperatorVariantPostfixIncrement.load(deserializer);
+    case 10: return UnaryOperatorVariantPostfixDecrement.load(deserializer);
+    default: throw new Error("Unknown variant index for UnaryOperator: " + index);
+  }
+}
+}
+
+
+export class UnaryOperatorVariantMinus extends UnaryOperator {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(0);
+}
+
+static load(deserializer: Deserializer): UnaryOperatorVariantMinus {
+  return new UnaryOperatorVariantMinus();
+}
+
+}
+
+export class UnaryOperatorVariantBitwiseNot extends UnaryOperator {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(1);
+}
+
+static load(deserializer: Deserializer): UnaryOperatorVariantBitwiseNot {
+  return new UnaryOperatorVariantBitwiseNot();
+}
+
+}
+
+export class UnaryOperatorVariantLogicalNot extends UnaryOperator {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(2);
+}
+
+static load(deserializer: Deserializer): UnaryOperatorVariantLogicalNot {
+  return new UnaryOperatorVariantLogicalNot();
+}
+
+}
+
+export class UnaryOperatorVariantPlus extends UnaryOperator {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(3);
+}
+
+static load(deserializer: Deserializer): UnaryOperatorVariantPlus {
+  return new UnaryOperatorVariantPlus();
+}
+
+}
+
+export class UnaryOperatorVariantTypeOf extends UnaryOperator {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(4);
+}
+
+static load(deserializer: Deserializer): UnaryOperatorVariantTypeOf {
+  return new UnaryOperatorVariantTypeOf();
+}
+
+}
+
+export class UnaryOperatorVariantVoid extends UnaryOperator {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(5);
+}
+
+static load(deserializer: Deserializer): UnaryOperatorVariantVoid {
+  return new UnaryOperatorVariantVoid();
+}
+
+}
+
+export class UnaryOperatorVariantDelete extends UnaryOperator {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(6);
+}
+
+static load(deserializer: Deserializer): UnaryOperatorVariantDelete {
+  return new UnaryOperatorVariantDelete();
+}
+
+}
+
+export class UnaryOperatorVariantPrefixIncrement extends UnaryOperator {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(7);
+}
+
+static load(deserializer: Deserializer): UnaryOperatorVariantPrefixIncrement {
+  return new UnaryOperatorVariantPrefixIncrement();
+}
+
+}
+
+export class UnaryOperatorVariantPrefixDecrement extends UnaryOperator {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(8);
+}
+
+static load(deserializer: Deserializer): UnaryOperatorVariantPrefixDecrement {
+  return new UnaryOperatorVariantPrefixDecrement();
+}
+
+}
+
+export class UnaryOperatorVariantPostfixIncrement extends UnaryOperator {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(9);
+}
+
+static load(deserializer: Deserializer): UnaryOperatorVariantPostfixIncrement {
+  return new UnaryOperatorVariantPostfixIncrement();
+}
+
+}
+
+export class UnaryOperatorVariantPostfixDecrement extends UnaryOperator {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(10);
+}
+
+static load(deserializer: Deserializer): UnaryOperatorVariantPostfixDecrement {
+  return new UnaryOperatorVariantPostfixDecrement();
+}
+
+}
+export class UnaryOperatorExpr {
+
+constructor (public operator: UnaryOperator, public expr: Expr) {
+}
+
+public serialize(serializer: Serializer): void {
+  this.operator.serialize(serializer);
+  this.expr.serialize(serializer);
+}
+
+static deserialize(deserializer: Deserializer): UnaryOperatorExpr {
+  const operator = UnaryOperator.deserialize(deserializer);
+  const expr = Expr.deserialize(deserializer);
+  return new UnaryOperatorExpr(operator,expr);
+}
+
+}
+export abstract class ValidationError {
+abstract serialize(serializer: Serializer): void;
+
+static deserialize(deserializer: Deserializer): ValidationError {
+  const index = deserializer.deserializeVariantIndex();
+  switch (index) {
+    case 0: return ValidationErrorVariantInvalidIdentifer.load(deserializer);
+    case 1: return ValidationErrorVariantDuplicateExportName.load(deserializer);
+    case 2: return ValidationErrorVariantMultipleDefaultExport.load(deserializer);
+    case 3: return ValidationErrorVariantNamedExportWithModuleExports.load(deserializer);
+    case 4: return ValidationErrorVariantRequiredParameterAfterOptional.load(deserializer);
+    case 5: return ValidationErrorVariantRestParameterNotLast.load(deserializer);
+    case 6: return ValidationErrorVariantOptionalChainAssignment.load(deserializer);
+    case 7: return ValidationErrorVariantOptionalChainInNew.load(deserializer);
+    case 8: return ValidationErrorVariantInvalidCompoundAssignmentOperator.load(deserializer);
+    case 9: return ValidationErrorVariantInvalidUpdateOperand.load(deserializer);
+    case 10: return ValidationErrorVariantDeleteVariable.load(deserializer);
+    case 11: return ValidationErrorVariantUndefinedLabel.load(deserializer);
+    case 12: return ValidationErrorVariantContinueToNonLoopLabel.load(deserializer);
+    case 13: return ValidationErrorVariantLabeledDeclaration.load(deserializer);
+    case 14: return ValidationErrorVariantEmptySwitchCaseList.load(deserializer);
+    case 15: return ValidationErrorVariantInvalidExhaustiveType.load(deserializer);
+    case 16: return ValidationErrorVariantNonExhaustiveSwitch.load(deserializer);
+    case 17: return ValidationErrorVariantNonReferenceExhaustiveSwitch.load(deserializer);
+    case 18: return ValidationErrorVariantExhaustiveCheckRedeclared.load(deserializer);
+    case 19: return ValidationErrorVariantInvalidSetterParameter.load(deserializer);
+    case 20: return ValidationErrorVariantMissingSuperCall.load(deserializer);
+    case 21: return ValidationErrorVariantSuperCallWithoutExtends.load(deserializer);
+    case 22: return ValidationErrorVariantStaticPrototypeMember.load(deserializer);
+    case 23: return ValidationErrorVariantYieldOutsideGenerator.load(deserializer);
+    case 24: return ValidationErrorVariantForAwaitOutsideAsync.load(deserializer);
+    case 25: return ValidationErrorVariantAwaitOutsideAsync.load(deserializer);
+    case 26: return ValidationErrorVariantInvalidRegExpPattern.load(deserializer);
+    case 27: return ValidationErrorVariantInvalidRegExpFlags.load(deserializer);
+    default: throw new Error("Unknown variant index for ValidationError: " + index);
+  }
+}
+}
+
+
+export class ValidationErrorVariantInvalidIdentifer extends ValidationError {
+
+constructor (public value: str) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(0);
+  serializer.serializeStr(this.value);
+}
+
+static load(deserializer: Deserializer): ValidationErrorVariantInvalidIdentifer {
+  const value = deserializer.deserializeStr();
+  return new ValidationErrorVariantInvalidIdentifer(value);
+}
+
+}
+
+export class ValidationErrorVariantDuplicateExportName extends ValidationError {
+
+constructor (public value: str) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(1);
+  serializer.serializeStr(this.value);
+}
+
+static load(deserializer: Deserializer): ValidationErrorVariantDuplicateExportName {
+  const value = deserializer.deserializeStr();
+  return new ValidationErrorVariantDuplicateExportName(value);
+}
+
+}
+
+export class ValidationErrorVariantMultipleDefaultExport extends ValidationError {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(2);
+}
+
+static load(deserializer: Deserializer): ValidationErrorVariantMultipleDefaultExport {
+  return new ValidationErrorVariantMultipleDefaultExport();
+}
+
+}
+
+export class ValidationErrorVariantNamedExportWithModuleExports extends ValidationError {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(3);
+}
+
+static load(deserializer: Deserializer): ValidationErrorVariantNamedExportWithModuleExports {
+  return new ValidationErrorVariantNamedExportWithModuleExports();
+}
+
+}
+
+export class ValidationErrorVariantRequiredParameterAfterOptional extends ValidationError {
+
+constructor (public value: uint64) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(4);
+  serializer.serializeU64(this.value);
+}
+
+static load(deserializer: Deserializer): ValidationErrorVariantRequiredParameterAfterOptional {
+  const value = deserializer.deserializeU64();
+  return new ValidationErrorVariantRequiredParameterAfterOptional(value);
+}
+
+}
+
+export class ValidationErrorVariantRestParameterNotLast extends ValidationError {
+
+constructor (public value: uint64) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(5);
+  serializer.serializeU64(this.value);
+}
+
+static load(deserializer: Deserializer): ValidationErrorVariantRestParameterNotLast {
+  const value = deserializer.deserializeU64();
+  return new ValidationErrorVariantRestParameterNotLast(value);
+}
+
+}
+
+export class ValidationErrorVariantOptionalChainAssignment extends ValidationError {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(6);
+}
+
+static load(deserializer: Deserializer): ValidationErrorVariantOptionalChainAssignment {
+  return new ValidationErrorVariantOptionalChainAssignment();
+}
+
+}
+
+export class ValidationErrorVariantOptionalChainInNew extends ValidationError {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(7);
+}
+
+static load(deserializer: Deserializer): ValidationErrorVariantOptionalChainInNew {
+  return new ValidationErrorVariantOptionalChainInNew();
+}
+
+}
+
+export class ValidationErrorVariantInvalidCompoundAssignmentOperator extends ValidationError {
+
+constructor (public value: str) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(8);
+  serializer.serializeStr(this.value);
+}
+
+static load(deserializer: Deserializer): ValidationErrorVariantInvalidCompoundAssignmentOperator {
+  const value = deserializer.deserializeStr();
+  return new ValidationErrorVariantInvalidCompoundAssignmentOperator(value);
+}
+
+}
+
+export class ValidationErrorVariantInvalidUpdateOperand extends ValidationError {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(9);
+}
+
+static load(deserializer: Deserializer): ValidationErrorVariantInvalidUpdateOperand {
+  return new ValidationErrorVariantInvalidUpdateOperand();
+}
+
+}
+
+export class ValidationErrorVariantDeleteVariable extends ValidationError {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(10);
+}
+
+static load(deserializer: Deserializer): ValidationErrorVariantDeleteVariable {
+  return new ValidationErrorVariantDeleteVariable();
+}
+
+}
+
+export class ValidationErrorVariantUndefinedLabel extends ValidationError {
+
+constructor (public value: str) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(11);
+  serializer.serializeStr(this.value);
+}
+
+static load(deserializer: Deserializer): ValidationErrorVariantUndefinedLabel {
+  const value = deserializer.deserializeStr();
+  return new ValidationErrorVariantUndefinedLabel(value);
+}
+
+}
+
+export class ValidationErrorVariantContinueToNonLoopLabel extends ValidationError {
+
+constructor (public value: str) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(12);
+  serializer.serializeStr(this.value);
+}
+
+static load(deserializer: Deserializer): ValidationErrorVariantContinueToNonLoopLabel {
+  const value = deserializer.deserializeStr();
+  return new ValidationErrorVariantContinueToNonLoopLabel(value);
+}
+
+}
+
+export class ValidationErrorVariantLabeledDeclaration extends ValidationError {
+
+constructor (public value: str) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(13);
+  serializer.serializeStr(this.value);
+}
+
+static load(deserializer: Deserializer): ValidationErrorVariantLabeledDeclaration {
+  const value = deserializer.deserializeStr();
+  return new ValidationErrorVariantLabeledDeclaration(value);
+}
+
+}
+
+export class ValidationErrorVariantEmptySwitchCaseList extends ValidationError {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(14);
+}
+
+static load(deserializer: Deserializer): ValidationErrorVariantEmptySwitchCaseList {
+  return new ValidationErrorVariantEmptySwitchCaseList();
+}
+
+}
+
+export class ValidationErrorVariantInvalidExhaustiveType extends ValidationError {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(15);
+}
+
+static load(deserializer: Deserializer): ValidationErrorVariantInvalidExhaustiveType {
+  return new ValidationErrorVariantInvalidExhaustiveType();
+}
+
+}
+
+export class ValidationErrorVariantNonExhaustiveSwitch extends ValidationError {
+
+constructor (public value: Seq<str>) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(16);
+  Helpers.serializeVectorStr(this.value, serializer);
+}
+
+static load(deserializer: Deserializer): ValidationErrorVariantNonExhaustiveSwitch {
+  const value = Helpers.deserializeVectorStr(deserializer);
+  return new ValidationErrorVariantNonExhaustiveSwitch(value);
+}
+
+}
+
+export class ValidationErrorVariantNonReferenceExhaustiveSwitch extends ValidationError {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(17);
+}
+
+static load(deserializer: Deserializer): ValidationErrorVariantNonReferenceExhaustiveSwitch {
+  return new ValidationErrorVariantNonReferenceExhaustiveSwitch();
+}
+
+}
+
+export class ValidationErrorVariantExhaustiveCheckRedeclared extends ValidationError {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(18);
+}
+
+static load(deserializer: Deserializer): ValidationErrorVariantExhaustiveCheckRedeclared {
+  return new ValidationErrorVariantExhaustiveCheckRedeclared();
+}
+
+}
+
+export class ValidationErrorVariantInvalidSetterParameter extends ValidationError {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(19);
+}
+
+static load(deserializer: Deserializer): ValidationErrorVariantInvalidSetterParameter {
+  return new ValidationErrorVariantInvalidSetterParameter();
+}
+
+}
+
+export class ValidationErrorVariantMissingSuperCall extends ValidationError {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(20);
+}
+
+static load(deserializer: Deserializer): ValidationErrorVariantMissingSuperCall {
+  return new ValidationErrorVariantMissingSuperCall();
+}
+
+}
+
+export class ValidationErrorVariantSuperCallWithoutExtends extends ValidationError {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(21);
+}
+
+static load(deserializer: Deserializer): ValidationErrorVariantSuperCallWithoutExtends {
+  return new ValidationErrorVariantSuperCallWithoutExtends();
+}
+
+}
+
+export class ValidationErrorVariantStaticPrototypeMember extends ValidationError {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(22);
+}
+
+static load(deserializer: Deserializer): ValidationErrorVariantStaticPrototypeMember {
+  return new ValidationErrorVariantStaticPrototypeMember();
+}
+
+}
+
+export class ValidationErrorVariantYieldOutsideGenerator extends ValidationError {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(23);
+}
+
+static load(deserializer: Deserializer): ValidationErrorVariantYieldOutsideGenerator {
+  return new ValidationErrorVariantYieldOutsideGenerator();
+}
+
+}
+
+export class ValidationErrorVariantForAwaitOutsideAsync extends ValidationError {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(24);
+}
+
+static load(deserializer: Deserializer): ValidationErrorVariantForAwaitOutsideAsync {
+  return new ValidationErrorVariantForAwaitOutsideAsync();
+}
+
+}
+
+export class ValidationErrorVariantAwaitOutsideAsync extends ValidationError {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(25);
+}
+
+static load(deserializer: Deserializer): ValidationErrorVariantAwaitOutsideAsync {
+  return new ValidationErrorVariantAwaitOutsideAsync();
+}
+
+}
+
+export class ValidationErrorVariantInvalidRegExpPattern extends ValidationError {
+
+constructor (public field0: str, public field1: str) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(26);
+  serializer.serializeStr(this.field0);
+  serializer.serializeStr(this.field1);
+}
+
+static load(deserializer: Deserializer): ValidationErrorVariantInvalidRegExpPattern {
+  const field0 = deserializer.deserializeStr();
+  const field1 = deserializer.deserializeStr();
+  return new ValidationErrorVariantInvalidRegExpPattern(field0,field1);
+}
+
+}
+
+export class ValidationErrorVariantInvalidRegExpFlags extends ValidationError {
+
+constructor (public value: str) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(27);
+  serializer.serializeStr(this.value);
+}
+
+static load(deserializer: Deserializer): ValidationErrorVariantInvalidRegExpFlags {
+  const value = deserializer.deserializeStr();
+  return new ValidationErrorVariantInvalidRegExpFlags(value);
+}
+
+}
+export class Variable {
+
+constructor (public name: str, public document: str, public documentTagList: Seq<DocumentTag>, public type: Type, public expr: Expr, public visibility: Visibility) {
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeStr(this.name);
+  serializer.serializeStr(this.document);
+  Helpers.serializeVectorDocumentTag(this.documentTagList, serializer);
+  this.type.serialize(serializer);
+  this.expr.serialize(serializer);
+  this.visibility.serialize(serializer);
+}
+
+static deserialize(deserializer: Deserializer): Variable {
+  const name = deserializer.deserializeStr();
+  const document = deserializer.deserializeStr();
+  const documentTagList = Helpers.deserializeVectorDocumentTag(deserializer);
+  const type = Type.deserialize(deserializer);
+  const expr = Expr.deserialize(deserializer);
+  const visibility = Visibility.deserialize(deserializer);
+  return new Variable(name,document,documentTagList,type,expr,visibility);
+}
+
+}
+export class VariableDefinitionStatement {
+
+constructor (public pattern: BindingPattern, public type: Type, public expr: Expr, public isConst: bool) {
+}
+
+public serialize(serializer: Serializer): void {
+  this.pattern.serialize(serializer);
+  this.type.serialize(serializer);
+  this.expr.serialize(serializer);
+  serializer.serializeBool(this.isConst);
+}
+
+static deserialize(deserializer: Deserializer): VariableDefinitionStatement {
+  const pattern = BindingPattern.deserialize(deserializer);
+  const type = Type.deserialize(deserializer);
   const expr = Expr.deserialize(deserializer);
-  const is_const = deserializer.deserializeBool();
-  return new VariableDefinitionStatement(name,type,expr,is_const);
+  const isConst = deserializer.deserializeBool();
+  return new VariableDefinitionStatement(pattern,type,expr,isConst);
+}
+
+}
+export abstract class Visibility {
+abstract serialize(serializer: Serializer): void;
+
+static deserialize(deserializer: Deserializer): Visibility {
+  const index = deserializer.deserializeVariantIndex();
+  switch (index) {
+    case 0: return VisibilityVariantExport.load(deserializer);
+    case 1: return VisibilityVariantPrivate.load(deserializer);
+    default: throw new Error("Unknown variant index for Visibility: " + index);
+  }
+}
+}
+
+
+export class VisibilityVariantExport extends Visibility {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(0);
+}
+
+static load(deserializer: Deserializer): VisibilityVariantExport {
+  return new VisibilityVariantExport();
+}
+
+}
+
+export class VisibilityVariantPrivate extends Visibility {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(1);
+}
+
+static load(deserializer: Deserializer): VisibilityVariantPrivate {
+  return new VisibilityVariantPrivate();
+}
+
+}
+export class WhileStatement {
+
+constructor (public condition: Expr, public statementList: Seq<Statement>) {
+}
+
+public serialize(serializer: Serializer): void {
+  this.condition.serialize(serializer);
+  Helpers.serializeVectorStatement(this.statementList, serializer);
+}
+
+static deserialize(deserializer: Deserializer): WhileStatement {
+  const condition = Expr.deserialize(deserializer);
+  const statementList = Helpers.deserializeVectorStatement(deserializer);
+  return new WhileStatement(condition,statementList);
+}
+
+}
+export class YieldExpr {
+
+constructor (public expr: Optional<Expr>, public isDelegate: bool) {
+}
+
+public serialize(serializer: Serializer): void {
+  Helpers.serializeOptionExpr(this.expr, serializer);
+  serializer.serializeBool(this.isDelegate);
+}
+
+static deserialize(deserializer: Deserializer): YieldExpr {
+  const expr = Helpers.deserializeOptionExpr(deserializer);
+  const isDelegate = deserializer.deserializeBool();
+  return new YieldExpr(expr,isDelegate);
 }
 
 }
 export class Helpers {
+  static serializeOptionArrayPatternItem(value: Optional<ArrayPatternItem>, serializer: Serializer): void {
+    if (value) {
+        serializer.serializeOptionTag(true);
+        value.serialize(serializer);
+    } else {
+        serializer.serializeOptionTag(false);
+    }
+  }
+
+  static deserializeOptionArrayPatternItem(deserializer: Deserializer): Optional<ArrayPatternItem> {
+    const tag = deserializer.deserializeOptionTag();
+    if (!tag) {
+        return null;
+    } else {
+        return ArrayPatternItem.deserialize(deserializer);
+    }
+  }
+
   static serializeOptionBinaryOperator(value: Optional<BinaryOperator>, serializer: Serializer): void {
     if (value) {
         serializer.serializeOptionTag(true);
@@ -2005,6 +4709,168 @@ export class Helpers {
     }
   }
 
+  static serializeOptionClassConstructor(value: Optional<ClassConstructor>, serializer: Serializer): void {
+    if (value) {
+        serializer.serializeOptionTag(true);
+        value.serialize(serializer);
+    } else {
+        serializer.serializeOptionTag(false);
+    }
+  }
+
+  static deserializeOptionClassConstructor(deserializer: Deserializer): Optional<ClassConstructor> {
+    const tag = deserializer.deserializeOptionTag();
+    if (!tag) {
+        return null;
+    } else {
+        return ClassConstructor.deserialize(deserializer);
+    }
+  }
+
+  static serializeOptionExpr(value: Optional<Expr>, serializer: Serializer): void {
+    if (value) {
+        serializer.serializeOptionTag(true);
+        value.serialize(serializer);
+    } else {
+        serializer.serializeOptionTag(false);
+    }
+  }
+
+  static deserializeOptionExpr(deserializer: Deserializer): Optional<Expr> {
+    const tag = deserializer.deserializeOptionTag();
+    if (!tag) {
+        return null;
+    } else {
+        return Expr.deserialize(deserializer);
+    }
+  }
+
+  static serializeOptionForInit(value: Optional<ForInit>, serializer: Serializer): void {
+    if (value) {
+        serializer.serializeOptionTag(true);
+        value.serialize(serializer);
+    } else {
+        serializer.serializeOptionTag(false);
+    }
+  }
+
+  static deserializeOptionForInit(deserializer: Deserializer): Optional<ForInit> {
+    const tag = deserializer.deserializeOptionTag();
+    if (!tag) {
+        return null;
+    } else {
+        return ForInit.deserialize(deserializer);
+    }
+  }
+
+  static serializeOptionForUpdate(value: Optional<ForUpdate>, serializer: Serializer): void {
+    if (value) {
+        serializer.serializeOptionTag(true);
+        value.serialize(serializer);
+    } else {
+        serializer.serializeOptionTag(false);
+    }
+  }
+
+  static deserializeOptionForUpdate(deserializer: Deserializer): Optional<ForUpdate> {
+    const tag = deserializer.deserializeOptionTag();
+    if (!tag) {
+        return null;
+    } else {
+        return ForUpdate.deserialize(deserializer);
+    }
+  }
+
+  static serializeOptionFunctionStyle(value: Optional<FunctionStyle>, serializer: Serializer): void {
+    if (value) {
+        serializer.serializeOptionTag(true);
+        value.serialize(serializer);
+    } else {
+        serializer.serializeOptionTag(false);
+    }
+  }
+
+  static deserializeOptionFunctionStyle(deserializer: Deserializer): Optional<FunctionStyle> {
+    const tag = deserializer.deserializeOptionTag();
+    if (!tag) {
+        return null;
+    } else {
+        return FunctionStyle.deserialize(deserializer);
+    }
+  }
+
+  static serializeOptionType(value: Optional<Type>, serializer: Serializer): void {
+    if (value) {
+        serializer.serializeOptionTag(true);
+        value.serialize(serializer);
+    } else {
+        serializer.serializeOptionTag(false);
+    }
+  }
+
+  static deserializeOptionType(deserializer: Deserializer): Optional<Type> {
+    const tag = deserializer.deserializeOptionTag();
+    if (!tag) {
+        return null;
+    } else {
+        return Type.deserialize(deserializer);
+    }
+  }
+
+  static serializeOptionStr(value: Optional<str>, serializer: Serializer): void {
+    if (value) {
+        serializer.serializeOptionTag(true);
+        serializer.serializeStr(value);
+    } else {
+        serializer.serializeOptionTag(false);
+    }
+  }
+
+  static deserializeOptionStr(deserializer: Deserializer): Optional<str> {
+    const tag = deserializer.deserializeOptionTag();
+    if (!tag) {
+        return null;
+    } else {
+        return deserializer.deserializeStr();
+    }
+  }
+
+  static serializeOptionVectorArrayItem(value: Optional<Seq<ArrayItem>>, serializer: Serializer): void {
+    if (value) {
+        serializer.serializeOptionTag(true);
+        Helpers.serializeVectorArrayItem(value, serializer);
+    } else {
+        serializer.serializeOptionTag(false);
+    }
+  }
+
+  static deserializeOptionVectorArrayItem(deserializer: Deserializer): Optional<Seq<ArrayItem>> {
+    const tag = deserializer.deserializeOptionTag();
+    if (!tag) {
+        return null;
+    } else {
+        return Helpers.deserializeVectorArrayItem(deserializer);
+    }
+  }
+
+  static serializeOptionVectorStatement(value: Optional<Seq<Statement>>, serializer: Serializer): void {
+    if (value) {
+        serializer.serializeOptionTag(true);
+        Helpers.serializeVectorStatement(value, serializer);
+    } else {
+        serializer.serializeOptionTag(false);
+    }
+  }
+
+  static deserializeOptionVectorStatement(deserializer: Deserializer): Optional<Seq<Statement>> {
+    const tag = deserializer.deserializeOptionTag();
+    if (!tag) {
+        return null;
+    } else {
+        return Helpers.deserializeVectorStatement(deserializer);
+    }
+  }
+
   static serializeVectorArrayItem(value: Seq<ArrayItem>, serializer: Serializer): void {
     serializer.serializeLen(value.length);
     value.forEach((item: ArrayItem) => {
@@ -2021,6 +4887,54 @@ export class Helpers {
     return list;
   }
 
+  static serializeVectorClassMember(value: Seq<ClassMember>, serializer: Serializer): void {
+    serializer.serializeLen(value.length);
+    value.forEach((item: ClassMember) => {
+        item.serialize(serializer);
+    });
+  }
+
+  static deserializeVectorClassMember(deserializer: Deserializer): Seq<ClassMember> {
+    const length = deserializer.deserializeLen();
+    const list: Seq<ClassMember> = [];
+    for (let i = 0; i < length; i++) {
+        list.push(ClassMember.deserialize(deserializer));
+    }
+    return list;
+  }
+
+  static serializeVectorComment(value: Seq<Comment>, serializer: Serializer): void {
+    serializer.serializeLen(value.length);
+    value.forEach((item: Comment) => {
+        item.serialize(serializer);
+    });
+  }
+
+  static deserializeVectorComment(deserializer: Deserializer): Seq<Comment> {
+    const length = deserializer.deserializeLen();
+    const list: Seq<Comment> = [];
+    for (let i = 0; i < length; i++) {
+        list.push(Comment.deserialize(deserializer));
+    }
+    return list;
+  }
+
+  static serializeVectorDocumentTag(value: Seq<DocumentTag>, serializer: Serializer): void {
+    serializer.serializeLen(value.length);
+    value.forEach((item: DocumentTag) => {
+        item.serialize(serializer);
+    });
+  }
+
+  static deserializeVectorDocumentTag(deserializer: Deserializer): Seq<DocumentTag> {
+    const length = deserializer.deserializeLen();
+    const list: Seq<DocumentTag> = [];
+    for (let i = 0; i < length; i++) {
+        list.push(DocumentTag.deserialize(deserializer));
+    }
+    return list;
+  }
+
   static serializeVectorExportDefinition(value: Seq<ExportDefinition>, serializer: Serializer): void {
     serializer.serializeLen(value.length);
     value.forEach((item: ExportDefinition) => {
@@ -2037,34 +4951,34 @@ export class Helpers {
     return list;
   }
 
-  static serializeVectorExpr(value: Seq<Expr>, serializer: Serializer): void {
+  static serializeVectorExportName(value: Seq<ExportName>, serializer: Serializer): void {
     serializer.serializeLen(value.length);
-    value.forEach((item: Expr) => {
+    value.forEach((item: ExportName) => {
         item.serialize(serializer);
     });
   }
 
-  static deserializeVectorExpr(deserializer: Deserializer): Seq<Expr> {
+  static deserializeVectorExportName(deserializer: Deserializer): Seq<ExportName> {
     const length = deserializer.deserializeLen();
-    const list: Seq<Expr> = [];
+    const list: Seq<ExportName> = [];
     for (let i = 0; i < length; i++) {
-        list.push(Expr.deserialize(deserializer));
+        list.push(ExportName.deserialize(deserializer));
     }
     return list;
   }
 
-  static serializeVectorIdentifer(value: Seq<Identifer>, serializer: Serializer): void {
+  static serializeVectorExpr(value: Seq<Expr>, serializer: Serializer): void {
     serializer.serializeLen(value.length);
-    value.forEach((item: Identifer) => {
+    value.forEach((item: Expr) => {
         item.serialize(serializer);
     });
   }
 
-  static deserializeVectorIdentifer(deserializer: Deserializer): Seq<Identifer> {
+  static deserializeVectorExpr(deserializer: Deserializer): Seq<Expr> {
     const length = deserializer.deserializeLen();
-    const list: Seq<Identifer> = [];
+    const list: Seq<Expr> = [];
     for (let i = 0; i < length; i++) {
-        list.push(Identifer.deserialize(deserializer));
+        list.push(Expr.deserialize(deserializer));
     }
     return list;
   }
@@ -2101,6 +5015,22 @@ export class Helpers {
     return list;
   }
 
+  static serializeVectorObjectPatternMember(value: Seq<ObjectPatternMember>, serializer: Serializer): void {
+    serializer.serializeLen(value.length);
+    value.forEach((item: ObjectPatternMember) => {
+        item.serialize(serializer);
+    });
+  }
+
+  static deserializeVectorObjectPatternMember(deserializer: Deserializer): Seq<ObjectPatternMember> {
+    const length = deserializer.deserializeLen();
+    const list: Seq<ObjectPatternMember> = [];
+    for (let i = 0; i < length; i++) {
+        list.push(ObjectPatternMember.deserialize(deserializer));
+    }
+    return list;
+  }
+
   static serializeVectorParameter(value: Seq<Parameter>, serializer: Serializer): void {
     serializer.serializeLen(value.length);
     value.forEach((item: Parameter) => {
@@ -2149,6 +5079,22 @@ export class Helpers {
     return list;
   }
 
+  static serializeVectorProjectFile(value: Seq<ProjectFile>, serializer: Serializer): void {
+    serializer.serializeLen(value.length);
+    value.forEach((item: ProjectFile) => {
+        item.serialize(serializer);
+    });
+  }
+
+  static deserializeVectorProjectFile(deserializer: Deserializer): Seq<ProjectFile> {
+    const length = deserializer.deserializeLen();
+    const list: Seq<ProjectFile> = [];
+    for (let i = 0; i < length; i++) {
+        list.push(ProjectFile.deserialize(deserializer));
+    }
+    return list;
+  }
+
   static serializeVectorStatement(value: Seq<Statement>, serializer: Serializer): void {
     serializer.serializeLen(value.length);
     value.forEach((item: Statement) => {
@@ -2181,5 +5127,53 @@ export class Helpers {
     return list;
   }
 
+  static serializeVectorValidationError(value: Seq<ValidationError>, serializer: Serializer): void {
+    serializer.serializeLen(value.length);
+    value.forEach((item: ValidationError) => {
+        item.serialize(serializer);
+    });
+  }
+
+  static deserializeVectorValidationError(deserializer: Deserializer): Seq<ValidationError> {
+    const length = deserializer.deserializeLen();
+    const list: Seq<ValidationError> = [];
+    for (let i = 0; i < length; i++) {
+        list.push(ValidationError.deserialize(deserializer));
+    }
+    return list;
+  }
+
+  static serializeVectorOptionArrayPatternItem(value: Seq<Optional<ArrayPatternItem>>, serializer: Serializer): void {
+    serializer.serializeLen(value.length);
+    value.forEach((item: Optional<ArrayPatternItem>) => {
+        Helpers.serializeOptionArrayPatternItem(item, serializer);
+    });
+  }
+
+  static deserializeVectorOptionArrayPatternItem(deserializer: Deserializer): Seq<Optional<ArrayPatternItem>> {
+    const length = deserializer.deserializeLen();
+    const list: Seq<Optional<ArrayPatternItem>> = [];
+    for (let i = 0; i < length; i++) {
+        list.push(Helpers.deserializeOptionArrayPatternItem(deserializer));
+    }
+    return list;
+  }
+
+  static serializeVectorStr(value: Seq<str>, serializer: Serializer): void {
+    serializer.serializeLen(value.length);
+    value.forEach((item: str) => {
+        serializer.serializeStr(item);
+    });
+  }
+
+  static deserializeVectorStr(deserializer: Deserializer): Seq<str> {
+    const length = deserializer.deserializeLen();
+    const list: Seq<str> = [];
+    for (let i = 0; i < length; i++) {
+        list.push(deserializer.deserializeStr());
+    }
+    return list;
+  }
+
 }
 
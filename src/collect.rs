@@ -0,0 +1,497 @@
+use crate::data;
+
+/// コード内で使われている, 他のモジュールで定義されたものへの参照
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Reference<'a> {
+    ImportedVariable(&'a data::ImportedVariable),
+    ImportedType(&'a data::ImportedType),
+    ProjectVariable(&'a data::ProjectReference),
+    ProjectType(&'a data::ProjectReference),
+}
+
+/// コード内で使われている, 他のモジュールで定義されたものへの参照を 出現順に集める
+pub fn reference_list(code: &data::Code) -> Vec<Reference<'_>> {
+    let mut reference_list = Vec::new();
+    for export_definition in &code.export_definition_list {
+        export_definition_collect(export_definition, &mut reference_list);
+    }
+    statement_list_collect(&code.statement_list, &mut reference_list);
+    reference_list
+}
+
+/// 宣言ファイルで出力される型の中で使われている参照を 出現順に集める.
+/// 関数の本体や変数の式, 外部に公開しない値は含まない
+pub fn declaration_reference_list(code: &data::Code) -> Vec<Reference<'_>> {
+    let mut reference_list = Vec::new();
+    for export_definition in &code.export_definition_list {
+        match export_definition {
+            data::ExportDefinition::TypeAlias(type_alias) => {
+                type_collect(&type_alias.r#type, &mut reference_list)
+            }
+            data::ExportDefinition::Function(function) => {
+                if function.visibility == data::Visibility::Export {
+                    parameter_with_document_list_collect(
+                        &function.parameter_list,
+                        &mut reference_list,
+                    );
+                    type_collect(&function.return_type, &mut reference_list);
+                }
+            }
+            data::ExportDefinition::Variable(variable) => {
+                if variable.visibility == data::Visibility::Export {
+                    type_collect(&variable.r#type, &mut reference_list)
+                }
+            }
+            data::ExportDefinition::ExportFrom(_) | data::ExportDefinition::ExportAll(_) => {}
+            data::ExportDefinition::Default(default_export) => match &default_export.expr {
+                // クラスは メンバーの型を出力する
+                data::Expr::Class(class_expr) => {
+                    class_declaration_collect(class_expr, &mut reference_list)
+                }
+                _ => type_collect(&default_export.r#type, &mut reference_list),
+            },
+        }
+    }
+    reference_list
+}
+
+/// 宣言ファイルで出力されるクラスの `extends` とメンバーの型の中の参照を集める
+fn class_declaration_collect<'a>(
+    class_expr: &'a data::ClassExpr,
+    reference_list: &mut Vec<Reference<'a>>,
+) {
+    if let Some(extends) = &class_expr.extends {
+        expr_collect(extends, reference_list);
+    }
+    if let Some(constructor) = &class_expr.constructor {
+        for parameter in &constructor.parameter_list {
+            type_collect(&parameter.r#type, reference_list);
+        }
+    }
+    for member in &class_expr.member_list {
+        match &member.kind {
+            data::ClassMemberKind::Field(field) => type_collect(&field.r#type, reference_list),
+            data::ClassMemberKind::Method(method) => {
+                for parameter in &method.parameter_list {
+                    type_collect(&parameter.r#type, reference_list);
+                }
+                type_collect(&method.return_type, reference_list);
+            }
+            data::ClassMemberKind::Getter(getter) => {
+                type_collect(&getter.return_type, reference_list)
+            }
+            data::ClassMemberKind::Setter(setter) => {
+                type_collect(&setter.parameter.r#type, reference_list)
+            }
+        }
+    }
+}
+
+fn export_definition_collect<'a>(
+    export_definition: &'a data::ExportDefinition,
+    reference_list: &mut Vec<Reference<'a>>,
+) {
+    match export_definition {
+        data::ExportDefinition::TypeAlias(type_alias) => {
+            type_collect(&type_alias.r#type, reference_list)
+        }
+        data::ExportDefinition::Function(function) => {
+            parameter_with_document_list_collect(&function.parameter_list, reference_list);
+            for parameter in &function.parameter_list {
+                parameter_kind_collect(&parameter.kind, reference_list);
+            }
+            type_collect(&function.return_type, reference_list);
+            statement_list_collect(&function.statement_list, reference_list);
+        }
+        data::ExportDefinition::Variable(variable) => {
+            type_collect(&variable.r#type, reference_list);
+            expr_collect(&variable.expr, reference_list);
+        }
+        data::ExportDefinition::ExportFrom(_) | data::ExportDefinition::ExportAll(_) => {}
+        data::ExportDefinition::Default(default_export) => {
+            type_collect(&default_export.r#type, reference_list);
+            expr_collect(&default_export.expr, reference_list);
+        }
+    }
+}
+
+fn parameter_with_document_list_collect<'a>(
+    parameter_list: &'a [data::ParameterWithDocument],
+    reference_list: &mut Vec<Reference<'a>>,
+) {
+    for parameter in parameter_list {
+        type_collect(&parameter.r#type, reference_list);
+    }
+}
+
+/// パラメーターのデフォルト値の中の参照を集める
+fn parameter_kind_collect<'a>(
+    kind: &'a data::ParameterKind,
+    reference_list: &mut Vec<Reference<'a>>,
+) {
+    if let data::ParameterKind::Default(default_expr) = kind {
+        expr_collect(default_expr, reference_list);
+    }
+}
+
+fn statement_list_collect<'a>(
+    statement_list: &'a [data::Statement],
+    reference_list: &mut Vec<Reference<'a>>,
+) {
+    for statement in statement_list {
+        statement_collect(statement, reference_list);
+    }
+}
+
+fn statement_collect<'a>(statement: &'a data::Statement, reference_list: &mut Vec<Reference<'a>>) {
+    match statement {
+        data::Statement::EvaluateExpr(expr)
+        | data::Statement::ThrowError(expr)
+        | data::Statement::Return(expr) => expr_collect(expr, reference_list),
+        data::Statement::Set(set_statement) => {
+            expr_collect(&set_statement.target, reference_list);
+            expr_collect(&set_statement.expr, reference_list);
+        }
+        data::Statement::If(if_statement) => {
+            expr_collect(&if_statement.condition, reference_list);
+            statement_list_collect(&if_statement.then_statement_list, reference_list);
+        }
+        data::Statement::ReturnVoid
+        | data::Statement::Continue
+        | data::Statement::Break
+        | data::Statement::LabeledBreak(_)
+        | data::Statement::LabeledContinue(_) => {}
+        data::Statement::VariableDefinition(variable_definition) => {
+            variable_definition_collect(variable_definition, reference_list)
+        }
+        data::Statement::FunctionDefinition(function_definition) => {
+            parameter_with_document_list_collect(
+                &function_definition.parameter_list,
+                reference_list,
+            );
+            for parameter in &function_definition.parameter_list {
+                parameter_kind_collect(&parameter.kind, reference_list);
+            }
+            type_collect(&function_definition.return_type, reference_list);
+            statement_list_collect(&function_definition.statement_list, reference_list);
+        }
+        data::Statement::For(for_statement) => {
+            expr_collect(&for_statement.until_expr, reference_list);
+            statement_list_collect(&for_statement.statement_list, reference_list);
+        }
+        data::Statement::ForOf(for_of_statement) => {
+            binding_pattern_collect(&for_of_statement.element_pattern, reference_list);
+            expr_collect(&for_of_statement.iterable_expr, reference_list);
+            statement_list_collect(&for_of_statement.statement_list, reference_list);
+        }
+        data::Statement::WhileTrue(statement_list) => {
+            statement_list_collect(statement_list, reference_list)
+        }
+        data::Statement::Switch(switch_statement) => {
+            expr_collect(&switch_statement.expr, reference_list);
+            for pattern in &switch_statement.pattern_list {
+                for case_expr in &pattern.case_list {
+                    expr_collect(case_expr, reference_list);
+                }
+                statement_list_collect(&pattern.statement_list, reference_list);
+            }
+            if let Some(default_statement_list) = &switch_statement.default_statement_list {
+                statement_list_collect(default_statement_list, reference_list);
+            }
+            if let Some(exhaustive_type) = &switch_statement.exhaustive_type {
+                type_collect(exhaustive_type, reference_list);
+            }
+        }
+        data::Statement::ForLoop(for_loop_statement) => {
+            match &for_loop_statement.init {
+                Some(data::ForInit::VariableDefinition(variable_definition)) => {
+                    variable_definition_collect(variable_definition, reference_list)
+                }
+                Some(data::ForInit::Set(set_statement)) => {
+                    expr_collect(&set_statement.target, reference_list);
+                    expr_collect(&set_statement.expr, reference_list);
+                }
+                Some(data::ForInit::Expr(expr)) => expr_collect(expr, reference_list),
+                None => {}
+            }
+            if let Some(test) = &for_loop_statement.test {
+                expr_collect(test, reference_list);
+            }
+            match &for_loop_statement.update {
+                Some(data::ForUpdate::Set(set_statement)) => {
+                    expr_collect(&set_statement.target, reference_list);
+                    expr_collect(&set_statement.expr, reference_list);
+                }
+                Some(data::ForUpdate::Expr(expr)) => expr_collect(expr, reference_list),
+                None => {}
+            }
+            statement_list_collect(&for_loop_statement.statement_list, reference_list);
+        }
+        data::Statement::While(while_statement) | data::Statement::DoWhile(while_statement) => {
+            expr_collect(&while_statement.condition, reference_list);
+            statement_list_collect(&while_statement.statement_list, reference_list);
+        }
+        data::Statement::ForIn(for_in_statement) => {
+            expr_collect(&for_in_statement.object_expr, reference_list);
+            statement_list_collect(&for_in_statement.statement_list, reference_list);
+        }
+        data::Statement::Commented(commented_statement) => {
+            statement_collect(&commented_statement.statement, reference_list)
+        }
+        data::Statement::Labeled(labeled_statement) => {
+            statement_collect(&labeled_statement.statement, reference_list)
+        }
+    }
+}
+
+fn variable_definition_collect<'a>(
+    variable_definition: &'a data::VariableDefinitionStatement,
+    reference_list: &mut Vec<Reference<'a>>,
+) {
+    binding_pattern_collect(&variable_definition.pattern, reference_list);
+    type_collect(&variable_definition.r#type, reference_list);
+    expr_collect(&variable_definition.expr, reference_list);
+}
+
+fn expr_collect<'a>(expr: &'a data::Expr, reference_list: &mut Vec<Reference<'a>>) {
+    match expr {
+        data::Expr::NumberLiteral(_)
+        | data::Expr::StringLiteral(_)
+        | data::Expr::RegExpLiteral(_)
+        | data::Expr::BooleanLiteral(_)
+        | data::Expr::NullLiteral
+        | data::Expr::UndefinedLiteral
+        | data::Expr::Variable(_)
+        | data::Expr::GlobalObjects(_)
+        | data::Expr::This => {}
+        data::Expr::UnaryOperator(unary_operator_expr) => {
+            expr_collect(&unary_operator_expr.expr, reference_list)
+        }
+        data::Expr::BinaryOperator(binary_operator_expr) => {
+            expr_collect(&binary_operator_expr.left, reference_list);
+            expr_collect(&binary_operator_expr.right, reference_list);
+        }
+        data::Expr::ConditionalOperator(conditional_operator_expr) => {
+            expr_collect(&conditional_operator_expr.condition, reference_list);
+            expr_collect(&conditional_operator_expr.then_expr, reference_list);
+            expr_collect(&conditional_operator_expr.else_expr, reference_list);
+        }
+        data::Expr::ArrayLiteral(item_list) => {
+            for item in item_list.iter() {
+                expr_collect(&item.expr, reference_list);
+            }
+        }
+        data::Expr::ObjectLiteral(member_list) => {
+            for member in member_list.iter() {
+                member_collect(member, reference_list);
+            }
+        }
+        data::Expr::Lambda(lambda_expr) => {
+            lambda_parameter_list_collect(&lambda_expr.parameter_list, reference_list);
+            type_collect(&lambda_expr.return_type, reference_list);
+            statement_list_collect(&lambda_expr.statement_list, reference_list);
+        }
+        data::Expr::ImportedVariable(imported_variable) => {
+            reference_list.push(Reference::ImportedVariable(imported_variable))
+        }
+        data::Expr::ProjectVariable(project_reference) => {
+            reference_list.push(Reference::ProjectVariable(project_reference))
+        }
+        data::Expr::Get(get_expr) => {
+            expr_collect(&get_expr.expr, reference_list);
+            expr_collect(&get_expr.property_expr, reference_list);
+        }
+        data::Expr::Call(call_expr) | data::Expr::New(call_expr) => {
+            expr_collect(&call_expr.expr, reference_list);
+            for parameter in &call_expr.parameter_list {
+                expr_collect(&parameter.expr, reference_list);
+            }
+        }
+        data::Expr::Yield(yield_expr) => {
+            if let Some(expr) = &yield_expr.expr {
+                expr_collect(expr, reference_list);
+            }
+        }
+        data::Expr::Class(class_expr) => class_collect(class_expr, reference_list),
+        data::Expr::Await(expr) => expr_collect(expr, reference_list),
+        data::Expr::TypeAssertion(type_assertion) => {
+            expr_collect(&type_assertion.expr, reference_list);
+            type_collect(&type_assertion.r#type, reference_list);
+        }
+    }
+}
+
+fn member_collect<'a>(member: &'a data::Member, reference_list: &mut Vec<Reference<'a>>) {
+    match member {
+        data::Member::Spread(expr) => expr_collect(expr, reference_list),
+        data::Member::KeyValue(key_value) => expr_collect(&key_value.value, reference_list),
+        data::Member::ComputedKeyValue(computed_key_value) => {
+            expr_collect(&computed_key_value.key_expr, reference_list);
+            expr_collect(&computed_key_value.value, reference_list);
+        }
+        data::Member::Method(method) => method_collect(method, reference_list),
+        data::Member::Getter(getter) => getter_collect(getter, reference_list),
+        data::Member::Setter(setter) => setter_collect(setter, reference_list),
+        data::Member::Commented(commented_member) => {
+            member_collect(&commented_member.member, reference_list)
+        }
+    }
+}
+
+fn class_collect<'a>(class_expr: &'a data::ClassExpr, reference_list: &mut Vec<Reference<'a>>) {
+    if let Some(extends) = &class_expr.extends {
+        expr_collect(extends, reference_list);
+    }
+    if let Some(constructor) = &class_expr.constructor {
+        lambda_parameter_list_collect(&constructor.parameter_list, reference_list);
+        for parameter in constructor.super_parameter_list.iter().flatten() {
+            expr_collect(&parameter.expr, reference_list);
+        }
+        statement_list_collect(&constructor.statement_list, reference_list);
+    }
+    for member in &class_expr.member_list {
+        match &member.kind {
+            data::ClassMemberKind::Field(field) => {
+                property_key_collect(&field.key, reference_list);
+                type_collect(&field.r#type, reference_list);
+                if let Some(value) = &field.value {
+                    expr_collect(value, reference_list);
+                }
+            }
+            data::ClassMemberKind::Method(method) => method_collect(method, reference_list),
+            data::ClassMemberKind::Getter(getter) => getter_collect(getter, reference_list),
+            data::ClassMemberKind::Setter(setter) => setter_collect(setter, reference_list),
+        }
+    }
+}
+
+fn method_collect<'a>(method: &'a data::ObjectMethod, reference_list: &mut Vec<Reference<'a>>) {
+    property_key_collect(&method.key, reference_list);
+    lambda_parameter_list_collect(&method.parameter_list, reference_list);
+    type_collect(&method.return_type, reference_list);
+    statement_list_collect(&method.statement_list, reference_list);
+}
+
+fn getter_collect<'a>(getter: &'a data::ObjectGetter, reference_list: &mut Vec<Reference<'a>>) {
+    property_key_collect(&getter.key, reference_list);
+    type_collect(&getter.return_type, reference_list);
+    statement_list_collect(&getter.statement_list, reference_list);
+}
+
+fn setter_collect<'a>(setter: &'a data::ObjectSetter, reference_list: &mut Vec<Reference<'a>>) {
+    property_key_collect(&setter.key, reference_list);
+    lambda_parameter_list_collect(std::slice::from_ref(&setter.parameter), reference_list);
+    statement_list_collect(&setter.statement_list, reference_list);
+}
+
+fn lambda_parameter_list_collect<'a>(
+    parameter_list: &'a [data::Parameter],
+    reference_list: &mut Vec<Reference<'a>>,
+) {
+    for parameter in parameter_list {
+        binding_pattern_collect(&parameter.pattern, reference_list);
+        type_collect(&parameter.r#type, reference_list);
+        parameter_kind_collect(&parameter.kind, reference_list);
+    }
+}
+
+fn property_key_collect<'a>(
+    property_key: &'a data::PropertyKey,
+    reference_list: &mut Vec<Reference<'a>>,
+) {
+    if let data::PropertyKey::Computed(expr) = property_key {
+        expr_collect(expr, reference_list);
+    }
+}
+
+/// パターンのデフォルト値の中の参照を集める
+fn binding_pattern_collect<'a>(
+    binding_pattern: &'a data::BindingPattern,
+    reference_list: &mut Vec<Reference<'a>>,
+) {
+    match binding_pattern {
+        data::BindingPattern::Identifer(_) => {}
+        data::BindingPattern::Object(object_pattern) => {
+            for member in &object_pattern.member_list {
+                binding_pattern_collect(&member.pattern, reference_list);
+                if let Some(default_expr) = &member.default_expr {
+                    expr_collect(default_expr, reference_list);
+                }
+            }
+        }
+        data::BindingPattern::Array(array_pattern) => {
+            for item in array_pattern.item_list.iter().flatten() {
+                binding_pattern_collect(&item.pattern, reference_list);
+                if let Some(default_expr) = &item.default_expr {
+                    expr_collect(default_expr, reference_list);
+                }
+            }
+        }
+    }
+}
+
+fn type_collect<'a>(r#type: &'a data::Type, reference_list: &mut Vec<Reference<'a>>) {
+    match r#type {
+        data::Type::Number
+        | data::Type::String
+        | data::Type::Boolean
+        | data::Type::Undefined
+        | data::Type::Null
+        | data::Type::Never
+        | data::Type::Void
+        | data::Type::StringLiteral(_)
+        | data::Type::ScopeInFile(_)
+        | data::Type::ScopeInGlobal(_) => {}
+        data::Type::Object(member_list) => {
+            for member in member_list.iter() {
+                type_collect(&member.r#type, reference_list);
+            }
+        }
+        data::Type::Function(function_type) => {
+            for parameter in function_type
+                .parameter_list
+                .iter()
+                .chain(&function_type.optional_parameter_list)
+                .chain(&function_type.rest_parameter)
+            {
+                type_collect(parameter, reference_list);
+            }
+            type_collect(&function_type.return_type, reference_list);
+        }
+        data::Type::WithTypeParameter(type_with_type_parameter) => {
+            type_collect(&type_with_type_parameter.r#type, reference_list);
+            for parameter in &type_with_type_parameter.type_parameter_list {
+                type_collect(parameter, reference_list);
+            }
+        }
+        data::Type::Union(type_list) => {
+            for r#type in type_list.iter() {
+                type_collect(r#type, reference_list);
+            }
+        }
+        data::Type::Intersection(intersection_type) => {
+            type_collect(&intersection_type.left, reference_list);
+            type_collect(&intersection_type.right, reference_list);
+        }
+        data::Type::ImportedType(imported_type) => {
+            reference_list.push(Reference::ImportedType(imported_type))
+        }
+        data::Type::ProjectType(project_reference) => {
+            reference_list.push(Reference::ProjectType(project_reference))
+        }
+    }
+}
+
+#[test]
+fn test_reference_list() {
+    let code = crate::sample_code();
+    let reference_list = reference_list(&code);
+    assert_eq!(reference_list.len(), 2);
+    match reference_list[0] {
+        Reference::ImportedType(imported_type) => {
+            assert_eq!(imported_type.module_name, "express");
+            assert_eq!(imported_type.name.get(), "Request");
+        }
+        _ => panic!("ImportedType になるはず"),
+    }
+}
@@ -9,10 +9,193 @@ pub enum CodeType {
 
     /// TypeScript. JavaScript に 型をつけた言語
     TypeScript,
+
+    /// TypeScript の宣言ファイル (.d.ts). 型と関数, 変数のシグネチャのみを出力する
+    Declaration,
+
+    /// JavaScript. 型は `@param {Type}` や `@type {Type}` などの JSDoc のコメントで表現し,
+    /// `// @ts-check` で型検査できるようにする
+    JavaScriptWithJsDoc,
+}
+
+/// モジュールの形式. JavaScript を出力するときに インポートとエクスポートの書き方が変わる.
+/// TypeScript と宣言ファイルは TypeScript のコンパイラが変換するので 常に ES Modules で出力する
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+pub enum ModuleFormat {
+    /// ES Modules. `import * as $$$express from "express";` と `export const a = ...;`
+    EsModule,
+
+    /// CommonJS. `const $$$express = require("express");` と `exports.a = a;`
+    CommonJs,
+
+    /// UMD. AMD, CommonJS のどちらでも読み込めて, どちらでもない場合は指定した名前のグローバル変数に代入する.
+    /// インポートしたモジュールは モジュール名と同じ名前のグローバル変数にあるものとする
+    Umd(String),
+}
+
+/// コード生成のオプション
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GenerateOption {
+    /// 出力するコードの種類
+    pub code_type: CodeType,
+
+    /// モジュールの形式
+    pub module_format: ModuleFormat,
+
+    /// 関数の定義の書き方. `style` を指定していない関数に使う. JSON で省略した場合はアロー関数
+    #[serde(default)]
+    pub function_style: FunctionStyle,
+
+    /// 文字列リテラルとプロパティ名の ASCII 以外の文字を `\uXXXX` か `\u{XXXXX}` にエスケープするかどうか.
+    /// JSON で省略した場合はエスケープしない
+    #[serde(default)]
+    pub is_ascii_only: bool,
+
+    /// CommonJS で default export をどこに代入するか. JSON で省略した場合は `exports.default`
+    #[serde(default)]
+    pub common_js_default_export: CommonJsDefaultExport,
+}
+
+/// CommonJS で default export を代入する先
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Default)]
+pub enum CommonJsDefaultExport {
+    /// `exports.default = expr;`. TypeScript が CommonJS に変換したときと同じ
+    #[default]
+    ExportsDefault,
+
+    /// `module.exports = expr;`. `require()` が default export の値を返す.
+    /// 他に値を export していると その値が失われるので, 検証エラーになる
+    ModuleExports,
+}
+
+/// 関数の定義の書き方
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Default)]
+pub enum FunctionStyle {
+    /// `const f = (a) => a;`
+    #[default]
+    Arrow,
+
+    /// `function f(a) { return a; }`. 巻き上げられるので, 相互再帰する関数や `this` や `arguments` を使う関数に使う
+    Declaration,
+}
+
+/// WASM 経由でコードを生成したときの結果
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+pub enum GenerateResult {
+    /// 生成されたコード
+    Ok(String),
+
+    /// 生成に失敗した理由
+    Error(GenerateError),
+}
+
+/// コード生成のエラー
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+pub enum GenerateError {
+    /// `Code` のデコードに失敗した. デコーダーのエラーメッセージ
+    DecodeCode(String),
+
+    /// `GenerateOption` のデコードに失敗した. デコーダーのエラーメッセージ
+    DecodeOption(String),
+
+    /// `Code` の検証に失敗した
+    Validation(Vec<ValidationError>),
+}
+
+/// `Code` の検証エラー. 出力すると JavaScript として正しくないコードになるもの
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+pub enum ValidationError {
+    /// 識別子として使えない文字列が識別子に指定されている
+    InvalidIdentifer(String),
+
+    /// 同じ名前のトップレベルの定義が2つ以上ある. ファイルの中の束縛どうしと export される名前どうしで比べる.
+    /// 外部に公開しない定義と, 同じ名前の `export { a } from` は衝突しない
+    DuplicateExportName(String),
+
+    /// default export が2つ以上ある
+    MultipleDefaultExport,
+
+    /// `CommonJsDefaultExport::ModuleExports` を指定しているが, default export 以外にも値を export している
+    NamedExportWithModuleExports,
+    /// 省略可能なパラメーターの後に 必須のパラメーターがある. パラメーターの位置
+    RequiredParameterAfterOptional(usize),
+
+    /// 残余パラメーターが最後のパラメーターではない. パラメーターの位置
+    RestParameterNotLast(usize),
+
+    /// optional chaining を含む式 `a?.b` に代入している
+    OptionalChainAssignment,
+
+    /// `New` に optional chaining `new f?.()` が指定されている
+    OptionalChainInNew,
+
+    /// `SetStatement` の `operator_maybe` が 複合代入演算子 `+=` などにできない演算子. 演算子の文字列
+    InvalidCompoundAssignmentOperator(String),
+
+    /// `++` や `--` の対象が 変数か optional chaining ではないプロパティの参照ではない
+    InvalidUpdateOperand,
+
+    /// `delete` の対象が変数. ES Modules は strict mode なので構文エラーになる
+    DeleteVariable,
+
+    /// `LabeledBreak` や `LabeledContinue` のラベルが 外側の文に付けられていない
+    UndefinedLabel(String),
+
+    /// `LabeledContinue` のラベルが ループではない文に付けられている
+    ContinueToNonLoopLabel(String),
+
+    /// ラベルが変数定義か関数定義に付けられている. ラベルの名前
+    LabeledDeclaration(String),
+
+    /// switch文の case の式が1つも指定されていない
+    EmptySwitchCaseList,
+
+    /// switch文の `exhaustive_type` が 文字列リテラル型かそのユニオン型ではない
+    InvalidExhaustiveType,
+
+    /// switch文の `exhaustive_type` の値のうち case にないもの
+    NonExhaustiveSwitch(Vec<String>),
+
+    /// `exhaustive_type` を指定した switch文の式が 変数かプロパティの参照ではない.
+    /// TypeScript で型を絞り込めず, default 節で もう一度評価されてしまう
+    NonReferenceExhaustiveSwitch,
+
+    /// `exhaustive_type` を指定した switch文の default 節で `exhaustiveCheck` を定義している.
+    /// TypeScript で出力する網羅性の検査の変数と衝突する
+    ExhaustiveCheckRedeclared,
+
+    /// オブジェクトリテラルのセッターのパラメーターが 省略可能か残余パラメーターになっている
+    InvalidSetterParameter,
+
+    /// クラスが継承しているが, コンストラクタで `super()` を呼んでいない
+    MissingSuperCall,
+
+    /// クラスが継承していないのに, コンストラクタで `super()` を呼んでいる
+    SuperCallWithoutExtends,
+
+    /// `static` のメンバーの名前が `prototype`. 構文エラーになる
+    StaticPrototypeMember,
+
+    /// `yield` がジェネレーター関数の外で使われている
+    YieldOutsideGenerator,
+
+    /// `for await` が async 関数の外で使われている. トップレベルでも使えない
+    ForAwaitOutsideAsync,
+
+    /// `await` が async 関数の外で使われている. トップレベルでも使えない
+    AwaitOutsideAsync,
+
+    /// 正規表現のパターンが ECMAScript の正規表現の構文として正しくない. パターンとエラーの内容
+    InvalidRegExpPattern(String, String),
+
+    /// 正規表現のフラグが正しくない. 知らないフラグ, 重複したフラグ, `u` と `v` の組み合わせ
+    InvalidRegExpFlags(String),
 }
 
 /// TypeScriptやJavaScriptのコードを表現する. TypeScriptでも出力できるように型情報をつける必要がある
 #[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct Code {
     /// 外部に公開する定義
     pub export_definition_list: Vec<ExportDefinition>,
@@ -21,7 +204,26 @@ pub struct Code {
     pub statement_list: Vec<Statement>,
 }
 
-/// 外部に公開する定義
+/// 複数のファイルからなるプロジェクト. ファイル間の参照は `ProjectReference` で表現する
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Project {
+    /// プロジェクト内のファイル
+    pub file_list: Vec<ProjectFile>,
+}
+
+/// プロジェクト内のファイル
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectFile {
+    /// 出力先のパス. プロジェクトのルートからの `/` 区切りの相対パスで, 拡張子は含めない `api/user`
+    pub file_path: String,
+
+    /// ファイルの中身
+    pub code: Code,
+}
+
+/// ファイルのトップレベルの定義. `visibility` が `Private` のものは外部に公開しない
 #[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
 pub enum ExportDefinition {
     /// 型定義. JavaScript では出力されない
@@ -32,6 +234,26 @@ pub enum ExportDefinition {
 
     /// 変数
     Variable(Variable),
+
+    /// 他のモジュールで export されているものを 名前を指定して export する
+    ExportFrom(ExportFrom),
+
+    /// 他のモジュールで export されているものを すべて export する
+    ExportAll(ExportAll),
+
+    /// default export. 1つのコードに1つまで
+    Default(DefaultExport),
+}
+
+/// トップレベルの定義を外部に公開するかどうか
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Default)]
+pub enum Visibility {
+    /// `export` をつけて外部に公開する
+    #[default]
+    Export,
+
+    /// `export` をつけずに, ファイルの中だけで使う
+    Private,
 }
 
 /// 型に別名を付けて定義する.
@@ -39,21 +261,34 @@ pub enum ExportDefinition {
 /// export type T = {}
 /// ```
 #[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct TypeAlias {
     pub name: identifer::Identifer,
     pub type_parameter_list: Vec<identifer::Identifer>,
     pub document: String,
+    /// ドキュメントに付ける `@deprecated` などのタグ. JSON で省略した場合はタグなし
+    #[serde(default)]
+    pub document_tag_list: Vec<DocumentTag>,
     pub r#type: Type,
+
+    /// 外部に公開するかどうか. JSON で省略した場合は公開する
+    #[serde(default)]
+    pub visibility: Visibility,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct Function {
-    /// 外部に公開する関数の名前
+    /// 関数の名前
     pub name: identifer::Identifer,
 
     /// ドキュメント
     pub document: String,
 
+    /// ドキュメントに付ける `@deprecated` などのタグ. JSON で省略した場合はタグなし
+    #[serde(default)]
+    pub document_tag_list: Vec<DocumentTag>,
+
     /// 型パラメーターのリスト
     pub type_parameter_list: Vec<identifer::Identifer>,
 
@@ -65,9 +300,27 @@ pub struct Function {
 
     /// 関数の本体
     pub statement_list: Vec<Statement>,
+
+    /// 外部に公開するかどうか. JSON で省略した場合は公開する
+    #[serde(default)]
+    pub visibility: Visibility,
+
+    /// 関数の定義の書き方. `None` なら `GenerateOption` の `function_style` を使う
+    #[serde(default)]
+    pub style: Option<FunctionStyle>,
+
+    /// async をつけるかどうか. JSON で省略した場合はつけない
+    #[serde(default)]
+    pub is_async: bool,
+
+    /// ジェネレーター関数 `function*` にするかどうか. アロー関数はジェネレーターにできないため `function*` で出力する.
+    /// JSON で省略した場合はジェネレーターにしない
+    #[serde(default)]
+    pub is_generator: bool,
 }
 /// ドキュメント付きの関数のパラメーター. パラメーター名, ドキュメント, 型
 #[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct ParameterWithDocument {
     /// パラメーター名
     pub name: identifer::Identifer,
@@ -77,20 +330,44 @@ pub struct ParameterWithDocument {
 
     /// パラメーターの型
     pub r#type: Type,
+
+    /// 省略可能かどうかなど. JSON で省略した場合は必須
+    #[serde(default)]
+    pub kind: ParameterKind,
 }
 
-/// 関数のパラメーター. パラメーター名, ドキュメント
+/// ラムダ式のパラメーター. パターン, 型
 #[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct Parameter {
-    /// パラメーター名
-    pub name: identifer::Identifer,
+    /// パラメーターを受け取るパターン
+    pub pattern: BindingPattern,
 
     /// パラメーターの型
     pub r#type: Type,
+
+    /// 省略可能かどうかなど. JSON で省略した場合は必須
+    #[serde(default)]
+    pub kind: ParameterKind,
+}
+
+/// パラメーターの種類. 必須のパラメーターは省略可能なパラメーターより前に, 残余パラメーターは最後に置く必要がある
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Default)]
+pub enum ParameterKind {
+    /// 必須 `x: T`
+    #[default]
+    Required,
+    /// 省略可能 `x?: T`
+    Optional,
+    /// デフォルト値あり `x: T = expr`. 宣言ファイルでは `x?: T` になる
+    Default(Expr),
+    /// 残余パラメーター `...x: T`. 型は配列の型を指定する
+    Rest,
 }
 
 /// 変数
 #[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct Variable {
     /// 変数の名前
     pub name: identifer::Identifer,
@@ -98,11 +375,147 @@ pub struct Variable {
     /// ドキュメント
     pub document: String,
 
+    /// ドキュメントに付ける `@deprecated` などのタグ. JSON で省略した場合はタグなし
+    #[serde(default)]
+    pub document_tag_list: Vec<DocumentTag>,
+
     /// 変数の型
     pub r#type: Type,
 
     /// 変数の式
     pub expr: Expr,
+
+    /// 外部に公開するかどうか. JSON で省略した場合は公開する
+    #[serde(default)]
+    pub visibility: Visibility,
+}
+
+/// 他のモジュールで export されているものを 名前を指定して export する
+/// ```ts
+/// export { a, b as c } from "./x";
+/// export type { T } from "./x";
+/// ```
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportFrom {
+    /// モジュール名 `./x`
+    pub module_name: String,
+
+    /// export する名前のリスト
+    pub name_list: Vec<ExportName>,
+
+    /// 型のみを export するかどうか. JavaScript では出力されない
+    pub is_type_only: bool,
+}
+
+/// `ExportFrom` で export する名前
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportName {
+    /// モジュールで export されている名前
+    pub name: identifer::Identifer,
+
+    /// 別の名前で export するときの名前. `b as c` の `c`
+    pub alias: Option<identifer::Identifer>,
+}
+
+/// 他のモジュールで export されているものを すべて export する
+/// ```ts
+/// export * from "./x";
+/// export * as ns from "./x";
+/// ```
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportAll {
+    /// モジュール名 `./x`
+    pub module_name: String,
+
+    /// 名前空間としてまとめて export するときの名前. `* as ns` の `ns`
+    pub namespace: Option<identifer::Identifer>,
+}
+
+/// default export
+/// ```ts
+/// export default expr;
+/// ```
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DefaultExport {
+    /// ドキュメント
+    pub document: String,
+
+    /// 型. 宣言ファイルと JSDoc で使う. クラス式の場合は メンバーの型を使うので使わない
+    pub r#type: Type,
+
+    /// export する式. 関数の場合はラムダ式を使う.
+    /// クラス式の場合は `export default class A {}` のようにクラス宣言として出力する
+    pub expr: Expr,
+}
+
+/// 変数の定義やパラメーターで 値を受け取るパターン. 分割代入ができる
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+pub enum BindingPattern {
+    /// 識別子 `a`
+    Identifer(identifer::Identifer),
+
+    /// オブジェクトの分割代入 `{ a, b: renamed, ...rest }`
+    Object(Box<ObjectPattern>),
+
+    /// 配列の分割代入 `[x, , y = 1]`
+    Array(Box<ArrayPattern>),
+}
+
+/// オブジェクトの分割代入
+/// ```ts
+/// { a, b: renamed, c = 0, ...rest }
+/// ```
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ObjectPattern {
+    /// プロパティのリスト
+    pub member_list: Vec<ObjectPatternMember>,
+
+    /// 残りのプロパティを受け取る変数 `...rest`
+    pub rest: Option<identifer::Identifer>,
+}
+
+/// オブジェクトの分割代入のプロパティ
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ObjectPatternMember {
+    /// プロパティ名
+    pub key: String,
+
+    /// プロパティの値を受け取るパターン
+    pub pattern: BindingPattern,
+
+    /// 値が `undefined` だったときに使う値
+    pub default_expr: Option<Expr>,
+}
+
+/// 配列の分割代入
+/// ```ts
+/// [x, , y = 1, ...rest]
+/// ```
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ArrayPattern {
+    /// 要素のリスト. `None` の要素は読み飛ばす
+    pub item_list: Vec<Option<ArrayPatternItem>>,
+
+    /// 残りの要素を受け取る変数 `...rest`
+    pub rest: Option<identifer::Identifer>,
+}
+
+/// 配列の分割代入の要素
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ArrayPatternItem {
+    /// 要素を受け取るパターン
+    pub pattern: BindingPattern,
+
+    /// 値が `undefined` だったときに使う値
+    pub default_expr: Option<Expr>,
 }
 
 /// 単項演算子
@@ -111,6 +524,22 @@ pub enum UnaryOperator {
     Minus,
     BitwiseNot,
     LogicalNot,
+    /// `+a`
+    Plus,
+    /// `typeof a`
+    TypeOf,
+    /// `void a`
+    Void,
+    /// `delete a.b`
+    Delete,
+    /// `++a`
+    PrefixIncrement,
+    /// `--a`
+    PrefixDecrement,
+    /// `a++`
+    PostfixIncrement,
+    /// `a--`
+    PostfixDecrement,
 }
 
 /// 2項演算子
@@ -127,13 +556,31 @@ pub enum BinaryOperator {
     UnsignedRightShift,
     LessThan,
     LessThanOrEqual,
+    /// `>`
+    GreaterThan,
+    /// `>=`
+    GreaterThanOrEqual,
+    /// `in`
+    In,
+    /// `instanceof`
+    InstanceOf,
+    /// `===`
     Equal,
+    /// `!==`
     NotEqual,
+    /// `==`
+    LooseEqual,
+    /// `!=`
+    LooseNotEqual,
     BitwiseAnd,
     BitwiseXOr,
     BitwiseOr,
     LogicalAnd,
     LogicalOr,
+    /// `??`. `&&` や `||` と組み合わせるときは括弧が必須
+    NullishCoalescing,
+    /// カンマ演算子 `a, b`. 関数の引数などの中では括弧で囲まれる
+    Comma,
 }
 
 /// 式
@@ -157,6 +604,17 @@ pub enum Expr {
     Call(Box<CallExpr>),
     New(Box<CallExpr>),
     TypeAssertion(Box<TypeAssertion>),
+    ProjectVariable(ProjectReference),
+    /// `yield a` や `yield* a`. ジェネレーター関数の中でのみ使える
+    Yield(Box<YieldExpr>),
+    /// `await a`. async 関数の中でのみ使える
+    Await(Box<Expr>),
+    /// クラス式 `class A extends B {}`
+    Class(Box<ClassExpr>),
+    /// `this`
+    This,
+    /// 正規表現リテラル `/a+/gu`
+    RegExpLiteral(RegExpLiteral),
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
@@ -175,6 +633,22 @@ pub enum Statement {
     WhileTrue(Vec<Statement>),
     Break,
     Switch(SwitchStatement),
+    /// `for (init; test; update) {}`
+    ForLoop(ForLoopStatement),
+    /// `while (condition) {}`
+    While(WhileStatement),
+    /// `do {} while (condition);`
+    DoWhile(WhileStatement),
+    /// `for (const key in object) {}`
+    ForIn(ForInStatement),
+    /// `label: for (;;) {}`
+    Labeled(LabeledStatement),
+    /// `break label;`
+    LabeledBreak(identifer::Identifer),
+    /// `continue label;`
+    LabeledContinue(identifer::Identifer),
+    /// コメントを付けた文
+    Commented(CommentedStatement),
 }
 
 /// 型
@@ -196,9 +670,11 @@ pub enum Type {
     ScopeInFile(identifer::Identifer),
     ScopeInGlobal(identifer::Identifer),
     StringLiteral(String),
+    ProjectType(ProjectReference),
 }
 /// 単項演算子と適用される式
 #[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct UnaryOperatorExpr {
     /// 単項演算子
     pub operator: UnaryOperator,
@@ -209,6 +685,7 @@ pub struct UnaryOperatorExpr {
 
 /// 2項演算子と左右の式
 #[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct BinaryOperatorExpr {
     /// 2項演算子
     pub operator: BinaryOperator,
@@ -221,6 +698,7 @@ pub struct BinaryOperatorExpr {
 }
 /// 条件演算子
 #[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct ConditionalOperatorExpr {
     /// 条件の式
     pub condition: Expr,
@@ -231,24 +709,54 @@ pub struct ConditionalOperatorExpr {
     /// 条件がfalseのときに評価される式
     pub else_expr: Expr,
 }
-/// 配列リテラルの要素
+/// 配列リテラルの要素や 関数呼び出しのパラメーター
 #[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct ArrayItem {
     /// 式
     pub expr: Expr,
 
-    /// スプレッド ...a のようにするか
+    /// スプレッド ...a のようにするか. JSON で省略した場合はしない
+    #[serde(default)]
     pub spread: bool,
+
+    /// 要素の前の行に出力するコメント. コメントがある配列リテラルや関数呼び出しは 複数行で出力する.
+    /// JSON で省略した場合はコメントなし
+    #[serde(default)]
+    pub comment_list: Vec<Comment>,
 }
 /// オブジェクトリテラルの要素
 #[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
 pub enum Member {
     Spread(Expr),
     KeyValue(KeyValue),
+    /// `[key]: value`
+    ComputedKeyValue(ComputedKeyValue),
+    /// `key() {}`
+    Method(ObjectMethod),
+    /// `get key() {}`
+    Getter(ObjectGetter),
+    /// `set key(value) {}`
+    Setter(ObjectSetter),
+    /// 前の行にコメントを付けたメンバー. コメントがあるオブジェクトリテラルは 複数行で出力する
+    Commented(CommentedMember),
 }
 
-/// 文字列のkeyと式のvalue
+/// 前の行にコメントを付けたオブジェクトリテラルのメンバー
 #[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentedMember {
+    /// メンバーの前の行に出力するコメント
+    pub comment_list: Vec<Comment>,
+
+    /// コメントを付けるメンバー
+    pub member: Box<Member>,
+}
+
+/// 文字列のkeyと式のvalue. key が `"0"` のような数値なら `0: value` と出力する.
+/// key が `__proto__` のときは プロトタイプを設定せずに自身のプロパティにするため `["__proto__"]: value` と出力する
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct KeyValue {
     /// key
     pub key: String,
@@ -257,8 +765,85 @@ pub struct KeyValue {
     pub value: Expr,
 }
 
+/// 式のkeyと式のvalue
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ComputedKeyValue {
+    /// key の式
+    pub key_expr: Expr,
+
+    /// value
+    pub value: Expr,
+}
+
+/// メソッドやゲッター, セッターのキー
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+pub enum PropertyKey {
+    /// 識別子として使えない文字列なら 文字列リテラルとして出力する
+    Name(String),
+    /// `[expr]`
+    Computed(Expr),
+}
+
+/// オブジェクトリテラルのメソッド
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ObjectMethod {
+    /// キー
+    pub key: PropertyKey,
+
+    /// 型パラメーターのリスト
+    pub type_parameter_list: Vec<identifer::Identifer>,
+
+    /// パラメーターのリスト
+    pub parameter_list: Vec<Parameter>,
+
+    /// 戻り値の型
+    pub return_type: Type,
+
+    /// メソッド本体
+    pub statement_list: Vec<Statement>,
+
+    /// async をつけるかどうか. JSON で省略した場合はつけない
+    #[serde(default)]
+    pub is_async: bool,
+
+    /// ジェネレーター `*key() {}` にするかどうか. JSON で省略した場合はジェネレーターにしない
+    #[serde(default)]
+    pub is_generator: bool,
+}
+
+/// オブジェクトリテラルのゲッター
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ObjectGetter {
+    /// キー
+    pub key: PropertyKey,
+
+    /// 値の型
+    pub return_type: Type,
+
+    /// ゲッター本体
+    pub statement_list: Vec<Statement>,
+}
+
+/// オブジェクトリテラルのセッター
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ObjectSetter {
+    /// キー
+    pub key: PropertyKey,
+
+    /// 設定される値を受け取るパラメーター
+    pub parameter: Parameter,
+
+    /// セッター本体
+    pub statement_list: Vec<Statement>,
+}
+
 /// ラムダ式
 #[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct LambdaExpr {
     /// パラメーターのリスト
     pub parameter_list: Vec<Parameter>,
@@ -271,9 +856,91 @@ pub struct LambdaExpr {
 
     /// ラムダ式本体
     pub statement_list: Vec<Statement>,
+
+    /// async をつけるかどうか. JSON で省略した場合はつけない
+    #[serde(default)]
+    pub is_async: bool,
+
+    /// ジェネレーター関数 `function*` にするかどうか. アロー関数はジェネレーターにできないため `function*` で出力する.
+    /// JSON で省略した場合はジェネレーターにしない
+    #[serde(default)]
+    pub is_generator: bool,
+}
+
+/// クラス式
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ClassExpr {
+    /// クラス名. JSON で省略した場合は名前のないクラス
+    pub name: Option<identifer::Identifer>,
+
+    /// 継承するクラスの式 `extends B`. JSON で省略した場合は継承しない
+    pub extends: Option<Expr>,
+
+    /// コンストラクタ. JSON で省略した場合は定義しない
+    pub constructor: Option<ClassConstructor>,
+
+    /// メンバーのリスト
+    pub member_list: Vec<ClassMember>,
+}
+
+/// クラスのコンストラクタ
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ClassConstructor {
+    /// パラメーターのリスト
+    pub parameter_list: Vec<Parameter>,
+
+    /// 本体の最初で呼ぶ `super(a, b)` のパラメーター. 継承しているクラスでは必須.
+    /// JSON で省略した場合は呼ばない
+    pub super_parameter_list: Option<Vec<ArrayItem>>,
+
+    /// コンストラクタ本体
+    pub statement_list: Vec<Statement>,
 }
+
+/// クラスのメンバー
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ClassMember {
+    /// `static` をつけるかどうか. JSON で省略した場合はつけない
+    #[serde(default)]
+    pub is_static: bool,
+
+    /// メンバーの種類
+    pub kind: ClassMemberKind,
+}
+
+/// クラスのメンバーの種類. キーが `constructor` のものは コンストラクタと区別するため `["constructor"]` と出力する
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+pub enum ClassMemberKind {
+    /// `key: Type = value;`
+    Field(ClassField),
+    /// `key() {}`
+    Method(ObjectMethod),
+    /// `get key() {}`
+    Getter(ObjectGetter),
+    /// `set key(value) {}`
+    Setter(ObjectSetter),
+}
+
+/// クラスのフィールド
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ClassField {
+    /// キー
+    pub key: PropertyKey,
+
+    /// 型
+    pub r#type: Type,
+
+    /// 初期値. JSON で省略した場合は初期値を指定しない
+    pub value: Option<Expr>,
+}
+
 /// インポートした変数
 #[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct ImportedVariable {
     /// モジュール名, 使うときにはnamedインポートされ, そのモジュール識別子は自動的につけられる
     pub module_name: String,
@@ -283,25 +950,58 @@ pub struct ImportedVariable {
 }
 /// プロパティアクセス
 #[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct GetExpr {
     /// 式
     pub expr: Expr,
 
     /// プロパティの式
     pub property_expr: Expr,
+
+    /// `a?.b` のように optional chaining を使うかどうか. JSON で省略した場合は使わない
+    #[serde(default)]
+    pub is_optional_chain: bool,
 }
 
 /// 式と呼ぶパラメーター
 #[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct CallExpr {
     /// 呼ばれる式
     pub expr: Expr,
-    /// パラメーター
-    pub parameter_list: Vec<Expr>,
+    /// パラメーター. `spread` で `f(...a)` のようにできる
+    pub parameter_list: Vec<ArrayItem>,
+
+    /// `f?.()` のように optional chaining を使うかどうか. `New` では使えない. JSON で省略した場合は使わない
+    #[serde(default)]
+    pub is_optional_chain: bool,
+}
+
+/// yield式
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct YieldExpr {
+    /// 返す値の式. `None` なら値を指定しない `yield`
+    pub expr: Option<Expr>,
+
+    /// 他のイテレーターに委譲する `yield*` にするかどうか
+    pub is_delegate: bool,
+}
+
+/// 正規表現リテラル
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RegExpLiteral {
+    /// パターン. `new RegExp(pattern)` に渡す文字列と同じで, `/` や改行はエスケープしなくてよい
+    pub pattern: String,
+
+    /// フラグ `dgimsuvy`. 同じフラグを2回以上指定したり, `u` と `v` を同時に指定したりはできない
+    pub flags: String,
 }
 
 /// 型アサーション
 #[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct TypeAssertion {
     /// 型アサーションを受ける式
     pub expr: Expr,
@@ -312,11 +1012,13 @@ pub struct TypeAssertion {
 
 /// 代入文
 #[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct SetStatement {
     /// 対象となる式. 指定の仕方によってはJSのSyntaxErrorになる
     pub target: Expr,
 
-    /// 演算子を=の左につける
+    /// 演算子を=の左につける. `LogicalOr` なら `||=`, `NullishCoalescing` なら `??=`.
+    /// 比較演算子, `in`, `instanceof`, カンマ演算子は 複合代入演算子にできないので検証エラーになる
     pub operator_maybe: Option<BinaryOperator>,
 
     /// 式
@@ -325,6 +1027,7 @@ pub struct SetStatement {
 
 /// if文
 #[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct IfStatement {
     /// 条件の式
     pub condition: Expr,
@@ -335,9 +1038,10 @@ pub struct IfStatement {
 
 /// ローカル変数定義
 #[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct VariableDefinitionStatement {
-    /// 変数名
-    pub name: identifer::Identifer,
+    /// 値を受け取るパターン
+    pub pattern: BindingPattern,
 
     /// 変数の型
     pub r#type: Type,
@@ -351,6 +1055,7 @@ pub struct VariableDefinitionStatement {
 
 /// ローカル関数定義
 #[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct FunctionDefinitionStatement {
     /// 変数名
     pub name: identifer::Identifer,
@@ -366,10 +1071,24 @@ pub struct FunctionDefinitionStatement {
 
     /// 関数本体
     pub statement_list: Vec<Statement>,
+
+    /// async をつけるかどうか. JSON で省略した場合はつけない
+    #[serde(default)]
+    pub is_async: bool,
+
+    /// ジェネレーター関数 `function*` にするかどうか. アロー関数はジェネレーターにできないため `function*` で出力する.
+    /// JSON で省略した場合はジェネレーターにしない
+    #[serde(default)]
+    pub is_generator: bool,
+
+    /// 関数の定義の書き方. `None` なら `GenerateOption` の `function_style` を使う
+    #[serde(default)]
+    pub style: Option<FunctionStyle>,
 }
 
 /// for文
 #[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct ForStatement {
     /// カウンタ変数名
     pub counter_variable_name: identifer::Identifer,
@@ -383,32 +1102,152 @@ pub struct ForStatement {
 
 /// forOf文
 #[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct ForOfStatement {
-    /// 要素の変数名
-    pub element_variable_name: identifer::Identifer,
+    /// 要素を受け取るパターン
+    pub element_pattern: BindingPattern,
 
     /// 繰り返す対象
     pub iterable_expr: Expr,
 
     /// 繰り返す文
     pub statement_list: Vec<Statement>,
+
+    /// `for await (const a of b)` にするかどうか. async 関数の中でのみ使える. JSON で省略した場合は await しない
+    #[serde(default)]
+    pub is_await: bool,
+}
+
+/// 初期化, 条件, 更新を指定するfor文
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ForLoopStatement {
+    /// 初期化. `None` なら省略する
+    pub init: Option<ForInit>,
+
+    /// 繰り返す条件の式. `None` なら省略する
+    pub test: Option<Expr>,
+
+    /// 1回繰り返すごとに実行する更新. `None` なら省略する
+    pub update: Option<ForUpdate>,
+
+    /// 繰り返す文
+    pub statement_list: Vec<Statement>,
+}
+
+/// for文の初期化の部分
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+pub enum ForInit {
+    /// `let i = 0`
+    VariableDefinition(VariableDefinitionStatement),
+    /// `i = 0`
+    Set(SetStatement),
+    Expr(Expr),
+}
+
+/// for文の更新の部分
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+pub enum ForUpdate {
+    /// `i += 1`
+    Set(SetStatement),
+    Expr(Expr),
+}
+
+/// while文, do-while文
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct WhileStatement {
+    /// 繰り返す条件の式
+    pub condition: Expr,
+
+    /// 繰り返す文
+    pub statement_list: Vec<Statement>,
+}
+
+/// forIn文
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ForInStatement {
+    /// キーを受け取る変数名
+    pub key_variable_name: identifer::Identifer,
+
+    /// キーを列挙する対象
+    pub object_expr: Expr,
+
+    /// 繰り返す文
+    pub statement_list: Vec<Statement>,
+}
+
+/// ラベル付きの文. `LabeledBreak` や `LabeledContinue` でラベルを指定して外側のループから抜けられる
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LabeledStatement {
+    /// ラベル
+    pub label: identifer::Identifer,
+
+    /// ラベルを付ける文
+    pub statement: Box<Statement>,
+}
+
+/// コメントを付けた文
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentedStatement {
+    /// 文の前の行に出力するコメント
+    pub leading_comment_list: Vec<Comment>,
+
+    /// コメントを付ける文
+    pub statement: Box<Statement>,
+
+    /// 文の後ろの同じ行に出力する `// text` のコメント. 2行目以降は 次の行に `//` を付けて出力する
+    #[serde(default)]
+    pub trailing_comment: Option<String>,
+}
+
+/// コメント. 改行を含む場合は行ごとに出力する
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+pub enum Comment {
+    /// `// text`
+    Line(String),
+
+    /// `/* text */`. text の中の `*/` は `*\/` にエスケープする
+    Block(String),
+
+    /// `// eslint-disable-next-line rule-a, rule-b`. ルールが空なら 次の行のすべてのルールを無効にする
+    EslintDisableNextLine(Vec<String>),
+
+    /// `// @ts-expect-error reason`. 次の行の型エラーを無視する
+    TsExpectError(String),
 }
 
 /// switch文
 #[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct SwitchStatement {
     /// switch(a) {} の a
     pub expr: Expr,
 
-    /// case "text": { statementList }
+    /// case "text": statementList
     pub pattern_list: Vec<Pattern>,
+
+    /// default: statementList. `None` なら default 節を出力しない
+    #[serde(default)]
+    pub default_statement_list: Option<Vec<Statement>>,
+
+    /// 文字列リテラル型のユニオン型を指定すると, すべての値の case があるかを検証する.
+    /// TypeScript では default 節で `expr` を `never` 型の `exhaustiveCheck` に代入して 型でも網羅されていることを確かめる.
+    /// `expr` は変数かプロパティの参照である必要がある
+    #[serde(default)]
+    pub exhaustive_type: Option<Type>,
 }
 
-/// switch文のcase "text": { statementList } の部分
+/// switch文の case "a": case "b": statementList の部分.
+/// 最後に break などがなければ 次の case に続けて実行される
 #[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct Pattern {
-    /// case に使う文字列
-    pub case_string: String,
+    /// case に使う式. 複数指定すると 同じ statementList を共有する
+    pub case_list: Vec<Expr>,
 
     /// statementList
     pub statement_list: Vec<Statement>,
@@ -416,6 +1255,7 @@ pub struct Pattern {
 
 /// オブジェクトのメンバーの型
 #[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct MemberType {
     /// プロパティ名
     pub name: String,
@@ -428,10 +1268,37 @@ pub struct MemberType {
 
     /// ドキュメント
     pub document: String,
+
+    /// ドキュメントに付ける `@deprecated` などのタグ. JSON で省略した場合はタグなし
+    #[serde(default)]
+    pub document_tag_list: Vec<DocumentTag>,
+}
+
+/// ドキュメントの JSDoc のタグ
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+pub enum DocumentTag {
+    /// `@deprecated 理由`. 空文字なら理由なし
+    Deprecated(String),
+
+    /// `@example` とコードブロック. コードの言語は出力する言語と同じにする
+    Example(String),
+
+    /// `@see 参照先`
+    See(String),
+
+    /// `@returns 説明`. JSDoc で型を表現する場合は `@returns {Type} 説明` になる
+    Returns(String),
+
+    /// `@throws 説明`. `{TypeError} 説明` のように型を含めてもよい
+    Throws(String),
+
+    /// `@since バージョン`
+    Since(String),
 }
 
 /// 関数の型
 #[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct FunctionType {
     /// 型パラメーターのリスト
     pub type_parameter_list: Vec<identifer::Identifer>,
@@ -439,12 +1306,21 @@ pub struct FunctionType {
     /// パラメーターの型. 意味のない引数名は適当に付く
     pub parameter_list: Vec<Type>,
 
+    /// 必須のパラメーターの後に続く 省略可能なパラメーターの型
+    #[serde(default)]
+    pub optional_parameter_list: Vec<Type>,
+
+    /// 残余パラメーターの型 `...$2: T[]`
+    #[serde(default)]
+    pub rest_parameter: Option<Type>,
+
     /// 戻り値の型
     pub return_type: Type,
 }
 
 /// パラメーター付きの型
 #[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct TypeWithTypeParameter {
     /// パラメーターをつけられる型
     pub r#type: Type,
@@ -455,6 +1331,7 @@ pub struct TypeWithTypeParameter {
 
 /// 交差型 `A & B`
 #[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct IntersectionType {
     /// 左に指定する型
     pub left: Type,
@@ -465,6 +1342,7 @@ pub struct IntersectionType {
 
 /// インポートされた型
 #[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct ImportedType {
     /// モジュール名 `fs` `three` `react` `typescript` `util` など
     pub module_name: String,
@@ -472,3 +1350,15 @@ pub struct ImportedType {
     /// 型の名前
     pub name: identifer::Identifer,
 }
+
+/// プロジェクト内のファイルで export されている定義への参照.
+/// 出力するときに, 参照しているファイルからの相対パスでインポートされる
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectReference {
+    /// 定義されているファイルのパス. `ProjectFile::file_path` と同じ形式
+    pub file_path: String,
+
+    /// 定義の名前
+    pub name: identifer::Identifer,
+}
@@ -1,5 +1,8 @@
 use serde::{Deserialize, Serialize};
-#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+
+/// 識別子. JSON などでは文字列としてシリアライズされる
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone)]
+#[serde(transparent)]
 pub struct Identifer {
     value: String,
 }
@@ -29,7 +32,7 @@ pub fn from_string(word: &str) -> Identifer {
                     result.push_str(&to_safe_string(&char))
                 }
                 let r: &str = &result;
-                if RESERVED_BY_LANGUAGE_WORD_SET.contains(&&r) {
+                if RESERVED_BY_LANGUAGE_WORD_SET.contains(&r) {
                     result + "_"
                 } else {
                     result
@@ -56,10 +59,10 @@ fn test_from_string() {
 }
 
 fn to_safe_first_char(char: &char) -> String {
-    if FIRST_SAFE_CHAR_SET.contains(&char) {
+    if FIRST_SAFE_CHAR_SET.contains(char) {
         char.to_string()
     } else {
-        escape_char(&char)
+        escape_char(char)
     }
 }
 
@@ -77,10 +80,10 @@ const SAFE_CHAR_SET: [char; 64] = [
 ];
 
 fn to_safe_string(char: &char) -> String {
-    if SAFE_CHAR_SET.contains(&char) {
+    if SAFE_CHAR_SET.contains(char) {
         String::from(*char)
     } else {
-        escape_char(&char)
+        escape_char(char)
     }
 }
 
@@ -165,6 +168,21 @@ const RESERVED_BY_LANGUAGE_WORD_SET: [&str; 68] = [
     "self",
 ];
 
+/// 識別子として直接コードで指定できるかどうか. `from_string` で作られた識別子は常に true になる
+pub fn is_identifer(word: &str) -> bool {
+    is_safe_property_name(word) && !RESERVED_BY_LANGUAGE_WORD_SET.contains(&word)
+}
+
+#[test]
+fn test_is_identifer() {
+    assert!(is_identifer("a"));
+    assert!(is_identifer(&from_string("this").get()));
+    assert!(is_identifer(&from_string("あ").get()));
+    assert!(!is_identifer("this"));
+    assert!(!is_identifer("0a"));
+    assert!(!is_identifer(""));
+}
+
 ///
 /// ```ts
 /// ({ await: 32 }.await)
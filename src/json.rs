@@ -0,0 +1,364 @@
+use crate::data;
+use crate::reflection;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use serde_reflection::{ContainerFormat, Format, Named, VariantFormat};
+
+/// JSON 形式のバージョン. 互換性のない変更をしたら上げる
+pub const FORMAT_VERSION: u32 = 4;
+
+/// JSON で表現された `data::Code`
+///
+/// ```json
+/// { "formatVersion": 4, "code": { "exportDefinitionList": [], "statementList": [] } }
+/// ```
+///
+/// - 構造体のフィールド名は camelCase
+/// - enum は `{ "列挙子名": 値 }` の形式. 値を持たない列挙子は `"列挙子名"` の文字列 (`{ "列挙子名": null }` も読める)
+/// - 識別子は文字列
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CodeJson {
+    /// JSON 形式のバージョン. `FORMAT_VERSION` と同じである必要がある
+    pub format_version: u32,
+
+    /// コード
+    pub code: data::Code,
+}
+
+/// バージョンだけを先に読むためのもの
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FormatVersionOnly {
+    format_version: u32,
+}
+
+/// JSON からのデコードのエラー
+#[derive(Debug)]
+pub enum DecodeError {
+    /// JSON として正しくないか, 形式が違う
+    Json(serde_json::Error),
+
+    /// 対応していない JSON 形式のバージョン
+    UnsupportedFormatVersion(u32),
+}
+
+impl std::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DecodeError::Json(error) => write!(f, "invalid json: {}", error),
+            DecodeError::UnsupportedFormatVersion(version) => write!(
+                f,
+                "unsupported format version {} (expected {})",
+                version, FORMAT_VERSION
+            ),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+/// `data::Code` を JSON 形式の文字列にする
+pub fn to_json(code: &data::Code) -> String {
+    serde_json::to_string(&CodeJsonRef {
+        format_version: FORMAT_VERSION,
+        code,
+    })
+    .expect("data::Code の JSON へのシリアライズに失敗することはない")
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CodeJsonRef<'a> {
+    format_version: u32,
+    code: &'a data::Code,
+}
+
+/// JSON 形式の文字列から `data::Code` を読み取る
+pub fn from_json(json: &str) -> Result<data::Code, DecodeError> {
+    let version = serde_json::from_str::<FormatVersionOnly>(json)
+        .map_err(DecodeError::Json)?
+        .format_version;
+    if version != FORMAT_VERSION {
+        return Err(DecodeError::UnsupportedFormatVersion(version));
+    }
+    serde_json::from_str::<CodeJson>(json)
+        .map(|code_json| code_json.code)
+        .map_err(DecodeError::Json)
+}
+
+/// JSON 形式を表現した JSON Schema (draft-07)
+pub fn schema() -> Value {
+    let registry = reflection::registry().expect("data の型の trace に失敗した");
+    let mut definitions = Map::new();
+    for (name, container_format) in registry.iter() {
+        definitions.insert(
+            name.clone(),
+            container_format_to_schema(name, container_format),
+        );
+    }
+    json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": "js-ts-code-generator Code",
+        "type": "object",
+        "properties": {
+            "formatVersion": { "const": FORMAT_VERSION },
+            "code": { "$ref": "#/definitions/Code" }
+        },
+        "required": ["formatVersion", "code"],
+        "additionalProperties": false,
+        "definitions": definitions
+    })
+}
+
+/// `#[serde(default)]` を付けていて JSON で省略できるフィールド. serde-reflection では分からないので ここに並べる.
+/// `Option` のフィールドは 省略すると `None` になるので 並べなくてよい
+const DEFAULT_FIELD_LIST: [(&str, &str); 27] = [
+    ("GenerateOption", "functionStyle"),
+    ("GenerateOption", "isAsciiOnly"),
+    ("GenerateOption", "commonJsDefaultExport"),
+    ("TypeAlias", "documentTagList"),
+    ("TypeAlias", "visibility"),
+    ("Function", "documentTagList"),
+    ("Function", "visibility"),
+    ("Function", "isAsync"),
+    ("Function", "isGenerator"),
+    ("ParameterWithDocument", "kind"),
+    ("Parameter", "kind"),
+    ("Variable", "documentTagList"),
+    ("Variable", "visibility"),
+    ("ArrayItem", "spread"),
+    ("ArrayItem", "commentList"),
+    ("ObjectMethod", "isAsync"),
+    ("ObjectMethod", "isGenerator"),
+    ("LambdaExpr", "isAsync"),
+    ("LambdaExpr", "isGenerator"),
+    ("ClassMember", "isStatic"),
+    ("GetExpr", "isOptionalChain"),
+    ("CallExpr", "isOptionalChain"),
+    ("FunctionDefinitionStatement", "isAsync"),
+    ("FunctionDefinitionStatement", "isGenerator"),
+    ("ForOfStatement", "isAwait"),
+    ("MemberType", "documentTagList"),
+    ("FunctionType", "optionalParameterList"),
+];
+
+fn container_format_to_schema(name: &str, container_format: &ContainerFormat) -> Value {
+    match container_format {
+        ContainerFormat::UnitStruct => json!({ "type": "null" }),
+        ContainerFormat::NewTypeStruct(format) => format_to_schema(format),
+        ContainerFormat::TupleStruct(format_list) => tuple_to_schema(format_list),
+        ContainerFormat::Struct(field_list) => struct_to_schema(name, field_list),
+        ContainerFormat::Enum(variant_map) => json!({
+            "oneOf": variant_map
+                .values()
+                .map(variant_to_schema)
+                .collect::<Vec<Value>>()
+        }),
+    }
+}
+
+fn variant_to_schema(variant: &Named<VariantFormat>) -> Value {
+    let value = match &variant.value {
+        // serde_json は `{ "列挙子名": null }` も読める
+        VariantFormat::Unit | VariantFormat::Variable(_) => {
+            return json!({
+                "anyOf": [
+                    { "const": variant.name },
+                    {
+                        "type": "object",
+                        "properties": { variant.name.clone(): { "type": "null" } },
+                        "required": [variant.name],
+                        "additionalProperties": false
+                    }
+                ]
+            })
+        }
+        VariantFormat::NewType(format) => format_to_schema(format),
+        VariantFormat::Tuple(format_list) => tuple_to_schema(format_list),
+        VariantFormat::Struct(field_list) => struct_to_schema(&variant.name, field_list),
+    };
+    let mut properties = Map::new();
+    properties.insert(variant.name.clone(), value);
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": [variant.name],
+        "additionalProperties": false
+    })
+}
+
+/// 構造体のスキーマ. `Option` と `#[serde(default)]` のフィールドは `required` に含めない
+fn struct_to_schema(name: &str, field_list: &[Named<Format>]) -> Value {
+    let mut properties = Map::new();
+    for field in field_list {
+        properties.insert(field.name.clone(), format_to_schema(&field.value));
+    }
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": field_list
+            .iter()
+            .filter(|field| {
+                !matches!(field.value, Format::Option(_))
+                    && !DEFAULT_FIELD_LIST.contains(&(name, field.name.as_str()))
+            })
+            .map(|field| field.name.clone())
+            .collect::<Vec<String>>(),
+        "additionalProperties": false
+    })
+}
+
+fn tuple_to_schema(format_list: &[Format]) -> Value {
+    json!({
+        "type": "array",
+        "items": format_list.iter().map(format_to_schema).collect::<Vec<Value>>(),
+        "minItems": format_list.len(),
+        "maxItems": format_list.len()
+    })
+}
+
+fn format_to_schema(format: &Format) -> Value {
+    match format {
+        Format::Variable(_) => json!({}),
+        Format::TypeName(name) => json!({ "$ref": format!("#/definitions/{}", name) }),
+        Format::Unit => json!({ "type": "null" }),
+        Format::Bool => json!({ "type": "boolean" }),
+        Format::I8 => integer_schema(i8::MIN as i64, i8::MAX as u64),
+        Format::I16 => integer_schema(i16::MIN as i64, i16::MAX as u64),
+        Format::I32 => integer_schema(i32::MIN as i64, i32::MAX as u64),
+        Format::U8 => integer_schema(0, u8::MAX as u64),
+        Format::U16 => integer_schema(0, u16::MAX as u64),
+        Format::U32 => integer_schema(0, u32::MAX as u64),
+        Format::I64 | Format::I128 | Format::U64 | Format::U128 => json!({ "type": "integer" }),
+        Format::F32 | Format::F64 => json!({ "type": "number" }),
+        Format::Char => json!({ "type": "string", "minLength": 1, "maxLength": 1 }),
+        Format::Str => json!({ "type": "string" }),
+        Format::Bytes => json!({ "type": "array", "items": integer_schema(0, u8::MAX as u64) }),
+        Format::Option(format) => {
+            json!({ "anyOf": [{ "type": "null" }, format_to_schema(format)] })
+        }
+        Format::Seq(format) => json!({ "type": "array", "items": format_to_schema(format) }),
+        Format::Map { value, .. } => {
+            json!({ "type": "object", "additionalProperties": format_to_schema(value) })
+        }
+        Format::Tuple(format_list) => tuple_to_schema(format_list),
+        Format::TupleArray { content, size } => json!({
+            "type": "array",
+            "items": format_to_schema(content),
+            "minItems": size,
+            "maxItems": size
+        }),
+    }
+}
+
+fn integer_schema(minimum: i64, maximum: u64) -> Value {
+    json!({ "type": "integer", "minimum": minimum, "maximum": maximum })
+}
+
+#[test]
+fn test_json_round_trip() {
+    let code = crate::sample_code();
+    let json = to_json(&code);
+    assert!(json.starts_with(
+        r#"{"formatVersion":4,"code":{"exportDefinitionList":[{"Function":{"name":"middleware","#
+    ));
+    assert_eq!(from_json(&json).unwrap(), code);
+}
+
+#[test]
+fn test_json_format_version() {
+    match from_json(r#"{"formatVersion":5,"code":{}}"#) {
+        Err(DecodeError::UnsupportedFormatVersion(5)) => {}
+        other => panic!("バージョンのエラーになるはず {:?}", other),
+    }
+    match from_json(r#"{"code":{"exportDefinitionList":[],"statementList":[]}}"#) {
+        Err(DecodeError::Json(_)) => {}
+        other => panic!("JSON のエラーになるはず {:?}", other),
+    }
+    assert_eq!(
+        from_json(
+            r#"{"formatVersion":4,"code":{"exportDefinitionList":[],"statementList":["Break"]}}"#
+        )
+        .unwrap(),
+        data::Code {
+            export_definition_list: vec![],
+            statement_list: vec![data::Statement::Break],
+        }
+    );
+}
+
+#[test]
+fn gen_json_schema() {
+    let schema = schema();
+    assert_eq!(
+        schema["definitions"]["Code"]["required"],
+        json!(["exportDefinitionList", "statementList"])
+    );
+    let _ = std::fs::write(
+        "code.schema.json",
+        serde_json::to_string_pretty(&schema).unwrap() + "\n",
+    );
+}
+
+#[test]
+fn test_default_field_list() {
+    let registry = reflection::registry().unwrap();
+    for (name, field_name) in DEFAULT_FIELD_LIST.iter() {
+        match registry.get(*name) {
+            Some(ContainerFormat::Struct(field_list)) => assert!(
+                field_list.iter().any(|field| field.name == *field_name),
+                "{}.{} がない",
+                name,
+                field_name
+            ),
+            _ => panic!("構造体 {} がない", name),
+        }
+    }
+}
+
+/// ソースコードの中のテストで使っている JSON が スキーマに合うことを確かめる
+#[test]
+fn test_fixture_match_schema() {
+    fn source_file_list(directory: &std::path::Path) -> Vec<std::path::PathBuf> {
+        let mut file_list = Vec::new();
+        for entry in std::fs::read_dir(directory).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                file_list.extend(source_file_list(&path));
+            } else if path.extension().is_some_and(|extension| extension == "rs") {
+                file_list.push(path);
+            }
+        }
+        file_list
+    }
+
+    let schema = jsonschema::JSONSchema::compile(&schema()).unwrap();
+    let assert_valid = |json: &str| {
+        if let Err(error_list) = schema.validate(&serde_json::from_str(json).unwrap()) {
+            panic!(
+                "{}\n{}",
+                error_list
+                    .map(|error| error.to_string())
+                    .collect::<Vec<String>>()
+                    .join("\n"),
+                json
+            );
+        }
+    };
+    assert_valid(&to_json(&crate::sample_code()));
+    let mut fixture_count = 0;
+    for path in source_file_list(std::path::Path::new("src")) {
+        let source = std::fs::read_to_string(path).unwrap();
+        for (start, _) in source.match_indices("r#\"{\"formatVersion\":") {
+            let json = &source[start + 3..];
+            let json = &json[..json.find("\"#").unwrap()];
+            // バージョンが違うなど わざと読めないようにしたものは除く
+            if from_json(json).is_ok() {
+                assert_valid(json);
+                fixture_count += 1;
+            }
+        }
+    }
+    assert!(fixture_count > 0);
+}
@@ -1,6 +1,12 @@
+pub mod collect;
 pub mod data;
+pub mod json;
+mod precedence;
+pub mod project;
+pub mod reflection;
 pub mod to_string;
 pub mod util;
+pub mod validation;
 
 #[test]
 fn test_serde() {
@@ -18,46 +24,7 @@ fn test_serde() {
 
 #[test]
 fn gen() {
-    let mut tracer = serde_reflection::Tracer::new(serde_reflection::TracerConfig::default());
-    let samples = serde_reflection::Samples::new();
-    let _ = tracer.trace_type::<data::Code>(&samples);
-    let _ = tracer.trace_type::<data::ExportDefinition>(&samples);
-    let _ = tracer.trace_type::<data::TypeAlias>(&samples);
-    let _ = tracer.trace_type::<data::Function>(&samples);
-    let _ = tracer.trace_type::<data::ParameterWithDocument>(&samples);
-    let _ = tracer.trace_type::<data::Parameter>(&samples);
-    let _ = tracer.trace_type::<data::Variable>(&samples);
-    let _ = tracer.trace_type::<data::UnaryOperator>(&samples);
-    let _ = tracer.trace_type::<data::BinaryOperator>(&samples);
-    let _ = tracer.trace_type::<data::Expr>(&samples);
-    let _ = tracer.trace_type::<data::Statement>(&samples);
-    let _ = tracer.trace_type::<data::Type>(&samples);
-    let _ = tracer.trace_type::<data::UnaryOperatorExpr>(&samples);
-    let _ = tracer.trace_type::<data::BinaryOperatorExpr>(&samples);
-    let _ = tracer.trace_type::<data::ConditionalOperatorExpr>(&samples);
-    let _ = tracer.trace_type::<data::ArrayItem>(&samples);
-    let _ = tracer.trace_type::<data::Member>(&samples);
-    let _ = tracer.trace_type::<data::KeyValue>(&samples);
-    let _ = tracer.trace_type::<data::LambdaExpr>(&samples);
-    let _ = tracer.trace_type::<data::ImportedVariable>(&samples);
-    let _ = tracer.trace_type::<data::GetExpr>(&samples);
-    let _ = tracer.trace_type::<data::CallExpr>(&samples);
-    let _ = tracer.trace_type::<data::TypeAssertion>(&samples);
-    let _ = tracer.trace_type::<data::SetStatement>(&samples);
-    let _ = tracer.trace_type::<data::IfStatement>(&samples);
-    let _ = tracer.trace_type::<data::VariableDefinitionStatement>(&samples);
-    let _ = tracer.trace_type::<data::FunctionDefinitionStatement>(&samples);
-    let _ = tracer.trace_type::<data::ForStatement>(&samples);
-    let _ = tracer.trace_type::<data::ForOfStatement>(&samples);
-    let _ = tracer.trace_type::<data::SwitchStatement>(&samples);
-    let _ = tracer.trace_type::<data::Pattern>(&samples);
-    let _ = tracer.trace_type::<data::MemberType>(&samples);
-    let _ = tracer.trace_type::<data::FunctionType>(&samples);
-    let _ = tracer.trace_type::<data::TypeWithTypeParameter>(&samples);
-    let _ = tracer.trace_type::<data::IntersectionType>(&samples);
-    let _ = tracer.trace_type::<data::ImportedType>(&samples);
-    let _ = tracer.trace_type::<data::identifer::Identifer>(&samples);
-    let registry = tracer.registry().unwrap();
+    let registry = reflection::registry().unwrap();
 
     // Create Python class definitions.
     let mut source = Vec::new();
@@ -66,15 +33,82 @@ fn gen() {
     let generator = serde_generate::typescript::CodeGenerator::new(&config);
     let _ = generator.output(&mut source, &registry);
     let _ = std::fs::write("out.ts", std::str::from_utf8(&source).unwrap());
-    ()
 }
 
+/// bincode でシリアライズした `data::Code` と `data::GenerateOption` を受け取り, コードを生成する.
+/// 戻り値は bincode でシリアライズした `data::GenerateResult`.
+/// TypeScript から呼ぶときの型とシリアライザは `out.ts` にある
+#[wasm_bindgen::prelude::wasm_bindgen]
+pub fn generate(code_as_bincode: &[u8], option_as_bincode: &[u8]) -> Vec<u8> {
+    bincode::serialize(&generate_from_bincode(code_as_bincode, option_as_bincode))
+        .expect("GenerateResult のシリアライズに失敗することはない")
+}
+
+fn generate_from_bincode(code_as_bincode: &[u8], option_as_bincode: &[u8]) -> data::GenerateResult {
+    let code = match bincode::deserialize::<data::Code>(code_as_bincode) {
+        Ok(code) => code,
+        Err(error) => {
+            return data::GenerateResult::Error(data::GenerateError::DecodeCode(error.to_string()))
+        }
+    };
+    let option = match bincode::deserialize::<data::GenerateOption>(option_as_bincode) {
+        Ok(option) => option,
+        Err(error) => {
+            return data::GenerateResult::Error(data::GenerateError::DecodeOption(
+                error.to_string(),
+            ))
+        }
+    };
+    let error_list = validation::validate_with_option(&code, &option);
+    if !error_list.is_empty() {
+        return data::GenerateResult::Error(data::GenerateError::Validation(error_list));
+    }
+    data::GenerateResult::Ok(to_string::to_string_with_option(&code, &option))
+}
+
+#[test]
+fn test_generate() {
+    let option = data::GenerateOption {
+        code_type: data::CodeType::TypeScript,
+        module_format: data::ModuleFormat::EsModule,
+        function_style: data::FunctionStyle::Arrow,
+        is_ascii_only: false,
+        common_js_default_export: data::CommonJsDefaultExport::ExportsDefault,
+    };
+    let result = bincode::deserialize::<data::GenerateResult>(&generate(
+        &bincode::serialize(&sample_code()).unwrap(),
+        &bincode::serialize(&option).unwrap(),
+    ))
+    .unwrap();
+    assert_eq!(
+        result,
+        data::GenerateResult::Ok(to_string::to_string(
+            &sample_code(),
+            &data::CodeType::TypeScript
+        ))
+    );
+
+    match generate_from_bincode(&[255, 1, 2], &bincode::serialize(&option).unwrap()) {
+        data::GenerateResult::Error(data::GenerateError::DecodeCode(_)) => {}
+        other => panic!("デコードエラーになるはず {:?}", other),
+    }
+    match generate_from_bincode(&bincode::serialize(&sample_code()).unwrap(), &[9]) {
+        data::GenerateResult::Error(data::GenerateError::DecodeOption(_)) => {}
+        other => panic!("デコードエラーになるはず {:?}", other),
+    }
+}
+
+/// 固定のサンプルのコードを `name` という名前の関数で生成する. 任意のコードを生成できないので `generate` を使う
+#[deprecated(note = "generate を使う")]
+// wasm-bindgen が生成するラッパーから呼ばれるため
+#[allow(deprecated)]
 #[wasm_bindgen::prelude::wasm_bindgen]
 pub fn code(name: &str) -> String {
     let sample = data::Code {
         export_definition_list: vec![data::ExportDefinition::Function(data::Function {
             name: data::identifer::from_string(name),
             document: String::from("ミドルウェア"),
+            document_tag_list: vec![],
             type_parameter_list: vec![],
             parameter_list: vec![
                 data::ParameterWithDocument {
@@ -84,6 +118,7 @@ pub fn code(name: &str) -> String {
                         module_name: String::from("express"),
                         name: data::identifer::from_string("Request"),
                     }),
+                    kind: data::ParameterKind::Required,
                 },
                 data::ParameterWithDocument {
                     name: data::identifer::from_string("response"),
@@ -92,12 +127,15 @@ pub fn code(name: &str) -> String {
                         module_name: String::from("express"),
                         name: data::identifer::from_string("Response"),
                     }),
+                    kind: data::ParameterKind::Required,
                 },
             ],
             return_type: data::Type::Void,
             statement_list: vec![
                 data::Statement::VariableDefinition(data::VariableDefinitionStatement {
-                    name: data::identifer::from_string("accept"),
+                    pattern: data::BindingPattern::Identifer(data::identifer::from_string(
+                        "accept",
+                    )),
                     r#type: data::Type::Union(Box::new(vec![
                         data::Type::String,
                         data::Type::Undefined,
@@ -133,6 +171,10 @@ pub fn code(name: &str) -> String {
                     ))],
                 }),
             ],
+            visibility: data::Visibility::Export,
+            is_async: false,
+            is_generator: false,
+            style: None,
         })],
         statement_list: vec![],
     };
@@ -161,6 +203,7 @@ pub fn sample_code() -> data::Code {
             data::ExportDefinition::Function(data::Function {
                 name: data::identifer::from_string("middleware"),
                 document: String::from("ミドルウェア"),
+                document_tag_list: vec![],
                 type_parameter_list: vec![],
                 parameter_list: vec![
                     data::ParameterWithDocument {
@@ -170,6 +213,7 @@ pub fn sample_code() -> data::Code {
                             module_name: String::from("express"),
                             name: data::identifer::from_string("Request"),
                         }),
+                        kind: data::ParameterKind::Required,
                     },
                     data::ParameterWithDocument {
                         name: data::identifer::from_string("response"),
@@ -178,12 +222,15 @@ pub fn sample_code() -> data::Code {
                             module_name: String::from("express"),
                             name: data::identifer::from_string("Response"),
                         }),
+                        kind: data::ParameterKind::Required,
                     },
                 ],
                 return_type: data::Type::Void,
                 statement_list: vec![
                     data::Statement::VariableDefinition(data::VariableDefinitionStatement {
-                        name: data::identifer::from_string("accept"),
+                        pattern: data::BindingPattern::Identifer(data::identifer::from_string(
+                            "accept",
+                        )),
                         r#type: data::Type::Union(Box::new(vec![
                             data::Type::String,
                             data::Type::Undefined,
@@ -221,28 +268,39 @@ pub fn sample_code() -> data::Code {
                         )],
                     }),
                 ],
+                visibility: data::Visibility::Export,
+                is_async: false,
+                is_generator: false,
+                style: None,
             }),
             data::ExportDefinition::Function(data::Function {
                 name: data::identifer::from_string("getZeroIndexElement"),
                 document: String::from("Uint8Arrayの0番目の要素を取得する"),
+                document_tag_list: vec![],
                 type_parameter_list: vec![],
                 parameter_list: vec![data::ParameterWithDocument {
                     name: data::identifer::from_string("array"),
                     document: String::from("Uint8Array"),
                     r#type: util::uint8array_type(),
+                    kind: data::ParameterKind::Required,
                 }],
                 return_type: data::Type::Number,
                 statement_list: vec![data::Statement::Return(data::Expr::Get(Box::new(
                     data::GetExpr {
                         expr: data::Expr::Variable(data::identifer::from_string("array")),
                         property_expr: data::Expr::NumberLiteral(0),
+                        is_optional_chain: false,
                     },
                 )))],
+                visibility: data::Visibility::Export,
+                is_async: false,
+                is_generator: false,
+                style: None,
             }),
         ],
         statement_list: vec![
             data::Statement::VariableDefinition(data::VariableDefinitionStatement {
-                name: data::identifer::from_string("sorena"),
+                pattern: data::BindingPattern::Identifer(data::identifer::from_string("sorena")),
                 is_const: false,
                 r#type: data::Type::String,
                 expr: data::Expr::StringLiteral(String::from("それな")),
@@ -264,6 +322,7 @@ fn test_snapshot() {
         r###"/* eslint-disable */
 /* generated by js-ts-code-generator. Do not edit! */
 
+import * as $$$express from "express";
 
 /**
  * ミドルウェア
@@ -283,7 +342,7 @@ export const middleware = (request: $$$express.Request, response: $$$express.Res
  * Uint8Arrayの0番目の要素を取得する
  * @param array Uint8Array
  */
-export const getZeroIndexElement = (array: Uint8Array): number => (array[0]);
+export const getZeroIndexElement = (array: Uint8Array): number => array[0];
 
 {
   let sorena: string = "それな";
@@ -314,7 +373,7 @@ export const middleware = (request, response) => {
  * Uint8Arrayの0番目の要素を取得する
  * @param array Uint8Array
  */
-export const getZeroIndexElement = (array) => (array[0]);
+export const getZeroIndexElement = (array) => array[0];
 
 {
   let sorena = "それな";
@@ -324,3 +383,74 @@ export const getZeroIndexElement = (array) => (array[0]);
     assert!(code_as_typescript.contains("void"));
     assert!(!code_as_javascript.contains("void"));
 }
+
+#[test]
+fn test_snapshot_declaration() {
+    let code_as_declaration = to_string::to_string(&sample_code(), &data::CodeType::Declaration);
+    println!("{}", code_as_declaration);
+    assert_eq!(
+        code_as_declaration,
+        r###"/* eslint-disable */
+/* generated by js-ts-code-generator. Do not edit! */
+
+import * as $$$express from "express";
+
+/**
+ * ミドルウェア
+ * @param request リクエスト
+ * @param response レスポンス
+ */
+export declare const middleware: (request: $$$express.Request, response: $$$express.Response) => void;
+
+
+
+/**
+ * Uint8Arrayの0番目の要素を取得する
+ * @param array Uint8Array
+ */
+export declare const getZeroIndexElement: (array: Uint8Array) => number;
+
+"###
+    );
+    assert!(!code_as_declaration.contains("sorena"));
+}
+
+#[test]
+fn test_snapshot_jsdoc() {
+    let code_as_jsdoc = to_string::to_string(&sample_code(), &data::CodeType::JavaScriptWithJsDoc);
+    println!("{}", code_as_jsdoc);
+    assert_eq!(
+        code_as_jsdoc,
+        r###"/* eslint-disable */
+/* generated by js-ts-code-generator. Do not edit! */
+
+// @ts-check
+
+/**
+ * ミドルウェア
+ * @param {import("express").Request} request リクエスト
+ * @param {import("express").Response} response レスポンス
+ * @returns {void}
+ */
+export const middleware = (request, response) => {
+  /** @type {string | undefined} */ const accept = request.headers.accept;
+  if (accept !== undefined && accept.includes("text/html")) {
+    response.setHeader("content-type", "text/html");
+  }
+};
+
+
+
+/**
+ * Uint8Arrayの0番目の要素を取得する
+ * @param {Uint8Array} array Uint8Array
+ * @returns {number}
+ */
+export const getZeroIndexElement = (array) => array[0];
+
+{
+  /** @type {string} */ let sorena = "それな";
+  console.log(sorena);
+}"###
+    );
+}
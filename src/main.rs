@@ -0,0 +1,377 @@
+use rust_js_ts_gen::{data, json, to_string, validation};
+use std::io::{Read, Write};
+
+const USAGE: &str = "usage: rust_js_ts_gen [OPTIONS] [INPUT]
+
+Render a data::Code as TypeScript or JavaScript.
+
+ARGS:
+    INPUT    JSON or bincode file of data::Code. Reads stdin when omitted or `-`
+
+OPTIONS:
+    --input-format <json|bincode>          Default: bincode for .bin/.bincode files, otherwise json
+    --code-type <typescript|javascript|jsdoc|declaration>
+                                           Default: javascript for .js output, declaration for
+                                           .d.ts output, otherwise typescript
+    --module-format <esm|commonjs|umd>     Module format of JavaScript output. Default: esm
+    --global-name <NAME>                   Global variable that umd assigns the exports to
+    --function-style <arrow|declaration>   How functions without their own style are written.
+                                           Default: arrow
+    --ascii-only                           Escape non-ASCII characters in strings and property names
+    --commonjs-default-export <exports-default|module-exports>
+                                           Where commonjs assigns the default export.
+                                           Default: exports-default
+    -o, --output <FILE>                    Write to FILE instead of stdout
+    --check                                Do not write. Fail when FILE differs from the rendered code
+    -h, --help                             Print this message
+
+EXIT STATUS:
+    0 success, 1 --check found a difference, 2 usage error,
+    3 input could not be decoded, 4 validation error, 5 I/O error";
+
+/// 終了コード
+#[derive(Debug, PartialEq, Eq)]
+enum Exit {
+    CheckDifference = 1,
+    Usage = 2,
+    Decode = 3,
+    Validation = 4,
+    Io = 5,
+}
+
+#[derive(PartialEq, Eq)]
+enum InputFormat {
+    Json,
+    Bincode,
+}
+
+struct Arguments {
+    input: Option<String>,
+    input_format: Option<InputFormat>,
+    code_type: Option<data::CodeType>,
+    module_format: Option<String>,
+    global_name: Option<String>,
+    function_style: data::FunctionStyle,
+    is_ascii_only: bool,
+    common_js_default_export: data::CommonJsDefaultExport,
+    output: Option<String>,
+    check: bool,
+}
+
+fn main() {
+    match run(std::env::args().skip(1).collect()) {
+        Ok(()) => {}
+        Err((exit, message)) => {
+            eprintln!("{}", message);
+            std::process::exit(exit as i32);
+        }
+    }
+}
+
+fn run(argument_list: Vec<String>) -> Result<(), (Exit, String)> {
+    let arguments = parse_arguments(argument_list)?;
+    let module_format = match (arguments.module_format.as_deref(), &arguments.global_name) {
+        (None, None) | (Some("esm"), None) => data::ModuleFormat::EsModule,
+        (Some("commonjs"), None) => data::ModuleFormat::CommonJs,
+        (Some("umd"), Some(global_name)) => data::ModuleFormat::Umd(global_name.clone()),
+        (Some("umd"), None) => {
+            return Err(usage_error("--module-format umd requires --global-name"))
+        }
+        (_, Some(_)) => return Err(usage_error("--global-name requires --module-format umd")),
+        (Some(other), None) => {
+            return Err(usage_error(&format!("unknown module format {}", other)))
+        }
+    };
+
+    let input_format = match arguments.input_format {
+        Some(input_format) => input_format,
+        None => match &arguments.input {
+            Some(path) if path.ends_with(".bin") || path.ends_with(".bincode") => {
+                InputFormat::Bincode
+            }
+            _ => InputFormat::Json,
+        },
+    };
+    let input = read_input(&arguments.input)
+        .map_err(|error| (Exit::Io, format!("failed to read input: {}", error)))?;
+    let code = match input_format {
+        InputFormat::Json => std::str::from_utf8(&input)
+            .map_err(|error| error.to_string())
+            .and_then(|input| json::from_json(input).map_err(|error| error.to_string())),
+        InputFormat::Bincode => {
+            bincode::deserialize::<data::Code>(&input).map_err(|error| error.to_string())
+        }
+    }
+    .map_err(|error| (Exit::Decode, format!("failed to decode input: {}", error)))?;
+
+    let code_type = match arguments.code_type {
+        Some(code_type) => code_type,
+        None => match &arguments.output {
+            Some(path) if path.ends_with(".js") => data::CodeType::JavaScript,
+            Some(path) if path.ends_with(".d.ts") => data::CodeType::Declaration,
+            _ => data::CodeType::TypeScript,
+        },
+    };
+    let option = data::GenerateOption {
+        code_type,
+        module_format,
+        function_style: arguments.function_style,
+        is_ascii_only: arguments.is_ascii_only,
+        common_js_default_export: arguments.common_js_default_export,
+    };
+
+    let error_list = validation::validate_with_option(&code, &option);
+    if !error_list.is_empty() {
+        return Err((
+            Exit::Validation,
+            error_list
+                .iter()
+                .map(|error| format!("error: {}", error))
+                .collect::<Vec<String>>()
+                .join("\n"),
+        ));
+    }
+
+    let code_as_string = to_string::to_string_with_option(&code, &option);
+
+    match (&arguments.output, arguments.check) {
+        (Some(path), true) => match std::fs::read(path) {
+            Ok(existing) if existing == code_as_string.as_bytes() => Ok(()),
+            Ok(_) => Err((Exit::CheckDifference, format!("{} is not up to date", path))),
+            // まだ生成されていないファイルも 最新ではないものとして扱う
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+                Err((Exit::CheckDifference, format!("{} does not exist", path)))
+            }
+            Err(error) => Err((Exit::Io, format!("failed to read {}: {}", path, error))),
+        },
+        (Some(path), false) => std::fs::write(path, code_as_string)
+            .map_err(|error| (Exit::Io, format!("failed to write {}: {}", path, error))),
+        (None, _) => std::io::stdout()
+            .write_all(code_as_string.as_bytes())
+            .map_err(|error| (Exit::Io, format!("failed to write stdout: {}", error))),
+    }
+}
+
+fn parse_arguments(argument_list: Vec<String>) -> Result<Arguments, (Exit, String)> {
+    let mut arguments = Arguments {
+        input: None,
+        input_format: None,
+        code_type: None,
+        module_format: None,
+        global_name: None,
+        function_style: data::FunctionStyle::Arrow,
+        is_ascii_only: false,
+        common_js_default_export: data::CommonJsDefaultExport::ExportsDefault,
+        output: None,
+        check: false,
+    };
+    let mut iter = argument_list.into_iter();
+    while let Some(argument) = iter.next() {
+        match argument.as_str() {
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                std::process::exit(0);
+            }
+            "--input-format" => {
+                arguments.input_format = Some(match option_value(&mut iter, &argument)?.as_str() {
+                    "json" => InputFormat::Json,
+                    "bincode" => InputFormat::Bincode,
+                    other => return Err(usage_error(&format!("unknown input format {}", other))),
+                })
+            }
+            "--code-type" => {
+                arguments.code_type = Some(match option_value(&mut iter, &argument)?.as_str() {
+                    "typescript" => data::CodeType::TypeScript,
+                    "javascript" => data::CodeType::JavaScript,
+                    "declaration" => data::CodeType::Declaration,
+                    "jsdoc" => data::CodeType::JavaScriptWithJsDoc,
+                    other => return Err(usage_error(&format!("unknown code type {}", other))),
+                })
+            }
+            "--module-format" => {
+                arguments.module_format = Some(option_value(&mut iter, &argument)?)
+            }
+            "--global-name" => arguments.global_name = Some(option_value(&mut iter, &argument)?),
+            "--function-style" => {
+                arguments.function_style = match option_value(&mut iter, &argument)?.as_str() {
+                    "arrow" => data::FunctionStyle::Arrow,
+                    "declaration" => data::FunctionStyle::Declaration,
+                    other => return Err(usage_error(&format!("unknown function style {}", other))),
+                }
+            }
+            "--ascii-only" => arguments.is_ascii_only = true,
+            "--commonjs-default-export" => {
+                arguments.common_js_default_export =
+                    match option_value(&mut iter, &argument)?.as_str() {
+                        "exports-default" => data::CommonJsDefaultExport::ExportsDefault,
+                        "module-exports" => data::CommonJsDefaultExport::ModuleExports,
+                        other => {
+                            return Err(usage_error(&format!(
+                                "unknown commonjs default export {}",
+                                other
+                            )))
+                        }
+                    }
+            }
+            "-o" | "--output" => arguments.output = Some(option_value(&mut iter, &argument)?),
+            "--check" => arguments.check = true,
+            "-" => arguments.input = None,
+            _ if argument.starts_with('-') => {
+                return Err(usage_error(&format!("unknown option {}", argument)))
+            }
+            _ if arguments.input.is_some() => {
+                return Err(usage_error("only one INPUT can be given"))
+            }
+            _ => arguments.input = Some(argument),
+        }
+    }
+    if arguments.check && arguments.output.is_none() {
+        return Err(usage_error("--check requires --output"));
+    }
+    Ok(arguments)
+}
+
+fn option_value(
+    iter: &mut impl Iterator<Item = String>,
+    option_name: &str,
+) -> Result<String, (Exit, String)> {
+    iter.next()
+        .ok_or_else(|| usage_error(&format!("{} requires a value", option_name)))
+}
+
+fn usage_error(message: &str) -> (Exit, String) {
+    (Exit::Usage, format!("error: {}\n\n{}", message, USAGE))
+}
+
+fn read_input(path: &Option<String>) -> std::io::Result<Vec<u8>> {
+    match path {
+        Some(path) => std::fs::read(path),
+        None => {
+            let mut buffer = Vec::new();
+            std::io::stdin().read_to_end(&mut buffer)?;
+            Ok(buffer)
+        }
+    }
+}
+
+#[cfg(test)]
+fn argument_list(argument_list: &[&str]) -> Vec<String> {
+    argument_list
+        .iter()
+        .map(|argument| argument.to_string())
+        .collect()
+}
+
+#[test]
+fn test_parse_arguments() {
+    let arguments = parse_arguments(argument_list(&[
+        "code.bin",
+        "--code-type",
+        "jsdoc",
+        "--module-format",
+        "umd",
+        "--global-name",
+        "lib",
+        "--function-style",
+        "declaration",
+        "--ascii-only",
+        "--commonjs-default-export",
+        "module-exports",
+        "-o",
+        "out.js",
+        "--check",
+    ]))
+    .ok()
+    .unwrap();
+    assert_eq!(arguments.input.as_deref(), Some("code.bin"));
+    assert!(arguments.input_format.is_none());
+    assert_eq!(
+        arguments.code_type,
+        Some(data::CodeType::JavaScriptWithJsDoc)
+    );
+    assert_eq!(arguments.module_format.as_deref(), Some("umd"));
+    assert_eq!(arguments.global_name.as_deref(), Some("lib"));
+    assert_eq!(arguments.function_style, data::FunctionStyle::Declaration);
+    assert!(arguments.is_ascii_only);
+    assert_eq!(
+        arguments.common_js_default_export,
+        data::CommonJsDefaultExport::ModuleExports
+    );
+    assert_eq!(arguments.output.as_deref(), Some("out.js"));
+    assert!(arguments.check);
+
+    for invalid in [
+        &["--unknown"][..],
+        &["a.json", "b.json"],
+        &["--code-type"],
+        &["--code-type", "python"],
+        &["--check"],
+    ] {
+        match parse_arguments(argument_list(invalid)) {
+            Err((Exit::Usage, _)) => {}
+            _ => panic!("引数のエラーになるはず {:?}", invalid),
+        }
+    }
+}
+
+#[test]
+fn test_exit_code() {
+    let directory =
+        std::env::temp_dir().join(format!("rust_js_ts_gen_main_{}", std::process::id()));
+    std::fs::create_dir_all(&directory).unwrap();
+    let path = |name: &str| directory.join(name).to_string_lossy().to_string();
+    let exit_code = |argument_list_slice: &[&str]| match run(argument_list(argument_list_slice)) {
+        Ok(()) => 0,
+        Err((exit, _)) => exit as i32,
+    };
+    std::fs::write(
+        path("code.json"),
+        json::to_json(&rust_js_ts_gen::sample_code()),
+    )
+    .unwrap();
+    std::fs::write(path("invalid.json"), "{").unwrap();
+    std::fs::write(
+        path("class.json"),
+        r#"{"formatVersion":4,"code":{"exportDefinitionList":[],"statementList":[{"EvaluateExpr":{"Variable":"class"}}]}}"#,
+    )
+    .unwrap();
+    let _ = std::fs::remove_file(path("missing.ts"));
+
+    assert_eq!(exit_code(&[&path("code.json"), "-o", &path("out.ts")]), 0);
+    assert_eq!(
+        exit_code(&[&path("code.json"), "-o", &path("out.ts"), "--check"]),
+        0
+    );
+    assert_eq!(
+        exit_code(&[
+            &path("code.json"),
+            "-o",
+            &path("out.ts"),
+            "--check",
+            "--code-type",
+            "javascript"
+        ]),
+        Exit::CheckDifference as i32
+    );
+    assert_eq!(
+        exit_code(&[&path("code.json"), "-o", &path("missing.ts"), "--check"]),
+        Exit::CheckDifference as i32
+    );
+    assert_eq!(
+        exit_code(&[&path("code.json"), "--module-format", "umd"]),
+        Exit::Usage as i32
+    );
+    assert_eq!(
+        exit_code(&[&path("invalid.json"), "-o", &path("out.ts")]),
+        Exit::Decode as i32
+    );
+    assert_eq!(
+        exit_code(&[&path("class.json"), "-o", &path("out.ts")]),
+        Exit::Validation as i32
+    );
+    assert_eq!(
+        exit_code(&[&path("missing.json"), "-o", &path("out.ts")]),
+        Exit::Io as i32
+    );
+    let _ = std::fs::remove_dir_all(&directory);
+}
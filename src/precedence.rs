@@ -0,0 +1,1426 @@
+//! 式を文字列にするときに 括弧で囲む必要があるかどうかを決める
+//!
+//! 式の優先順位と, 式が置かれる場所が要求する優先順位の表から判断する.
+//! 優先順位だけでは表現できない規則 (`(-a) ** b` や `(a && b) ?? c` など) も ここで扱う
+//!
+//! https://developer.mozilla.org/ja/docs/Web/JavaScript/Reference/Operators/Operator_Precedence#Table
+
+use crate::data;
+
+/// 式が置かれる場所
+#[derive(Clone, Copy)]
+pub(crate) enum Position<'a> {
+    /// 文として評価される式 `a;`
+    Statement,
+    /// 関数の引数や配列の要素, 変数の初期値など 代入式が置かれる場所. カンマ演算子は括弧で囲む
+    Assignment,
+    /// ラムダ式の本体 `() => a`
+    LambdaBody,
+    /// 2項演算子の左辺
+    BinaryLeft(&'a data::BinaryOperator),
+    /// 2項演算子の右辺
+    BinaryRight(&'a data::BinaryOperator),
+    /// 単項演算子の対象
+    UnaryOperand(&'a data::UnaryOperator),
+    /// `await a` の `a`
+    AwaitOperand,
+    /// 条件演算子 `a ? b : c` の `a`
+    ConditionalCondition,
+    /// 条件演算子 `a ? b : c` の `b` と `c`
+    ConditionalBranch,
+    /// プロパティアクセスや関数呼び出しの対象 `a.b`, `a()` の `a`
+    Member,
+    /// `new a()` の `a`
+    NewTarget,
+    /// `export default a;` の `a`
+    DefaultExport,
+    /// 型アサーション `a as T` の `a`
+    TypeAssertion,
+}
+
+/// 結合の向き
+#[derive(Eq, PartialEq)]
+pub enum Associativity {
+    LeftToRight,
+    RightToLeft,
+}
+
+const COMMA: u8 = 1;
+const ASSIGNMENT: u8 = 3;
+const CONDITIONAL: u8 = 4;
+const RELATIONAL: u8 = 12;
+const UNARY: u8 = 17;
+const POSTFIX: u8 = 18;
+const MEMBER: u8 = 20;
+const PRIMARY: u8 = 21;
+
+/// 式を括弧で囲む必要があるかどうか
+pub(crate) fn needs_parentheses(
+    position: Position,
+    expr: &data::Expr,
+    code_type: &data::CodeType,
+) -> bool {
+    expr_precedence(expr, code_type) < minimum_precedence(position)
+        || needs_parentheses_by_rule(position, expr, code_type)
+}
+
+/// 式の優先順位
+fn expr_precedence(expr: &data::Expr, code_type: &data::CodeType) -> u8 {
+    match expr {
+        data::Expr::NumberLiteral(number) if *number < 0 => UNARY,
+        data::Expr::NumberLiteral(_)
+        | data::Expr::StringLiteral(_)
+        | data::Expr::RegExpLiteral(_)
+        | data::Expr::BooleanLiteral(_)
+        | data::Expr::NullLiteral
+        | data::Expr::UndefinedLiteral
+        | data::Expr::ArrayLiteral(_)
+        | data::Expr::ObjectLiteral(_)
+        | data::Expr::Variable(_)
+        | data::Expr::GlobalObjects(_)
+        | data::Expr::ImportedVariable(_)
+        | data::Expr::ProjectVariable(_)
+        | data::Expr::Class(_)
+        | data::Expr::This => PRIMARY,
+        // `function* () {}` は関数式
+        data::Expr::Lambda(lambda_expr) if lambda_expr.is_generator => PRIMARY,
+        data::Expr::Lambda(_) | data::Expr::Yield(_) => ASSIGNMENT,
+        data::Expr::Get(_) | data::Expr::Call(_) | data::Expr::New(_) => MEMBER,
+        data::Expr::UnaryOperator(unary_operator_expr) => {
+            if is_postfix(&unary_operator_expr.operator) {
+                POSTFIX
+            } else {
+                UNARY
+            }
+        }
+        data::Expr::Await(_) => UNARY,
+        data::Expr::BinaryOperator(binary_operator_expr) => {
+            binary_operator_precedence(&binary_operator_expr.operator)
+        }
+        data::Expr::ConditionalOperator(_) => CONDITIONAL,
+        data::Expr::TypeAssertion(type_assertion) => match code_type {
+            // JavaScript では型アサーションを出力しない
+            data::CodeType::JavaScript => expr_precedence(&type_assertion.expr, code_type),
+            // `/** @type {T} */ (a)`
+            data::CodeType::JavaScriptWithJsDoc => PRIMARY,
+            data::CodeType::TypeScript | data::CodeType::Declaration => RELATIONAL,
+        },
+    }
+}
+
+/// 式が置かれる場所が要求する優先順位. これより低い優先順位の式は括弧で囲む
+fn minimum_precedence(position: Position) -> u8 {
+    match position {
+        Position::Statement => COMMA,
+        Position::Assignment
+        | Position::LambdaBody
+        | Position::ConditionalBranch
+        | Position::DefaultExport => ASSIGNMENT,
+        Position::BinaryLeft(binary_operator) => {
+            binary_operator_precedence(binary_operator)
+                + match binary_operator_associativity(binary_operator) {
+                    Associativity::LeftToRight => 0,
+                    Associativity::RightToLeft => 1,
+                }
+        }
+        Position::BinaryRight(binary_operator) => {
+            binary_operator_precedence(binary_operator)
+                + match binary_operator_associativity(binary_operator) {
+                    Associativity::LeftToRight => 1,
+                    Associativity::RightToLeft => 0,
+                }
+        }
+        Position::UnaryOperand(unary_operator) => {
+            if is_postfix(unary_operator) {
+                MEMBER
+            } else {
+                UNARY
+            }
+        }
+        Position::AwaitOperand => UNARY,
+        Position::ConditionalCondition => CONDITIONAL + 1,
+        Position::Member | Position::NewTarget => MEMBER,
+        Position::TypeAssertion => RELATIONAL,
+    }
+}
+
+/// 優先順位だけでは決まらない規則
+fn needs_parentheses_by_rule(
+    position: Position,
+    expr: &data::Expr,
+    code_type: &data::CodeType,
+) -> bool {
+    let expr = output_expr(expr, code_type);
+    match position {
+        // `{` で始まると ブロックとして解釈されてしまう
+        Position::Statement => starts_with(expr, code_type, &|leftmost| {
+            matches!(leftmost, data::Expr::ObjectLiteral(_))
+                || is_declaration_keyword_expr(leftmost)
+        }),
+        // `export default class {}.a` は クラス宣言の後に `.a` が続くことになり SyntaxError
+        Position::DefaultExport => {
+            !is_declaration_keyword_expr(expr)
+                && starts_with(expr, code_type, &is_declaration_keyword_expr)
+        }
+        Position::LambdaBody => starts_with(expr, code_type, &|leftmost| {
+            matches!(leftmost, data::Expr::ObjectLiteral(_))
+        }),
+        // `-a ** b` は SyntaxError
+        Position::BinaryLeft(data::BinaryOperator::Exponentiation) => {
+            is_unary_expr(expr) || is_mixed_nullish_coalescing(position, expr)
+        }
+        // `a && b ?? c` は SyntaxError. `a as T < b` は型引数として解釈されてしまう
+        Position::BinaryLeft(_) => {
+            is_mixed_nullish_coalescing(position, expr) || is_type_script_assertion(expr, code_type)
+        }
+        Position::BinaryRight(_) => is_mixed_nullish_coalescing(position, expr),
+        // `- -a` が `--a` にならないようにする
+        Position::UnaryOperand(data::UnaryOperator::Minus) => match expr {
+            data::Expr::UnaryOperator(unary_operator_expr) => matches!(
+                unary_operator_expr.operator,
+                data::UnaryOperator::Minus | data::UnaryOperator::PrefixDecrement
+            ),
+            data::Expr::NumberLiteral(number) => *number < 0,
+            _ => false,
+        },
+        Position::UnaryOperand(data::UnaryOperator::Plus) => match expr {
+            data::Expr::UnaryOperator(unary_operator_expr) => matches!(
+                unary_operator_expr.operator,
+                data::UnaryOperator::Plus | data::UnaryOperator::PrefixIncrement
+            ),
+            _ => false,
+        },
+        Position::UnaryOperand(_) | Position::AwaitOperand => false,
+        Position::ConditionalCondition => is_type_script_assertion(expr, code_type),
+        // TypeScript では `a ? (b): T => c : d` の型注釈が条件演算子の `:` と紛らわしい
+        Position::ConditionalBranch => {
+            matches!(expr, data::Expr::Lambda(_))
+                && (*code_type == data::CodeType::TypeScript
+                    || *code_type == data::CodeType::Declaration)
+        }
+        // `1.toString()` は SyntaxError
+        Position::Member => matches!(expr, data::Expr::NumberLiteral(_)),
+        // `new a().b()` や `new a?.b()` は 意味が変わるか SyntaxError になる
+        Position::NewTarget => {
+            matches!(expr, data::Expr::NumberLiteral(_))
+                || contains_call_or_optional_chain(expr, code_type)
+        }
+        Position::Assignment | Position::TypeAssertion => false,
+    }
+}
+
+/// 括弧で囲まれずに `in` 演算子が現れるかどうか. for文の初期化の部分では forIn文の `in` と区別できない
+pub(crate) fn contains_in_operator(expr: &data::Expr) -> bool {
+    match expr {
+        data::Expr::BinaryOperator(binary_operator_expr) => {
+            binary_operator_expr.operator == data::BinaryOperator::In
+                || contains_in_operator(&binary_operator_expr.left)
+                || contains_in_operator(&binary_operator_expr.right)
+        }
+        data::Expr::UnaryOperator(unary_operator_expr) => {
+            contains_in_operator(&unary_operator_expr.expr)
+        }
+        data::Expr::ConditionalOperator(conditional_operator_expr) => {
+            contains_in_operator(&conditional_operator_expr.condition)
+                || contains_in_operator(&conditional_operator_expr.then_expr)
+                || contains_in_operator(&conditional_operator_expr.else_expr)
+        }
+        data::Expr::Lambda(lambda_expr) => match lambda_expr.statement_list.as_slice() {
+            [data::Statement::Return(expr)] => contains_in_operator(expr),
+            _ => false,
+        },
+        data::Expr::Get(get_expr) => contains_in_operator(&get_expr.expr),
+        data::Expr::Call(call_expr) | data::Expr::New(call_expr) => {
+            contains_in_operator(&call_expr.expr)
+        }
+        data::Expr::TypeAssertion(type_assertion) => contains_in_operator(&type_assertion.expr),
+        data::Expr::Yield(yield_expr) => yield_expr.expr.as_ref().is_some_and(contains_in_operator),
+        data::Expr::Await(expr) => contains_in_operator(expr),
+        _ => false,
+    }
+}
+
+/// 2項演算子の優先順位
+pub(crate) fn binary_operator_precedence(binary_operator: &data::BinaryOperator) -> u8 {
+    match binary_operator {
+        data::BinaryOperator::Exponentiation => 16,
+        data::BinaryOperator::Multiplication
+        | data::BinaryOperator::Division
+        | data::BinaryOperator::Remainder => 15,
+        data::BinaryOperator::Addition | data::BinaryOperator::Subtraction => 14,
+        data::BinaryOperator::LeftShift
+        | data::BinaryOperator::SignedRightShift
+        | data::BinaryOperator::UnsignedRightShift => 13,
+        data::BinaryOperator::LessThan
+        | data::BinaryOperator::LessThanOrEqual
+        | data::BinaryOperator::GreaterThan
+        | data::BinaryOperator::GreaterThanOrEqual
+        | data::BinaryOperator::In
+        | data::BinaryOperator::InstanceOf => RELATIONAL,
+        data::BinaryOperator::Equal
+        | data::BinaryOperator::NotEqual
+        | data::BinaryOperator::LooseEqual
+        | data::BinaryOperator::LooseNotEqual => 11,
+        data::BinaryOperator::BitwiseAnd => 10,
+        data::BinaryOperator::BitwiseXOr => 9,
+        data::BinaryOperator::BitwiseOr => 8,
+        data::BinaryOperator::LogicalAnd => 6,
+        data::BinaryOperator::LogicalOr | data::BinaryOperator::NullishCoalescing => 5,
+        data::BinaryOperator::Comma => COMMA,
+    }
+}
+
+/// 2項演算子の結合の向き
+pub fn binary_operator_associativity(binary_operator: &data::BinaryOperator) -> Associativity {
+    match binary_operator {
+        data::BinaryOperator::Exponentiation => Associativity::RightToLeft,
+        data::BinaryOperator::Multiplication
+        | data::BinaryOperator::Division
+        | data::BinaryOperator::Remainder
+        | data::BinaryOperator::Addition
+        | data::BinaryOperator::Subtraction
+        | data::BinaryOperator::LeftShift
+        | data::BinaryOperator::SignedRightShift
+        | data::BinaryOperator::UnsignedRightShift
+        | data::BinaryOperator::LessThan
+        | data::BinaryOperator::LessThanOrEqual
+        | data::BinaryOperator::GreaterThan
+        | data::BinaryOperator::GreaterThanOrEqual
+        | data::BinaryOperator::In
+        | data::BinaryOperator::InstanceOf
+        | data::BinaryOperator::Equal
+        | data::BinaryOperator::NotEqual
+        | data::BinaryOperator::LooseEqual
+        | data::BinaryOperator::LooseNotEqual
+        | data::BinaryOperator::BitwiseAnd
+        | data::BinaryOperator::BitwiseXOr
+        | data::BinaryOperator::BitwiseOr
+        | data::BinaryOperator::LogicalAnd
+        | data::BinaryOperator::LogicalOr
+        | data::BinaryOperator::NullishCoalescing
+        | data::BinaryOperator::Comma => Associativity::LeftToRight,
+    }
+}
+
+fn is_postfix(unary_operator: &data::UnaryOperator) -> bool {
+    matches!(
+        unary_operator,
+        data::UnaryOperator::PostfixIncrement | data::UnaryOperator::PostfixDecrement
+    )
+}
+
+/// `**` の左辺に置くと SyntaxError になる単項演算子の式かどうか. インクリメントとデクリメントは置ける
+fn is_unary_expr(expr: &data::Expr) -> bool {
+    match expr {
+        data::Expr::Await(_) => true,
+        data::Expr::UnaryOperator(unary_operator_expr) => !matches!(
+            unary_operator_expr.operator,
+            data::UnaryOperator::PrefixIncrement
+                | data::UnaryOperator::PrefixDecrement
+                | data::UnaryOperator::PostfixIncrement
+                | data::UnaryOperator::PostfixDecrement
+        ),
+        data::Expr::NumberLiteral(number) => *number < 0,
+        _ => false,
+    }
+}
+
+/// `??` と `&&`, `||` を括弧なしで組み合わせているかどうか
+///
+/// ```ts
+/// (a && b) ?? c
+/// a || (b ?? c)
+/// ```
+fn is_mixed_nullish_coalescing(position: Position, expr: &data::Expr) -> bool {
+    let operator = match position {
+        Position::BinaryLeft(operator) | Position::BinaryRight(operator) => operator,
+        _ => return false,
+    };
+    let is_logical = |operator: &data::BinaryOperator| {
+        *operator == data::BinaryOperator::LogicalAnd
+            || *operator == data::BinaryOperator::LogicalOr
+    };
+    match expr {
+        data::Expr::BinaryOperator(operand) => {
+            (*operator == data::BinaryOperator::NullishCoalescing && is_logical(&operand.operator))
+                || (is_logical(operator)
+                    && operand.operator == data::BinaryOperator::NullishCoalescing)
+        }
+        _ => false,
+    }
+}
+
+fn is_type_script_assertion(expr: &data::Expr, code_type: &data::CodeType) -> bool {
+    matches!(expr, data::Expr::TypeAssertion(_))
+        && (*code_type == data::CodeType::TypeScript || *code_type == data::CodeType::Declaration)
+}
+
+/// `new` の対象として括弧なしで置くと 関数呼び出しの結果に対して new をしてしまうか SyntaxError になるか
+fn contains_call_or_optional_chain(expr: &data::Expr, code_type: &data::CodeType) -> bool {
+    match expr {
+        data::Expr::Call(_) => true,
+        data::Expr::Get(get_expr) => {
+            get_expr.is_optional_chain
+                || contains_call_or_optional_chain(
+                    output_expr(&get_expr.expr, code_type),
+                    code_type,
+                )
+        }
+        _ => false,
+    }
+}
+
+/// 実際に出力される式. JavaScript では型アサーションを出力しないので 中の式の規則で括弧を決める
+fn output_expr<'a>(expr: &'a data::Expr, code_type: &data::CodeType) -> &'a data::Expr {
+    match (expr, code_type) {
+        (data::Expr::TypeAssertion(type_assertion), data::CodeType::JavaScript) => {
+            output_expr(&type_assertion.expr, code_type)
+        }
+        _ => expr,
+    }
+}
+
+/// 括弧で囲まれずに出力したとき `is_leftmost` を満たす式から始まるかどうか
+fn starts_with(
+    expr: &data::Expr,
+    code_type: &data::CodeType,
+    is_leftmost: &dyn Fn(&data::Expr) -> bool,
+) -> bool {
+    if is_leftmost(expr) {
+        return true;
+    }
+    let starts_with_left = |position: Position, left: &data::Expr| {
+        !needs_parentheses(position, left, code_type) && starts_with(left, code_type, is_leftmost)
+    };
+    match expr {
+        data::Expr::BinaryOperator(binary_operator_expr) => starts_with_left(
+            Position::BinaryLeft(&binary_operator_expr.operator),
+            &binary_operator_expr.left,
+        ),
+        data::Expr::ConditionalOperator(conditional_operator_expr) => starts_with_left(
+            Position::ConditionalCondition,
+            &conditional_operator_expr.condition,
+        ),
+        data::Expr::UnaryOperator(unary_operator_expr)
+            if is_postfix(&unary_operator_expr.operator) =>
+        {
+            starts_with_left(
+                Position::UnaryOperand(&unary_operator_expr.operator),
+                &unary_operator_expr.expr,
+            )
+        }
+        data::Expr::Get(get_expr) => starts_with_left(Position::Member, &get_expr.expr),
+        data::Expr::Call(call_expr) => starts_with_left(Position::Member, &call_expr.expr),
+        data::Expr::TypeAssertion(type_assertion) => match code_type {
+            data::CodeType::JavaScript => starts_with(&type_assertion.expr, code_type, is_leftmost),
+            data::CodeType::JavaScriptWithJsDoc => false,
+            data::CodeType::TypeScript | data::CodeType::Declaration => {
+                starts_with_left(Position::TypeAssertion, &type_assertion.expr)
+            }
+        },
+        _ => false,
+    }
+}
+
+/// `function` か `class` キーワードで始まる式. 文の先頭に置くと宣言として解釈されてしまう
+fn is_declaration_keyword_expr(expr: &data::Expr) -> bool {
+    matches!(expr, data::Expr::Lambda(lambda_expr) if lambda_expr.is_generator)
+        || matches!(expr, data::Expr::Class(_))
+}
+
+/// 出力した式を読み直して 元の式と同じになるかを確かめるための, テスト用の小さな JavaScript の式のパーサー.
+/// TypeScript の型は `as number` と 関数の戻り値の `: number` だけを読む.
+/// 優先順位の表は上のものを使わず, 仕様から独立に書いている
+#[cfg(test)]
+mod reparse {
+    use crate::data;
+    use crate::util;
+
+    #[derive(PartialEq, Debug)]
+    enum Token {
+        Number(i32),
+        String(String),
+        Word(String),
+        Punctuator(&'static str),
+        /// 正規表現リテラルのパターンとフラグ
+        RegExp(String, String),
+    }
+
+    /// 直後の `/` を 割り算ではなく正規表現リテラルの始まりとして読むキーワード
+    const KEYWORD_BEFORE_EXPR_LIST: [&str; 10] = [
+        "typeof",
+        "void",
+        "delete",
+        "in",
+        "instanceof",
+        "yield",
+        "new",
+        "return",
+        "await",
+        "extends",
+    ];
+
+    /// 読んだトークンの直後の `/` が正規表現リテラルの始まりになるかどうか
+    fn is_regexp_allowed(token_list: &[Token]) -> bool {
+        match token_list.split_last() {
+            None => true,
+            Some((Token::Number(_), _))
+            | Some((Token::String(_), _))
+            | Some((Token::RegExp(_, _), _)) => false,
+            Some((Token::Word(word), _)) => KEYWORD_BEFORE_EXPR_LIST.contains(&word.as_str()),
+            // 前置の `++` の後は式の始まり
+            Some((Token::Punctuator("++"), rest)) | Some((Token::Punctuator("--"), rest)) => {
+                is_regexp_allowed(rest)
+            }
+            Some((Token::Punctuator(punctuator), _)) => !matches!(*punctuator, ")" | "]" | "}"),
+        }
+    }
+
+    const PUNCTUATOR_LIST: [&str; 42] = [
+        ">>>", "===", "!==", "...", "**", "<<", ">>", "<=", ">=", "==", "!=", "&&", "||", "??",
+        "?.", "++", "--", "=>", "+", "-", "*", "/", "%", "<", ">", "&", "|", "^", "!", "~", "?",
+        ":", ",", ".", "(", ")", "[", "]", "{", "}", ";", "=",
+    ];
+
+    fn tokenize(code: &str) -> Result<Vec<Token>, String> {
+        let char_list: Vec<char> = code.chars().collect();
+        let mut token_list = Vec::new();
+        let mut index = 0;
+        while index < char_list.len() {
+            let char = char_list[index];
+            if char.is_whitespace() {
+                index += 1;
+            } else if char.is_ascii_digit() {
+                let start = index;
+                while index < char_list.len() && char_list[index].is_ascii_digit() {
+                    index += 1;
+                }
+                // `1.a` の `1.` は数値として読まれる
+                if index < char_list.len() && char_list[index] == '.' {
+                    return Err(String::from("number followed by ."));
+                }
+                let number: String = char_list[start..index].iter().collect();
+                token_list.push(Token::Number(number.parse().map_err(|_| number.clone())?));
+            } else if char == '/' && is_regexp_allowed(&token_list) {
+                let mut pattern = String::new();
+                let mut is_in_class = false;
+                index += 1;
+                while char_list[index] != '/' || is_in_class {
+                    match char_list[index] {
+                        '\\' => {
+                            pattern.push('\\');
+                            index += 1;
+                        }
+                        '[' => is_in_class = true,
+                        ']' => is_in_class = false,
+                        _ => {}
+                    }
+                    pattern.push(char_list[index]);
+                    index += 1;
+                }
+                index += 1;
+                let start = index;
+                while index < char_list.len() && char_list[index].is_alphabetic() {
+                    index += 1;
+                }
+                token_list.push(Token::RegExp(
+                    pattern,
+                    char_list[start..index].iter().collect(),
+                ));
+            } else if char == '"' {
+                let mut string = String::new();
+                index += 1;
+                while char_list[index] != '"' {
+                    if char_list[index] == '\\' {
+                        index += 1;
+                    }
+                    string.push(char_list[index]);
+                    index += 1;
+                }
+                index += 1;
+                token_list.push(Token::String(string));
+            } else if char.is_alphabetic() || char == '_' || char == '$' {
+                let start = index;
+                while index < char_list.len()
+                    && (char_list[index].is_alphanumeric()
+                        || char_list[index] == '_'
+                        || char_list[index] == '$')
+                {
+                    index += 1;
+                }
+                token_list.push(Token::Word(char_list[start..index].iter().collect()));
+            } else {
+                let rest: String = char_list[index..].iter().take(3).collect();
+                let punctuator = PUNCTUATOR_LIST
+                    .iter()
+                    .find(|punctuator| rest.starts_with(*punctuator))
+                    .ok_or_else(|| format!("unknown character {}", char))?;
+                index += punctuator.chars().count();
+                token_list.push(Token::Punctuator(punctuator));
+            }
+        }
+        Ok(token_list)
+    }
+
+    /// 括弧で囲まれていない 一番外側の演算子の種類. SyntaxError になる組み合わせを見つけるために使う
+    #[derive(PartialEq, Clone, Copy)]
+    enum Outermost {
+        Other,
+        Unary,
+        Logical,
+        NullishCoalescing,
+    }
+
+    struct Parser {
+        token_list: Vec<Token>,
+        index: usize,
+    }
+
+    impl Parser {
+        fn peek(&self) -> Option<&Token> {
+            self.token_list.get(self.index)
+        }
+
+        fn is_punctuator(&self, offset: usize, punctuator: &str) -> bool {
+            matches!(self.token_list.get(self.index + offset), Some(Token::Punctuator(p)) if *p == punctuator)
+        }
+
+        fn is_word(&self, word: &str) -> bool {
+            self.is_word_at(0, word)
+        }
+
+        fn is_word_at(&self, offset: usize, word: &str) -> bool {
+            matches!(self.token_list.get(self.index + offset), Some(Token::Word(w)) if w == word)
+        }
+
+        fn expect_word(&mut self, word: &str) -> Result<(), String> {
+            if self.is_word(word) {
+                self.index += 1;
+                Ok(())
+            } else {
+                Err(format!("expected {} but found {:?}", word, self.peek()))
+            }
+        }
+
+        fn eat(&mut self, punctuator: &str) -> bool {
+            if self.is_punctuator(0, punctuator) {
+                self.index += 1;
+                true
+            } else {
+                false
+            }
+        }
+
+        fn expect(&mut self, punctuator: &str) -> Result<(), String> {
+            if self.eat(punctuator) {
+                Ok(())
+            } else {
+                Err(format!(
+                    "expected {} but found {:?}",
+                    punctuator,
+                    self.peek()
+                ))
+            }
+        }
+
+        fn statement(&mut self) -> Result<data::Expr, String> {
+            if self.is_punctuator(0, "{") {
+                return Err(String::from("statement starts with {"));
+            }
+            if self.is_word("function") || (self.is_word("async") && self.is_word_at(1, "function"))
+            {
+                return Err(String::from("statement starts with function"));
+            }
+            if self.is_word("class") {
+                return Err(String::from("statement starts with class"));
+            }
+            let expr = self.expression()?;
+            self.expect(";")?;
+            match self.peek() {
+                Some(token) => Err(format!("unexpected {:?}", token)),
+                None => Ok(expr),
+            }
+        }
+
+        fn expression(&mut self) -> Result<data::Expr, String> {
+            let mut left = self.assignment()?;
+            while self.eat(",") {
+                let right = self.assignment()?;
+                left = binary(data::BinaryOperator::Comma, left, right);
+            }
+            Ok(left)
+        }
+
+        /// `() =>`, `async (): number =>` のようなアロー関数の本体より前の部分のトークンの数
+        fn arrow_head_length(&self) -> Option<usize> {
+            let start = if self.is_word("async") { 1 } else { 0 };
+            if !(self.is_punctuator(start, "(") && self.is_punctuator(start + 1, ")")) {
+                return None;
+            }
+            if self.is_punctuator(start + 2, "=>") {
+                return Some(start + 3);
+            }
+            if self.is_punctuator(start + 2, ":")
+                && self.is_word_at(start + 3, "number")
+                && self.is_punctuator(start + 4, "=>")
+            {
+                return Some(start + 5);
+            }
+            None
+        }
+
+        /// 値を指定しない `yield` の直後に来るトークンかどうか
+        fn is_assignment_end(&self) -> bool {
+            match self.peek() {
+                None => true,
+                Some(Token::Punctuator(punctuator)) => {
+                    matches!(*punctuator, ")" | "]" | "}" | "," | ";" | ":")
+                }
+                Some(_) => false,
+            }
+        }
+
+        fn assignment(&mut self) -> Result<data::Expr, String> {
+            if self.is_word("yield") {
+                self.index += 1;
+                let is_delegate = self.eat("*");
+                let expr = if !is_delegate && self.is_assignment_end() {
+                    None
+                } else {
+                    Some(self.assignment()?)
+                };
+                return Ok(yield_expr(expr, is_delegate));
+            }
+            if let Some(head_length) = self.arrow_head_length() {
+                let is_async = self.is_word("async");
+                self.index += head_length;
+                if self.is_punctuator(0, "{") {
+                    return Err(String::from("lambda body starts with {"));
+                }
+                return Ok(function_expr(self.assignment()?, is_async, false));
+            }
+            let (condition, _) = self.binary(0)?;
+            if self.eat("?") {
+                let then_expr = self.assignment()?;
+                self.expect(":")?;
+                let else_expr = self.assignment()?;
+                return Ok(data::Expr::ConditionalOperator(Box::new(
+                    data::ConditionalOperatorExpr {
+                        condition,
+                        then_expr,
+                        else_expr,
+                    },
+                )));
+            }
+            Ok(condition)
+        }
+
+        fn binary_operator(&self) -> Option<(data::BinaryOperator, u8)> {
+            let operator = match self.peek()? {
+                Token::Punctuator(punctuator) => match *punctuator {
+                    "??" => (data::BinaryOperator::NullishCoalescing, 1),
+                    "||" => (data::BinaryOperator::LogicalOr, 2),
+                    "&&" => (data::BinaryOperator::LogicalAnd, 3),
+                    "|" => (data::BinaryOperator::BitwiseOr, 4),
+                    "^" => (data::BinaryOperator::BitwiseXOr, 5),
+                    "&" => (data::BinaryOperator::BitwiseAnd, 6),
+                    "===" => (data::BinaryOperator::Equal, 7),
+                    "!==" => (data::BinaryOperator::NotEqual, 7),
+                    "==" => (data::BinaryOperator::LooseEqual, 7),
+                    "!=" => (data::BinaryOperator::LooseNotEqual, 7),
+                    "<" => (data::BinaryOperator::LessThan, 8),
+                    "<=" => (data::BinaryOperator::LessThanOrEqual, 8),
+                    ">" => (data::BinaryOperator::GreaterThan, 8),
+                    ">=" => (data::BinaryOperator::GreaterThanOrEqual, 8),
+                    "<<" => (data::BinaryOperator::LeftShift, 9),
+                    ">>" => (data::BinaryOperator::SignedRightShift, 9),
+                    ">>>" => (data::BinaryOperator::UnsignedRightShift, 9),
+                    "+" => (data::BinaryOperator::Addition, 10),
+                    "-" => (data::BinaryOperator::Subtraction, 10),
+                    "*" => (data::BinaryOperator::Multiplication, 11),
+                    "/" => (data::BinaryOperator::Division, 11),
+                    "%" => (data::BinaryOperator::Remainder, 11),
+                    "**" => (data::BinaryOperator::Exponentiation, 12),
+                    _ => return None,
+                },
+                Token::Word(word) if word == "in" => (data::BinaryOperator::In, 8),
+                Token::Word(word) if word == "instanceof" => (data::BinaryOperator::InstanceOf, 8),
+                _ => return None,
+            };
+            // `??` は `||` と同じ優先順位として扱い, 混ぜたときは下でエラーにする
+            Some(if operator.0 == data::BinaryOperator::NullishCoalescing {
+                (operator.0, 2)
+            } else {
+                operator
+            })
+        }
+
+        /// 優先順位が `minimum` 以上の2項演算子の式. TypeScript の `as` は関係演算子と同じ優先順位
+        fn binary(&mut self, minimum: u8) -> Result<(data::Expr, Outermost), String> {
+            let (mut left, mut left_outermost) = self.unary()?;
+            loop {
+                if self.is_word("as") && 8 >= minimum {
+                    self.index += 1;
+                    self.expect_word("number")?;
+                    left = type_assertion(left);
+                    left_outermost = Outermost::Other;
+                    continue;
+                }
+                let (operator, precedence) = match self.binary_operator() {
+                    Some(operator) => operator,
+                    None => break,
+                };
+                if precedence < minimum {
+                    break;
+                }
+                self.index += 1;
+                let is_exponentiation = operator == data::BinaryOperator::Exponentiation;
+                if is_exponentiation && left_outermost == Outermost::Unary {
+                    return Err(String::from("unary expression before **"));
+                }
+                let (right, right_outermost) = self.binary(if is_exponentiation {
+                    precedence
+                } else {
+                    precedence + 1
+                })?;
+                let outermost = match operator {
+                    data::BinaryOperator::LogicalAnd | data::BinaryOperator::LogicalOr => {
+                        Outermost::Logical
+                    }
+                    data::BinaryOperator::NullishCoalescing => Outermost::NullishCoalescing,
+                    _ => Outermost::Other,
+                };
+                let is_mixed = |operand: Outermost| {
+                    (outermost == Outermost::Logical && operand == Outermost::NullishCoalescing)
+                        || (outermost == Outermost::NullishCoalescing
+                            && operand == Outermost::Logical)
+                };
+                if is_mixed(left_outermost) || is_mixed(right_outermost) {
+                    return Err(String::from("?? mixed with && or ||"));
+                }
+                left = binary(operator, left, right);
+                left_outermost = outermost;
+            }
+            Ok((left, left_outermost))
+        }
+
+        fn unary(&mut self) -> Result<(data::Expr, Outermost), String> {
+            if self.is_word("await") {
+                self.index += 1;
+                let (expr, _) = self.unary()?;
+                return Ok((util::await_value(expr), Outermost::Unary));
+            }
+            let operator = match self.peek() {
+                Some(Token::Punctuator("-")) => {
+                    // `-1++` は `-(1++)`
+                    if let (Some(Token::Number(number)), false, false) = (
+                        self.token_list.get(self.index + 1),
+                        self.is_punctuator(2, "++"),
+                        self.is_punctuator(2, "--"),
+                    ) {
+                        let number = -number;
+                        self.index += 2;
+                        return Ok((data::Expr::NumberLiteral(number), Outermost::Unary));
+                    }
+                    Some(data::UnaryOperator::Minus)
+                }
+                Some(Token::Punctuator("+")) => Some(data::UnaryOperator::Plus),
+                Some(Token::Punctuator("!")) => Some(data::UnaryOperator::LogicalNot),
+                Some(Token::Punctuator("~")) => Some(data::UnaryOperator::BitwiseNot),
+                Some(Token::Punctuator("++")) => Some(data::UnaryOperator::PrefixIncrement),
+                Some(Token::Punctuator("--")) => Some(data::UnaryOperator::PrefixDecrement),
+                Some(Token::Word(word)) if word == "typeof" => Some(data::UnaryOperator::TypeOf),
+                Some(Token::Word(word)) if word == "void" => Some(data::UnaryOperator::Void),
+                Some(Token::Word(word)) if word == "delete" => Some(data::UnaryOperator::Delete),
+                _ => None,
+            };
+            match operator {
+                Some(operator) => {
+                    self.index += 1;
+                    let outermost = match operator {
+                        data::UnaryOperator::PrefixIncrement
+                        | data::UnaryOperator::PrefixDecrement => Outermost::Other,
+                        _ => Outermost::Unary,
+                    };
+                    let (expr, _) = self.unary()?;
+                    Ok((unary(operator, expr), outermost))
+                }
+                None => {
+                    let expr = self.left_hand_side(true)?;
+                    if self.eat("++") {
+                        Ok((
+                            unary(data::UnaryOperator::PostfixIncrement, expr),
+                            Outermost::Other,
+                        ))
+                    } else if self.eat("--") {
+                        Ok((
+                            unary(data::UnaryOperator::PostfixDecrement, expr),
+                            Outermost::Other,
+                        ))
+                    } else {
+                        Ok((expr, Outermost::Other))
+                    }
+                }
+            }
+        }
+
+        /// プロパティアクセスや関数呼び出しの連なり. `new` の対象では関数呼び出しと optional chaining は読まない
+        fn left_hand_side(&mut self, allow_call: bool) -> Result<data::Expr, String> {
+            let mut expr = if self.is_word("new") {
+                self.index += 1;
+                let target = self.left_hand_side(false)?;
+                let parameter_list = self.argument_list()?;
+                data::Expr::New(Box::new(data::CallExpr {
+                    expr: target,
+                    parameter_list,
+                    is_optional_chain: false,
+                }))
+            } else {
+                self.primary()?
+            };
+            loop {
+                if self.eat(".") {
+                    expr = get(expr, self.property_name()?, false);
+                } else if self.is_punctuator(0, "[") {
+                    self.index += 1;
+                    let property_expr = self.expression()?;
+                    self.expect("]")?;
+                    expr = get(expr, property_expr, false);
+                } else if allow_call && self.is_punctuator(0, "(") {
+                    expr = call(expr, self.argument_list()?, false);
+                } else if allow_call && self.eat("?.") {
+                    if self.is_punctuator(0, "(") {
+                        expr = call(expr, self.argument_list()?, true);
+                    } else if self.eat("[") {
+                        let property_expr = self.expression()?;
+                        self.expect("]")?;
+                        expr = get(expr, property_expr, true);
+                    } else {
+                        expr = get(expr, self.property_name()?, true);
+                    }
+                } else if self.is_punctuator(0, "?.") {
+                    return Err(String::from("optional chain in new"));
+                } else {
+                    return Ok(expr);
+                }
+            }
+        }
+
+        fn property_name(&mut self) -> Result<data::Expr, String> {
+            match self.token_list.get(self.index) {
+                Some(Token::Word(word)) => {
+                    let word = word.clone();
+                    self.index += 1;
+                    Ok(data::Expr::StringLiteral(word))
+                }
+                token => Err(format!("expected property name but found {:?}", token)),
+            }
+        }
+
+        fn argument_list(&mut self) -> Result<Vec<data::ArrayItem>, String> {
+            self.expect("(")?;
+            let mut argument_list = Vec::new();
+            while !self.eat(")") {
+                if !argument_list.is_empty() {
+                    self.expect(",")?;
+                }
+                argument_list.push(self.item()?);
+            }
+            Ok(argument_list)
+        }
+
+        /// 配列リテラルの要素や 関数呼び出しのパラメーター. `...` があればスプレッド
+        fn item(&mut self) -> Result<data::ArrayItem, String> {
+            Ok(if self.eat("...") {
+                util::spread(self.assignment()?)
+            } else {
+                util::array_item(self.assignment()?)
+            })
+        }
+
+        fn primary(&mut self) -> Result<data::Expr, String> {
+            let token = self
+                .token_list
+                .get(self.index)
+                .ok_or_else(|| String::from("unexpected end"))?;
+            self.index += 1;
+            Ok(match token {
+                Token::Number(number) => data::Expr::NumberLiteral(*number),
+                Token::String(string) => data::Expr::StringLiteral(string.clone()),
+                Token::RegExp(pattern, flags) => util::regexp(pattern, flags),
+                Token::Word(word) => match word.as_str() {
+                    "async" if self.is_word("function") => {
+                        self.index += 1;
+                        return self.generator_function_expr(true);
+                    }
+                    "async" => {
+                        // `async () => a`
+                        self.index -= 1;
+                        return self.assignment();
+                    }
+                    "function" => return self.generator_function_expr(false),
+                    "class" => return self.class_extends_expr(),
+                    "this" => data::Expr::This,
+                    "true" => data::Expr::BooleanLiteral(true),
+                    "false" => data::Expr::BooleanLiteral(false),
+                    "null" => data::Expr::NullLiteral,
+                    "undefined" => data::Expr::UndefinedLiteral,
+                    _ => data::Expr::Variable(data::identifer::from_string(word)),
+                },
+                Token::Punctuator("(") => {
+                    self.index -= 1;
+                    if self.is_punctuator(1, ")") {
+                        // `() => a`
+                        return self.assignment();
+                    }
+                    self.index += 1;
+                    let expr = self.expression()?;
+                    self.expect(")")?;
+                    expr
+                }
+                Token::Punctuator("[") => {
+                    let mut item_list = Vec::new();
+                    while !self.eat("]") {
+                        if !item_list.is_empty() {
+                            self.expect(",")?;
+                        }
+                        item_list.push(self.item()?);
+                    }
+                    data::Expr::ArrayLiteral(Box::new(item_list))
+                }
+                Token::Punctuator("{") => {
+                    let mut member_list = Vec::new();
+                    while !self.eat("}") {
+                        if !member_list.is_empty() {
+                            self.expect(",")?;
+                        }
+                        let key = match self.property_name()? {
+                            data::Expr::StringLiteral(key) => key,
+                            _ => unreachable!(),
+                        };
+                        self.expect(":")?;
+                        member_list.push(data::Member::KeyValue(data::KeyValue {
+                            key,
+                            value: self.assignment()?,
+                        }));
+                    }
+                    data::Expr::ObjectLiteral(Box::new(member_list))
+                }
+                token => return Err(format!("unexpected {:?}", token)),
+            })
+        }
+
+        /// `class` の後の `extends a {}`
+        fn class_extends_expr(&mut self) -> Result<data::Expr, String> {
+            self.expect_word("extends")?;
+            let extends = self.left_hand_side(true)?;
+            self.expect("{")?;
+            self.expect("}")?;
+            Ok(class_extends(extends))
+        }
+
+        /// `function` の後の `* (): number { return a; }`
+        fn generator_function_expr(&mut self, is_async: bool) -> Result<data::Expr, String> {
+            self.expect("*")?;
+            self.expect("(")?;
+            self.expect(")")?;
+            if self.eat(":") {
+                self.expect_word("number")?;
+            }
+            self.expect("{")?;
+            self.expect_word("return")?;
+            let body = self.expression()?;
+            self.expect(";")?;
+            self.expect("}")?;
+            Ok(function_expr(body, is_async, true))
+        }
+    }
+
+    fn binary(operator: data::BinaryOperator, left: data::Expr, right: data::Expr) -> data::Expr {
+        data::Expr::BinaryOperator(Box::new(data::BinaryOperatorExpr {
+            operator,
+            left,
+            right,
+        }))
+    }
+
+    fn unary(operator: data::UnaryOperator, expr: data::Expr) -> data::Expr {
+        data::Expr::UnaryOperator(Box::new(data::UnaryOperatorExpr { operator, expr }))
+    }
+
+    fn get(expr: data::Expr, property_expr: data::Expr, is_optional_chain: bool) -> data::Expr {
+        data::Expr::Get(Box::new(data::GetExpr {
+            expr,
+            property_expr,
+            is_optional_chain,
+        }))
+    }
+
+    fn call(
+        expr: data::Expr,
+        parameter_list: Vec<data::ArrayItem>,
+        is_optional_chain: bool,
+    ) -> data::Expr {
+        data::Expr::Call(Box::new(data::CallExpr {
+            expr,
+            parameter_list,
+            is_optional_chain,
+        }))
+    }
+
+    /// 式文 `a;` を読む
+    fn parse_statement(code: &str) -> Result<data::Expr, String> {
+        Parser {
+            token_list: tokenize(code)?,
+            index: 0,
+        }
+        .statement()
+    }
+
+    fn function_expr(body: data::Expr, is_async: bool, is_generator: bool) -> data::Expr {
+        data::Expr::Lambda(Box::new(data::LambdaExpr {
+            parameter_list: vec![],
+            type_parameter_list: vec![],
+            return_type: data::Type::Number,
+            statement_list: vec![data::Statement::Return(body)],
+            is_async,
+            is_generator,
+        }))
+    }
+
+    fn type_assertion(expr: data::Expr) -> data::Expr {
+        data::Expr::TypeAssertion(Box::new(data::TypeAssertion {
+            expr,
+            r#type: data::Type::Number,
+        }))
+    }
+
+    fn yield_expr(expr: Option<data::Expr>, is_delegate: bool) -> data::Expr {
+        data::Expr::Yield(Box::new(data::YieldExpr { expr, is_delegate }))
+    }
+
+    /// メンバーのない 継承するだけのクラス
+    fn class_extends(extends: data::Expr) -> data::Expr {
+        data::Expr::Class(Box::new(data::ClassExpr {
+            name: None,
+            extends: Some(extends),
+            constructor: None,
+            member_list: vec![],
+        }))
+    }
+
+    const BINARY_OPERATOR_LIST: [fn() -> data::BinaryOperator; 26] = [
+        || data::BinaryOperator::Exponentiation,
+        || data::BinaryOperator::Multiplication,
+        || data::BinaryOperator::Division,
+        || data::BinaryOperator::Remainder,
+        || data::BinaryOperator::Addition,
+        || data::BinaryOperator::Subtraction,
+        || data::BinaryOperator::LeftShift,
+        || data::BinaryOperator::SignedRightShift,
+        || data::BinaryOperator::UnsignedRightShift,
+        || data::BinaryOperator::LessThan,
+        || data::BinaryOperator::LessThanOrEqual,
+        || data::BinaryOperator::GreaterThan,
+        || data::BinaryOperator::GreaterThanOrEqual,
+        || data::BinaryOperator::In,
+        || data::BinaryOperator::InstanceOf,
+        || data::BinaryOperator::Equal,
+        || data::BinaryOperator::NotEqual,
+        || data::BinaryOperator::LooseEqual,
+        || data::BinaryOperator::LooseNotEqual,
+        || data::BinaryOperator::BitwiseAnd,
+        || data::BinaryOperator::BitwiseXOr,
+        || data::BinaryOperator::BitwiseOr,
+        || data::BinaryOperator::LogicalAnd,
+        || data::BinaryOperator::LogicalOr,
+        || data::BinaryOperator::NullishCoalescing,
+        || data::BinaryOperator::Comma,
+    ];
+
+    const UNARY_OPERATOR_LIST: [fn() -> data::UnaryOperator; 11] = [
+        || data::UnaryOperator::Minus,
+        || data::UnaryOperator::BitwiseNot,
+        || data::UnaryOperator::LogicalNot,
+        || data::UnaryOperator::Plus,
+        || data::UnaryOperator::TypeOf,
+        || data::UnaryOperator::Void,
+        || data::UnaryOperator::Delete,
+        || data::UnaryOperator::PrefixIncrement,
+        || data::UnaryOperator::PrefixDecrement,
+        || data::UnaryOperator::PostfixIncrement,
+        || data::UnaryOperator::PostfixDecrement,
+    ];
+
+    /// 2項演算子, 単項演算子以外の 子を持つ式の形の数
+    const OTHER_SHAPE_COUNT: usize = 20;
+
+    const SHAPE_COUNT: usize =
+        BINARY_OPERATOR_LIST.len() + UNARY_OPERATOR_LIST.len() + OTHER_SHAPE_COUNT;
+
+    const LEAF_COUNT: usize = 12;
+
+    /// 子を持たない式. `GlobalObjects` などは 変数やプロパティアクセスと同じように出力されるので含めない
+    fn leaf(index: usize) -> data::Expr {
+        match index % LEAF_COUNT {
+            0 => data::Expr::Variable(data::identifer::from_string("a")),
+            1 => data::Expr::Variable(data::identifer::from_string("b")),
+            2 => data::Expr::NumberLiteral(1),
+            3 => data::Expr::NumberLiteral(-2),
+            4 => data::Expr::ObjectLiteral(Box::default()),
+            5 => util::regexp("a+", "g"),
+            6 => yield_expr(None, false),
+            7 => data::Expr::StringLiteral(String::from("s")),
+            8 => data::Expr::BooleanLiteral(true),
+            9 => data::Expr::NullLiteral,
+            10 => data::Expr::UndefinedLiteral,
+            _ => data::Expr::This,
+        }
+    }
+
+    /// 形の子の数
+    fn slot_count(index: usize) -> usize {
+        if index < BINARY_OPERATOR_LIST.len() {
+            return 2;
+        }
+        let index = index - BINARY_OPERATOR_LIST.len();
+        if index < UNARY_OPERATOR_LIST.len() {
+            return 1;
+        }
+        match index - UNARY_OPERATOR_LIST.len() {
+            0 => 3,
+            1 | 3 | 9 | 10 | 12..=19 => 1,
+            _ => 2,
+        }
+    }
+
+    /// `index` 番目の形の式を `child` で子を作って組み立てる
+    fn shape(index: usize, child: &mut dyn FnMut(usize) -> data::Expr) -> data::Expr {
+        if index < BINARY_OPERATOR_LIST.len() {
+            let left = child(0);
+            return binary(BINARY_OPERATOR_LIST[index](), left, child(1));
+        }
+        let index = index - BINARY_OPERATOR_LIST.len();
+        if index < UNARY_OPERATOR_LIST.len() {
+            return match (UNARY_OPERATOR_LIST[index](), child(0)) {
+                // `-1` は数値リテラルとして読まれる
+                (data::UnaryOperator::Minus, data::Expr::NumberLiteral(number)) if number >= 0 => {
+                    data::Expr::NumberLiteral(-number)
+                }
+                (operator, expr) => unary(operator, expr),
+            };
+        }
+        match index - UNARY_OPERATOR_LIST.len() {
+            0 => {
+                let condition = child(0);
+                let then_expr = child(1);
+                data::Expr::ConditionalOperator(Box::new(data::ConditionalOperatorExpr {
+                    condition,
+                    then_expr,
+                    else_expr: child(2),
+                }))
+            }
+            1 => get(
+                child(0),
+                data::Expr::StringLiteral(String::from("key")),
+                false,
+            ),
+            2 => {
+                let expr = child(0);
+                get(expr, child(1), false)
+            }
+            3 => get(
+                child(0),
+                data::Expr::StringLiteral(String::from("key")),
+                true,
+            ),
+            4 => {
+                let expr = child(0);
+                get(expr, child(1), true)
+            }
+            5 => {
+                let expr = child(0);
+                call(expr, vec![util::array_item(child(1))], false)
+            }
+            6 => {
+                let expr = child(0);
+                call(expr, vec![util::array_item(child(1))], true)
+            }
+            7 => {
+                let expr = child(0);
+                data::Expr::New(Box::new(data::CallExpr {
+                    expr,
+                    parameter_list: vec![util::array_item(child(1))],
+                    is_optional_chain: false,
+                }))
+            }
+            8 => {
+                let first = child(0);
+                data::Expr::ArrayLiteral(Box::new(vec![
+                    util::array_item(first),
+                    util::array_item(child(1)),
+                ]))
+            }
+            9 => {
+                data::Expr::ObjectLiteral(Box::new(vec![data::Member::KeyValue(data::KeyValue {
+                    key: String::from("key"),
+                    value: child(0),
+                })]))
+            }
+            10 => function_expr(child(0), false, false),
+            11 => {
+                let expr = child(0);
+                call(expr, vec![util::spread(child(1))], false)
+            }
+            12 => type_assertion(child(0)),
+            13 => yield_expr(Some(child(0)), false),
+            14 => yield_expr(Some(child(0)), true),
+            15 => function_expr(child(0), true, false),
+            16 => function_expr(child(0), false, true),
+            17 => function_expr(child(0), true, true),
+            18 => util::await_value(child(0)),
+            _ => class_extends(child(0)),
+        }
+    }
+
+    /// 型アサーションを取り除いた式. JavaScript では型アサーションを出力しないので 読み直すとこれと同じになる.
+    /// `-(1 as number)` は `-1` になるので 数値リテラルとして読まれる
+    fn erase_type_assertion(expr: &data::Expr) -> data::Expr {
+        fn erase(value: serde_json::Value) -> serde_json::Value {
+            match value {
+                serde_json::Value::Object(mut map) => match map.remove("TypeAssertion") {
+                    Some(mut type_assertion) => erase(type_assertion["expr"].take()),
+                    None => {
+                        let erased: serde_json::Value = map
+                            .into_iter()
+                            .map(|(key, value)| (key, erase(value)))
+                            .collect();
+                        match (
+                            &erased["UnaryOperator"]["operator"],
+                            erased["UnaryOperator"]["expr"]["NumberLiteral"].as_i64(),
+                        ) {
+                            (serde_json::Value::String(operator), Some(number))
+                                if operator == "Minus" && number >= 0 =>
+                            {
+                                serde_json::json!({ "NumberLiteral": -number })
+                            }
+                            _ => erased,
+                        }
+                    }
+                },
+                serde_json::Value::Array(list) => list.into_iter().map(erase).collect(),
+                value => value,
+            }
+        }
+        serde_json::from_value(erase(serde_json::to_value(expr).unwrap())).unwrap()
+    }
+
+    /// 式文として出力したものを読み直して 元の式と同じになることを確かめる.
+    /// JavaScript と TypeScript の両方を確かめる
+    fn assert_reparse(expr: data::Expr) {
+        let erased_statement_list =
+            vec![data::Statement::EvaluateExpr(erase_type_assertion(&expr))];
+        let code = data::Code {
+            export_definition_list: vec![],
+            statement_list: vec![data::Statement::EvaluateExpr(expr)],
+        };
+        for (code_type, expected) in [
+            (data::CodeType::JavaScript, &erased_statement_list),
+            (data::CodeType::TypeScript, &code.statement_list),
+        ] {
+            let code_string = crate::to_string::to_string(&code, &code_type);
+            let statement = code_string
+                .strip_prefix(
+                    "/* eslint-disable */\n/* generated by js-ts-code-generator. Do not edit! */\n\n{\n  ",
+                )
+                .and_then(|statement| statement.strip_suffix("\n}"))
+                .unwrap_or_else(|| panic!("{:?}", code_string));
+            match parse_statement(statement) {
+                Ok(parsed) => assert_eq!(
+                    expected,
+                    &vec![data::Statement::EvaluateExpr(parsed)],
+                    "{:?} {}",
+                    code_type,
+                    statement
+                ),
+                Err(message) => panic!("{:?} {}: {}", code_type, message, statement),
+            }
+        }
+    }
+
+    /// JavaScript では型アサーションを出力しないので 中の式に必要な括弧を付ける
+    #[test]
+    fn test_reparse_type_assertion() {
+        let variable = |name: &str| data::Expr::Variable(data::identifer::from_string(name));
+        assert_reparse(binary(
+            data::BinaryOperator::Exponentiation,
+            type_assertion(util::minus(variable("a"))),
+            variable("b"),
+        ));
+        assert_reparse(util::get(type_assertion(data::Expr::NumberLiteral(1)), "x"));
+        assert_reparse(util::minus(type_assertion(util::minus(variable("a")))));
+        assert_reparse(binary(
+            data::BinaryOperator::NullishCoalescing,
+            type_assertion(binary(
+                data::BinaryOperator::LogicalAnd,
+                variable("a"),
+                variable("b"),
+            )),
+            variable("c"),
+        ));
+        assert_reparse(data::Expr::New(Box::new(data::CallExpr {
+            expr: type_assertion(call(variable("f"), vec![], false)),
+            parameter_list: vec![],
+            is_optional_chain: false,
+        })));
+    }
+
+    #[test]
+    fn test_reparse_depth_2() {
+        for outer in 0..SHAPE_COUNT {
+            for slot in 0..slot_count(outer) {
+                for inner in 0..SHAPE_COUNT + LEAF_COUNT {
+                    assert_reparse(shape(outer, &mut |index| {
+                        if index != slot {
+                            leaf(index)
+                        } else if inner < SHAPE_COUNT {
+                            shape(inner, &mut leaf)
+                        } else {
+                            leaf(inner)
+                        }
+                    }));
+                }
+            }
+        }
+    }
+
+    /// 線形合同法による乱数
+    struct Random(u64);
+
+    impl Random {
+        fn next(&mut self, max: usize) -> usize {
+            self.0 = self
+                .0
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ((self.0 >> 33) as usize) % max
+        }
+    }
+
+    fn random_expr(random: &mut Random, depth: usize) -> data::Expr {
+        if depth == 0 || random.next(4) == 0 {
+            return leaf(random.next(LEAF_COUNT));
+        }
+        shape(random.next(SHAPE_COUNT), &mut |_| {
+            random_expr(random, depth - 1)
+        })
+    }
+
+    #[test]
+    fn test_reparse_random() {
+        let mut random = Random(20);
+        for _ in 0..20000 {
+            assert_reparse(random_expr(&mut random, 5));
+        }
+    }
+}
@@ -0,0 +1,446 @@
+use crate::collect;
+use crate::data;
+use crate::to_string;
+use crate::validation;
+
+/// プロジェクトの検証, 出力のエラー
+#[derive(Debug)]
+pub enum ProjectError {
+    /// ファイルのパスとして使えない. 空, `/` で始まる, `.` や `..` を含む など
+    InvalidFilePath(String),
+
+    /// 同じパスのファイルが2つ以上ある
+    DuplicateFilePath(String),
+
+    /// 参照しているファイルがプロジェクト内にない
+    UnknownFile {
+        /// 参照しているファイル
+        file_path: String,
+        /// 参照されているファイル
+        reference_file_path: String,
+    },
+
+    /// 参照している名前が参照先のファイルで export されていない
+    UndefinedReference {
+        /// 参照しているファイル
+        file_path: String,
+        /// 参照されているファイル
+        reference_file_path: String,
+        /// 参照している名前
+        name: String,
+    },
+
+    /// 値のインポートが循環している. 循環しているファイルのパスを参照順に並べたもの
+    ImportCycle(Vec<String>),
+
+    /// ファイルの `Code` の検証エラー
+    Validation {
+        file_path: String,
+        error_list: Vec<data::ValidationError>,
+    },
+
+    /// ファイルの書き込みに失敗した
+    Io(std::io::Error),
+}
+
+impl std::fmt::Display for ProjectError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProjectError::InvalidFilePath(file_path) => {
+                write!(f, "{:?} is not a valid file path", file_path)
+            }
+            ProjectError::DuplicateFilePath(file_path) => {
+                write!(f, "{:?} is defined more than once", file_path)
+            }
+            ProjectError::UnknownFile {
+                file_path,
+                reference_file_path,
+            } => write!(
+                f,
+                "{}: {:?} is not in the project",
+                file_path, reference_file_path
+            ),
+            ProjectError::UndefinedReference {
+                file_path,
+                reference_file_path,
+                name,
+            } => write!(
+                f,
+                "{}: {:?} is not exported from {:?}",
+                file_path, name, reference_file_path
+            ),
+            ProjectError::ImportCycle(file_path_list) => {
+                write!(f, "import cycle: {}", file_path_list.join(" -> "))
+            }
+            ProjectError::Validation {
+                file_path,
+                error_list,
+            } => write!(
+                f,
+                "{}: {}",
+                file_path,
+                error_list
+                    .iter()
+                    .map(|error| error.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            ProjectError::Io(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for ProjectError {}
+
+/// 出力するファイルの拡張子
+pub fn file_extension(code_type: &data::CodeType) -> &'static str {
+    match code_type {
+        data::CodeType::JavaScript | data::CodeType::JavaScriptWithJsDoc => ".js",
+        data::CodeType::TypeScript => ".ts",
+        data::CodeType::Declaration => ".d.ts",
+    }
+}
+
+/// プロジェクトを検証する. 問題がなければ空のリストを返す
+///
+/// 型だけの参照は出力時に消えるため循環していてもよいが, 値の参照の循環はエラーになる
+pub fn validate(project: &data::Project) -> Vec<ProjectError> {
+    let mut error_list = Vec::new();
+    let mut file_path_list: Vec<&str> = Vec::new();
+    for file in &project.file_list {
+        if !is_valid_file_path(&file.file_path) {
+            error_list.push(ProjectError::InvalidFilePath(file.file_path.clone()));
+        }
+        if file_path_list.contains(&file.file_path.as_str()) {
+            error_list.push(ProjectError::DuplicateFilePath(file.file_path.clone()));
+        } else {
+            file_path_list.push(&file.file_path);
+        }
+        let validation_error_list = validation::validate(&file.code);
+        if !validation_error_list.is_empty() {
+            error_list.push(ProjectError::Validation {
+                file_path: file.file_path.clone(),
+                error_list: validation_error_list,
+            });
+        }
+    }
+
+    for file in &project.file_list {
+        for reference in collect::reference_list(&file.code) {
+            let project_reference = match reference {
+                collect::Reference::ProjectVariable(project_reference)
+                | collect::Reference::ProjectType(project_reference) => project_reference,
+                collect::Reference::ImportedVariable(_) | collect::Reference::ImportedType(_) => {
+                    continue
+                }
+            };
+            match find_file(project, &project_reference.file_path) {
+                None => error_list.push(ProjectError::UnknownFile {
+                    file_path: file.file_path.clone(),
+                    reference_file_path: project_reference.file_path.clone(),
+                }),
+                Some(reference_file) => {
+                    if !is_exported(&reference_file.code, reference, &project_reference.name) {
+                        error_list.push(ProjectError::UndefinedReference {
+                            file_path: file.file_path.clone(),
+                            reference_file_path: project_reference.file_path.clone(),
+                            name: project_reference.name.get(),
+                        })
+                    }
+                }
+            }
+        }
+    }
+
+    for cycle in import_cycle_list(project) {
+        error_list.push(ProjectError::ImportCycle(cycle));
+    }
+    error_list
+}
+
+/// プロジェクトを検証して, 各ファイルを文字列にする. 拡張子付きのパスと中身のリストを返す
+pub fn to_string_list(
+    project: &data::Project,
+    option: &data::GenerateOption,
+) -> Result<Vec<(String, String)>, Vec<ProjectError>> {
+    let mut error_list = validate(project);
+    for file in &project.file_list {
+        let option_error_list = validation::option_validate(&file.code, option);
+        if !option_error_list.is_empty() {
+            error_list.push(ProjectError::Validation {
+                file_path: file.file_path.clone(),
+                error_list: option_error_list,
+            });
+        }
+    }
+    if !error_list.is_empty() {
+        return Err(error_list);
+    }
+    Ok(project
+        .file_list
+        .iter()
+        .map(|file| {
+            (
+                file.file_path.clone() + file_extension(&option.code_type),
+                to_string::to_string_in_project(&file.code, option, &file.file_path),
+            )
+        })
+        .collect())
+}
+
+/// プロジェクトを検証して, すべてのファイルを `directory` 以下に書き込む
+pub fn write(
+    project: &data::Project,
+    option: &data::GenerateOption,
+    directory: &std::path::Path,
+) -> Result<(), Vec<ProjectError>> {
+    for (file_path, code_as_string) in to_string_list(project, option)? {
+        let path = directory.join(file_path);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|error| vec![ProjectError::Io(error)])?;
+        }
+        std::fs::write(path, code_as_string).map_err(|error| vec![ProjectError::Io(error)])?;
+    }
+    Ok(())
+}
+
+/// `file_path` のファイルから `reference_file_path` のファイルをインポートするときのモジュール名
+///
+/// - `a/b` から `a/c` → `./c`
+/// - `a/b/c` から `d` → `../../d`
+pub fn relative_module_name(file_path: &str, reference_file_path: &str) -> String {
+    let directory: Vec<&str> = {
+        let mut segment_list: Vec<&str> = file_path.split('/').collect();
+        segment_list.pop();
+        segment_list
+    };
+    let reference: Vec<&str> = reference_file_path.split('/').collect();
+    let common_length = directory
+        .iter()
+        .zip(reference.iter().take(reference.len() - 1))
+        .take_while(|(a, b)| a == b)
+        .count();
+    let rest = reference[common_length..].join("/");
+    if directory.len() == common_length {
+        String::from("./") + &rest
+    } else {
+        "../".repeat(directory.len() - common_length) + &rest
+    }
+}
+
+fn is_valid_file_path(file_path: &str) -> bool {
+    !file_path.contains('\\')
+        && file_path
+            .split('/')
+            .all(|segment| !segment.is_empty() && segment != "." && segment != "..")
+}
+
+fn find_file<'a>(project: &'a data::Project, file_path: &str) -> Option<&'a data::ProjectFile> {
+    project
+        .file_list
+        .iter()
+        .find(|file| file.file_path == file_path)
+}
+
+fn is_exported(
+    code: &data::Code,
+    reference: collect::Reference,
+    name: &data::identifer::Identifer,
+) -> bool {
+    code.export_definition_list.iter().any(|export_definition| {
+        match (export_definition, &reference) {
+            (data::ExportDefinition::TypeAlias(type_alias), collect::Reference::ProjectType(_)) => {
+                type_alias.name == *name && type_alias.visibility == data::Visibility::Export
+            }
+            (
+                data::ExportDefinition::Function(function),
+                collect::Reference::ProjectVariable(_),
+            ) => function.name == *name && function.visibility == data::Visibility::Export,
+            (
+                data::ExportDefinition::Variable(variable),
+                collect::Reference::ProjectVariable(_),
+            ) => variable.name == *name && variable.visibility == data::Visibility::Export,
+            (data::ExportDefinition::ExportFrom(export_from), _) => {
+                (!export_from.is_type_only
+                    || matches!(reference, collect::Reference::ProjectType(_)))
+                    && export_from.name_list.iter().any(|export_name| {
+                        export_name.alias.as_ref().unwrap_or(&export_name.name) == name
+                    })
+            }
+            // `export * from` で何が export されるかは 参照先のモジュールを見ないと分からない
+            (data::ExportDefinition::ExportAll(export_all), _) => match &export_all.namespace {
+                Some(namespace) => namespace == name,
+                None => true,
+            },
+            _ => false,
+        }
+    })
+}
+
+/// 値の参照でファイル間のインポートが循環しているものを探す
+fn import_cycle_list(project: &data::Project) -> Vec<Vec<String>> {
+    let mut cycle_list = Vec::new();
+    let mut finished: Vec<&str> = Vec::new();
+    for file in &project.file_list {
+        let mut stack: Vec<&str> = Vec::new();
+        import_cycle_search(
+            project,
+            &file.file_path,
+            &mut stack,
+            &mut finished,
+            &mut cycle_list,
+        );
+    }
+    cycle_list
+}
+
+fn import_cycle_search<'a>(
+    project: &'a data::Project,
+    file_path: &'a str,
+    stack: &mut Vec<&'a str>,
+    finished: &mut Vec<&'a str>,
+    cycle_list: &mut Vec<Vec<String>>,
+) {
+    if finished.contains(&file_path) {
+        return;
+    }
+    if let Some(index) = stack.iter().position(|path| *path == file_path) {
+        let mut cycle: Vec<String> = stack[index..].iter().map(|path| path.to_string()).collect();
+        cycle.push(file_path.to_string());
+        cycle_list.push(cycle);
+        return;
+    }
+    let file = match find_file(project, file_path) {
+        Some(file) => file,
+        None => return,
+    };
+    stack.push(file_path);
+    for reference in collect::reference_list(&file.code) {
+        if let collect::Reference::ProjectVariable(project_reference) = reference {
+            if project_reference.file_path != file_path {
+                import_cycle_search(
+                    project,
+                    &project_reference.file_path,
+                    stack,
+                    finished,
+                    cycle_list,
+                );
+            }
+        }
+    }
+    stack.pop();
+    finished.push(file_path);
+}
+
+#[test]
+fn test_relative_module_name() {
+    assert_eq!(relative_module_name("a", "b"), "./b");
+    assert_eq!(relative_module_name("a/b", "a/c"), "./c");
+    assert_eq!(relative_module_name("a/b/c", "d"), "../../d");
+    assert_eq!(relative_module_name("a/b/c", "a/b"), "../b");
+    assert_eq!(relative_module_name("a", "x/y/z"), "./x/y/z");
+}
+
+#[cfg(test)]
+fn project_variable(file_path: &str, name: &str) -> data::Expr {
+    data::Expr::ProjectVariable(data::ProjectReference {
+        file_path: String::from(file_path),
+        name: data::identifer::from_string(name),
+    })
+}
+
+#[cfg(test)]
+fn variable_code(name: &str, expr: data::Expr) -> data::Code {
+    data::Code {
+        export_definition_list: vec![data::ExportDefinition::Variable(data::Variable {
+            name: data::identifer::from_string(name),
+            document: String::new(),
+            document_tag_list: vec![],
+            r#type: data::Type::Number,
+            expr,
+            visibility: data::Visibility::Export,
+        })],
+        statement_list: vec![],
+    }
+}
+
+#[test]
+fn test_project_to_string() {
+    let project = data::Project {
+        file_list: vec![
+            data::ProjectFile {
+                file_path: String::from("lib/zero"),
+                code: variable_code("zero", data::Expr::NumberLiteral(0)),
+            },
+            data::ProjectFile {
+                file_path: String::from("api/one"),
+                code: variable_code(
+                    "one",
+                    crate::util::addition(
+                        project_variable("lib/zero", "zero"),
+                        data::Expr::NumberLiteral(1),
+                    ),
+                ),
+            },
+        ],
+    };
+    let file_list = to_string_list(
+        &project,
+        &data::GenerateOption {
+            code_type: data::CodeType::TypeScript,
+            module_format: data::ModuleFormat::EsModule,
+            function_style: data::FunctionStyle::Arrow,
+            is_ascii_only: false,
+            common_js_default_export: data::CommonJsDefaultExport::ExportsDefault,
+        },
+    )
+    .unwrap();
+    assert_eq!(file_list[1].0, "api/one.ts");
+    assert_eq!(
+        file_list[1].1,
+        r###"/* eslint-disable */
+/* generated by js-ts-code-generator. Do not edit! */
+
+import * as $$$$2e$2e$2flib$2fzero from "../lib/zero";
+export const one: number = $$$$2e$2e$2flib$2fzero.zero + 1;
+
+"###
+    );
+}
+
+#[test]
+fn test_project_validate() {
+    let project = data::Project {
+        file_list: vec![
+            data::ProjectFile {
+                file_path: String::from("a"),
+                code: variable_code("a", project_variable("b", "b")),
+            },
+            data::ProjectFile {
+                file_path: String::from("b"),
+                code: variable_code("b", project_variable("a", "a")),
+            },
+            data::ProjectFile {
+                file_path: String::from("c"),
+                code: variable_code("c", project_variable("a", "notFound")),
+            },
+            data::ProjectFile {
+                file_path: String::from("../d"),
+                code: variable_code("d", project_variable("e", "e")),
+            },
+        ],
+    };
+    let error_list: Vec<String> = validate(&project)
+        .iter()
+        .map(|error| error.to_string())
+        .collect();
+    assert_eq!(
+        error_list,
+        vec![
+            "\"../d\" is not a valid file path",
+            "c: \"notFound\" is not exported from \"a\"",
+            "../d: \"e\" is not in the project",
+            "import cycle: a -> b -> a",
+        ]
+    );
+}
@@ -0,0 +1,91 @@
+use crate::data;
+
+/// `data` の型の構造を serde-reflection で取得する.
+/// TypeScript 向けの型定義 (out.ts) や JSON Schema を生成するために使う.
+///
+/// enum は1つずつ trace しないと すべての列挙子を見つけられないので, 型を追加したらここにも追加する
+pub fn registry() -> serde_reflection::Result<serde_reflection::Registry> {
+    let mut tracer = serde_reflection::Tracer::new(serde_reflection::TracerConfig::default());
+    let samples = serde_reflection::Samples::new();
+    tracer.trace_type::<data::Code>(&samples)?;
+    tracer.trace_type::<data::CodeType>(&samples)?;
+    tracer.trace_type::<data::ModuleFormat>(&samples)?;
+    tracer.trace_type::<data::GenerateOption>(&samples)?;
+    tracer.trace_type::<data::GenerateResult>(&samples)?;
+    tracer.trace_type::<data::GenerateError>(&samples)?;
+    tracer.trace_type::<data::ValidationError>(&samples)?;
+    tracer.trace_type::<data::ExportDefinition>(&samples)?;
+    tracer.trace_type::<data::Visibility>(&samples)?;
+    tracer.trace_type::<data::CommonJsDefaultExport>(&samples)?;
+    tracer.trace_type::<data::FunctionStyle>(&samples)?;
+    tracer.trace_type::<data::ExportFrom>(&samples)?;
+    tracer.trace_type::<data::ExportName>(&samples)?;
+    tracer.trace_type::<data::ExportAll>(&samples)?;
+    tracer.trace_type::<data::DefaultExport>(&samples)?;
+    tracer.trace_type::<data::BindingPattern>(&samples)?;
+    tracer.trace_type::<data::ObjectPattern>(&samples)?;
+    tracer.trace_type::<data::ObjectPatternMember>(&samples)?;
+    tracer.trace_type::<data::ArrayPattern>(&samples)?;
+    tracer.trace_type::<data::ArrayPatternItem>(&samples)?;
+    tracer.trace_type::<data::TypeAlias>(&samples)?;
+    tracer.trace_type::<data::Function>(&samples)?;
+    tracer.trace_type::<data::ParameterWithDocument>(&samples)?;
+    tracer.trace_type::<data::Parameter>(&samples)?;
+    tracer.trace_type::<data::ParameterKind>(&samples)?;
+    tracer.trace_type::<data::Variable>(&samples)?;
+    tracer.trace_type::<data::UnaryOperator>(&samples)?;
+    tracer.trace_type::<data::BinaryOperator>(&samples)?;
+    tracer.trace_type::<data::Expr>(&samples)?;
+    tracer.trace_type::<data::Statement>(&samples)?;
+    tracer.trace_type::<data::Type>(&samples)?;
+    tracer.trace_type::<data::UnaryOperatorExpr>(&samples)?;
+    tracer.trace_type::<data::BinaryOperatorExpr>(&samples)?;
+    tracer.trace_type::<data::ConditionalOperatorExpr>(&samples)?;
+    tracer.trace_type::<data::ArrayItem>(&samples)?;
+    tracer.trace_type::<data::Member>(&samples)?;
+    tracer.trace_type::<data::KeyValue>(&samples)?;
+    tracer.trace_type::<data::ComputedKeyValue>(&samples)?;
+    tracer.trace_type::<data::PropertyKey>(&samples)?;
+    tracer.trace_type::<data::ObjectMethod>(&samples)?;
+    tracer.trace_type::<data::ObjectGetter>(&samples)?;
+    tracer.trace_type::<data::ObjectSetter>(&samples)?;
+    tracer.trace_type::<data::CommentedMember>(&samples)?;
+    tracer.trace_type::<data::LambdaExpr>(&samples)?;
+    tracer.trace_type::<data::ClassExpr>(&samples)?;
+    tracer.trace_type::<data::ClassConstructor>(&samples)?;
+    tracer.trace_type::<data::ClassMember>(&samples)?;
+    tracer.trace_type::<data::ClassMemberKind>(&samples)?;
+    tracer.trace_type::<data::ClassField>(&samples)?;
+    tracer.trace_type::<data::ImportedVariable>(&samples)?;
+    tracer.trace_type::<data::GetExpr>(&samples)?;
+    tracer.trace_type::<data::CallExpr>(&samples)?;
+    tracer.trace_type::<data::TypeAssertion>(&samples)?;
+    tracer.trace_type::<data::YieldExpr>(&samples)?;
+    tracer.trace_type::<data::RegExpLiteral>(&samples)?;
+    tracer.trace_type::<data::SetStatement>(&samples)?;
+    tracer.trace_type::<data::IfStatement>(&samples)?;
+    tracer.trace_type::<data::VariableDefinitionStatement>(&samples)?;
+    tracer.trace_type::<data::FunctionDefinitionStatement>(&samples)?;
+    tracer.trace_type::<data::ForStatement>(&samples)?;
+    tracer.trace_type::<data::ForOfStatement>(&samples)?;
+    tracer.trace_type::<data::ForLoopStatement>(&samples)?;
+    tracer.trace_type::<data::ForInit>(&samples)?;
+    tracer.trace_type::<data::ForUpdate>(&samples)?;
+    tracer.trace_type::<data::WhileStatement>(&samples)?;
+    tracer.trace_type::<data::ForInStatement>(&samples)?;
+    tracer.trace_type::<data::LabeledStatement>(&samples)?;
+    tracer.trace_type::<data::CommentedStatement>(&samples)?;
+    tracer.trace_type::<data::Comment>(&samples)?;
+    tracer.trace_type::<data::SwitchStatement>(&samples)?;
+    tracer.trace_type::<data::Pattern>(&samples)?;
+    tracer.trace_type::<data::MemberType>(&samples)?;
+    tracer.trace_type::<data::DocumentTag>(&samples)?;
+    tracer.trace_type::<data::FunctionType>(&samples)?;
+    tracer.trace_type::<data::TypeWithTypeParameter>(&samples)?;
+    tracer.trace_type::<data::IntersectionType>(&samples)?;
+    tracer.trace_type::<data::ImportedType>(&samples)?;
+    tracer.trace_type::<data::Project>(&samples)?;
+    tracer.trace_type::<data::ProjectFile>(&samples)?;
+    tracer.trace_type::<data::ProjectReference>(&samples)?;
+    tracer.registry()
+}
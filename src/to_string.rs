@@ -1,4 +1,9 @@
+use crate::collect;
 use crate::data;
+use crate::precedence;
+use crate::project;
+
+pub use crate::precedence::Associativity;
 
 /// 入れ子の回数
 struct Indent {
@@ -17,91 +22,816 @@ impl Indent {
     }
 }
 
-const ESLINT_DISABLE_COMMENT: &'static str =
+/// 出力の設定と, 出力しているファイルの情報
+struct Context<'a> {
+    /// 出力するコードの種類
+    code_type: &'a data::CodeType,
+
+    /// モジュールの形式
+    module_format: &'a data::ModuleFormat,
+
+    /// プロジェクト内のファイルとして出力しているときの, そのファイルのパス
+    file_path: Option<&'a str>,
+
+    /// `style` を指定していない関数の定義の書き方
+    function_style: &'a data::FunctionStyle,
+
+    /// 文字列リテラルとプロパティ名の ASCII 以外の文字をエスケープするかどうか
+    is_ascii_only: bool,
+
+    /// CommonJS で default export を `exports.default` ではなく `module.exports` に代入するかどうか
+    is_default_module_exports: bool,
+}
+
+static ES_MODULE: data::ModuleFormat = data::ModuleFormat::EsModule;
+
+impl<'a> Context<'a> {
+    fn new(option: &'a data::GenerateOption, file_path: Option<&'a str>) -> Self {
+        Context {
+            code_type: &option.code_type,
+            // TypeScript は TypeScript のコンパイラが モジュールの形式を変換する
+            module_format: match option.code_type {
+                data::CodeType::JavaScript | data::CodeType::JavaScriptWithJsDoc => {
+                    &option.module_format
+                }
+                data::CodeType::TypeScript | data::CodeType::Declaration => &ES_MODULE,
+            },
+            file_path,
+            function_style: &option.function_style,
+            is_ascii_only: option.is_ascii_only,
+            is_default_module_exports: option.common_js_default_export
+                == data::CommonJsDefaultExport::ModuleExports,
+        }
+    }
+}
+
+const ESLINT_DISABLE_COMMENT: &str =
     "/* eslint-disable */\n/* generated by js-ts-code-generator. Do not edit! */\n\n";
 
-/// コードを文字列にする
+const TS_CHECK_COMMENT: &str = "// @ts-check\n";
+
+/// コードを ES Modules 形式で文字列にする
 pub fn to_string(code: &data::Code, code_type: &data::CodeType) -> String {
-    String::from(ESLINT_DISABLE_COMMENT)
-        + &(code
-            .export_definition_list
-            .iter()
-            .map(|export_definition| export_definition_to_string(&export_definition, code_type)))
-        .collect::<Vec<String>>()
-        .join("\n")
-        + &(if code.statement_list.is_empty() {
+    code_to_string(
+        code,
+        &Context {
+            code_type,
+            module_format: &ES_MODULE,
+            file_path: None,
+            function_style: &data::FunctionStyle::Arrow,
+            is_ascii_only: false,
+            is_default_module_exports: false,
+        },
+    )
+}
+
+/// コードの種類とモジュールの形式を指定して, コードを文字列にする
+pub fn to_string_with_option(code: &data::Code, option: &data::GenerateOption) -> String {
+    code_to_string(code, &Context::new(option, None))
+}
+
+/// プロジェクト内の `file_path` のファイルとして, コードを文字列にする.
+/// 同じファイルへの `ProjectReference` はインポートせず, 別のファイルへの参照は相対パスでインポートする
+pub fn to_string_in_project(
+    code: &data::Code,
+    option: &data::GenerateOption,
+    file_path: &str,
+) -> String {
+    code_to_string(code, &Context::new(option, Some(file_path)))
+}
+
+fn code_to_string(code: &data::Code, context: &Context) -> String {
+    let module_name_list = import_module_name_list(code, context);
+    let body = (code
+        .export_definition_list
+        .iter()
+        .map(|export_definition| export_definition_to_string(export_definition, context)))
+    .collect::<Vec<String>>()
+    .join("\n")
+        + &(if code.statement_list.is_empty() || *context.code_type == data::CodeType::Declaration {
             String::new()
         } else {
-            statement_list_to_string(&code.statement_list, &Indent::zero(), code_type)
+            statement_list_to_string(&code.statement_list, &Indent::zero(), context)
+        })
+        + (if *context.code_type == data::CodeType::Declaration && has_private_type_alias(code) {
+            // 宣言ファイルでは export {} がないと, export をつけていない定義も公開されてしまう
+            "export {};\n"
+        } else {
+            ""
+        });
+    String::from(ESLINT_DISABLE_COMMENT)
+        + (if *context.code_type == data::CodeType::JavaScriptWithJsDoc {
+            TS_CHECK_COMMENT
+        } else {
+            ""
+        })
+        + &match context.module_format {
+            data::ModuleFormat::EsModule | data::ModuleFormat::CommonJs => {
+                import_list_to_string(&module_name_list, context) + &body
+            }
+            data::ModuleFormat::Umd(global_name) => {
+                umd_to_string(&module_name_list, global_name, &body, context)
+            }
+        }
+}
+
+/// default export 以外で 値を export しているかどうか
+pub(crate) fn has_named_value_export(code: &data::Code) -> bool {
+    code.export_definition_list
+        .iter()
+        .any(|export_definition| match export_definition {
+            data::ExportDefinition::Function(function) => {
+                function.visibility == data::Visibility::Export
+            }
+            data::ExportDefinition::Variable(variable) => {
+                variable.visibility == data::Visibility::Export
+            }
+            data::ExportDefinition::ExportFrom(export_from) => !export_from.is_type_only,
+            data::ExportDefinition::ExportAll(_) => true,
+            data::ExportDefinition::TypeAlias(_) | data::ExportDefinition::Default(_) => false,
+        })
+}
+
+fn has_private_type_alias(code: &data::Code) -> bool {
+    code.export_definition_list
+        .iter()
+        .any(|export_definition| match export_definition {
+            data::ExportDefinition::TypeAlias(type_alias) => {
+                type_alias.visibility == data::Visibility::Private
+            }
+            data::ExportDefinition::Function(_)
+            | data::ExportDefinition::Variable(_)
+            | data::ExportDefinition::ExportFrom(_)
+            | data::ExportDefinition::ExportAll(_)
+            | data::ExportDefinition::Default(_) => false,
+        })
+}
+
+/// インポートするモジュールのリスト.
+/// JavaScript では型は出力されないので, 型でのみ使われているモジュールはインポートしない.
+/// JSDoc の型では `import("express").Request` のように参照するので, こちらもインポートしない.
+/// 宣言ファイルでは関数の本体などは出力されないので, シグネチャで使われているモジュールのみインポートする
+fn import_module_name_list(code: &data::Code, context: &Context) -> Vec<String> {
+    let reference_list = match context.code_type {
+        data::CodeType::Declaration => collect::declaration_reference_list(code),
+        data::CodeType::JavaScript
+        | data::CodeType::JavaScriptWithJsDoc
+        | data::CodeType::TypeScript => collect::reference_list(code),
+    };
+    let mut module_name_list: Vec<String> = Vec::new();
+    for reference in reference_list {
+        let module_name = match reference {
+            collect::Reference::ImportedVariable(imported_variable) => {
+                Some(imported_variable.module_name.clone())
+            }
+            collect::Reference::ImportedType(imported_type) => match context.code_type {
+                data::CodeType::JavaScript | data::CodeType::JavaScriptWithJsDoc => None,
+                data::CodeType::TypeScript | data::CodeType::Declaration => {
+                    Some(imported_type.module_name.clone())
+                }
+            },
+            collect::Reference::ProjectVariable(project_reference) => {
+                project_reference_module_name(project_reference, context)
+            }
+            collect::Reference::ProjectType(project_reference) => match context.code_type {
+                data::CodeType::JavaScript | data::CodeType::JavaScriptWithJsDoc => None,
+                data::CodeType::TypeScript | data::CodeType::Declaration => {
+                    project_reference_module_name(project_reference, context)
+                }
+            },
+        };
+        if let Some(module_name) = module_name {
+            if !module_name_list.contains(&module_name) {
+                module_name_list.push(module_name);
+            }
+        }
+    }
+    // ES Modules 以外では `export ... from` が使えないので, インポートしてから `exports` に代入する
+    if *context.module_format != data::ModuleFormat::EsModule {
+        for export_definition in &code.export_definition_list {
+            let module_name = match export_definition {
+                data::ExportDefinition::ExportFrom(export_from) if !export_from.is_type_only => {
+                    &export_from.module_name
+                }
+                data::ExportDefinition::ExportAll(export_all) => &export_all.module_name,
+                _ => continue,
+            };
+            if !module_name_list.contains(module_name) {
+                module_name_list.push(module_name.clone());
+            }
+        }
+    }
+    module_name_list
+}
+
+/// モジュールをインポートする
+///
+/// ```ts
+/// import * as $$$express from "express";
+/// ```
+///
+/// ```js
+/// const $$$express = require("express");
+/// ```
+fn import_list_to_string(module_name_list: &[String], context: &Context) -> String {
+    module_name_list
+        .iter()
+        .map(|module_name| match context.module_format {
+            data::ModuleFormat::CommonJs => {
+                String::from("const ")
+                    + &module_name_to_identifer(module_name)
+                    + " = require("
+                    + &string_literal_value_to_string(module_name, context.is_ascii_only)
+                    + ");\n"
+            }
+            data::ModuleFormat::EsModule | data::ModuleFormat::Umd(_) => {
+                String::from("import * as ")
+                    + &module_name_to_identifer(module_name)
+                    + " from "
+                    + &string_literal_value_to_string(module_name, context.is_ascii_only)
+                    + ";\n"
+            }
         })
+        .collect::<String>()
+}
+
+/// UMD の形式で囲む. インポートしたモジュールは factory の引数で受け取る
+///
+/// ```js
+/// (function (root, factory) {
+///   if (typeof define === "function" && define.amd) {
+///     define(["exports", "express"], factory);
+///   } else if (typeof exports === "object") {
+///     factory(exports, require("express"));
+///   } else {
+///     factory((root["sample"] = {}), root["express"]);
+///   }
+/// })(typeof self !== "undefined" ? self : this, function (exports, $$$express) {
+///   ...
+/// });
+/// ```
+fn umd_to_string(
+    module_name_list: &[String],
+    global_name: &str,
+    body: &str,
+    context: &Context,
+) -> String {
+    let module_name_literal_list = module_name_list
+        .iter()
+        .map(|module_name| string_literal_value_to_string(module_name, context.is_ascii_only))
+        .collect::<Vec<String>>();
+    String::from("(function (root, factory) {\n")
+        + "  if (typeof define === \"function\" && define.amd) {\n"
+        + "    define(["
+        + &std::iter::once(String::from("\"exports\""))
+            .chain(module_name_literal_list.iter().cloned())
+            .collect::<Vec<String>>()
+            .join(", ")
+        + "], factory);\n"
+        + "  } else if (typeof exports === \"object\") {\n"
+        + "    factory("
+        + &std::iter::once(String::from("exports"))
+            .chain(
+                module_name_literal_list
+                    .iter()
+                    .map(|module_name| String::from("require(") + module_name + ")"),
+            )
+            .collect::<Vec<String>>()
+            .join(", ")
+        + ");\n"
+        + "  } else {\n"
+        + "    factory("
+        + &std::iter::once(
+            String::from("(root[")
+                + &string_literal_value_to_string(global_name, context.is_ascii_only)
+                + "] = {})",
+        )
+        .chain(
+            module_name_literal_list
+                .iter()
+                .map(|module_name| String::from("root[") + module_name + "]"),
+        )
+        .collect::<Vec<String>>()
+        .join(", ")
+        + ");\n"
+        + "  }\n"
+        + "})(typeof self !== \"undefined\" ? self : this, function ("
+        + &std::iter::once(String::from("exports"))
+            .chain(
+                module_name_list
+                    .iter()
+                    .map(|module_name| module_name_to_identifer(module_name)),
+            )
+            .collect::<Vec<String>>()
+            .join(", ")
+        + ") {\n"
+        + &body
+            .lines()
+            .map(|line| {
+                if line.is_empty() {
+                    String::new()
+                } else {
+                    String::from("  ") + line
+                }
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+        + "\n});\n"
+}
+
+/// トップレベルの定義の始まり. ES Modules 以外では ただの変数定義にして, 後から `exports` に代入する
+fn export_const_keyword(visibility: &data::Visibility, context: &Context) -> &'static str {
+    match (visibility, context.module_format) {
+        (data::Visibility::Export, data::ModuleFormat::EsModule) => "export const ",
+        (data::Visibility::Export, data::ModuleFormat::CommonJs)
+        | (data::Visibility::Export, data::ModuleFormat::Umd(_))
+        | (data::Visibility::Private, _) => "const ",
+    }
+}
+
+/// 関数の定義の書き方. 関数ごとの指定がなければ `GenerateOption` の指定を使う
+fn function_style<'a>(
+    style: &'a Option<data::FunctionStyle>,
+    context: &Context<'a>,
+) -> &'a data::FunctionStyle {
+    style.as_ref().unwrap_or(context.function_style)
+}
+
+/// 関数宣言で書いたトップレベルの関数の定義の始まり
+fn export_function_keyword(visibility: &data::Visibility, context: &Context) -> &'static str {
+    match (visibility, context.module_format) {
+        (data::Visibility::Export, data::ModuleFormat::EsModule) => "export ",
+        (data::Visibility::Export, data::ModuleFormat::CommonJs)
+        | (data::Visibility::Export, data::ModuleFormat::Umd(_))
+        | (data::Visibility::Private, _) => "",
+    }
+}
+
+/// 関数宣言で書いたトップレベルの関数の定義の終わり
+///
+/// ```js
+/// exports.name = name;
+/// ```
+fn export_function_end(
+    name: &data::identifer::Identifer,
+    visibility: &data::Visibility,
+    context: &Context,
+) -> String {
+    match (visibility, context.module_format) {
+        (data::Visibility::Export, data::ModuleFormat::CommonJs)
+        | (data::Visibility::Export, data::ModuleFormat::Umd(_)) => {
+            String::from("\nexports.") + &name.get() + " = " + &name.get() + ";\n\n"
+        }
+        (data::Visibility::Export, data::ModuleFormat::EsModule)
+        | (data::Visibility::Private, _) => String::from("\n\n"),
+    }
+}
+
+/// トップレベルの定義の終わり
+///
+/// ```js
+/// exports.name = name;
+/// ```
+fn export_const_end(
+    name: &data::identifer::Identifer,
+    visibility: &data::Visibility,
+    context: &Context,
+) -> String {
+    match (visibility, context.module_format) {
+        (data::Visibility::Export, data::ModuleFormat::CommonJs)
+        | (data::Visibility::Export, data::ModuleFormat::Umd(_)) => {
+            String::from(";\nexports.") + &name.get() + " = " + &name.get() + ";\n\n"
+        }
+        (data::Visibility::Export, data::ModuleFormat::EsModule)
+        | (data::Visibility::Private, _) => String::from(";\n\n"),
+    }
+}
+
+/// インポートしたモジュールにつける識別子. 識別子に使えない文字はエスケープされる
+///
+/// `express` → `$$$express`
+fn module_name_to_identifer(module_name: &str) -> String {
+    String::from("$$$")
+        + &module_name
+            .chars()
+            .map(|char| {
+                if char.is_ascii_alphanumeric() || char == '_' {
+                    char.to_string()
+                } else {
+                    format!("${:x}", char as u32)
+                }
+            })
+            .collect::<String>()
+}
+
+/// `ProjectReference` の参照先のモジュール名. 出力しているファイル自身を参照していたら `None`
+fn project_reference_module_name(
+    project_reference: &data::ProjectReference,
+    context: &Context,
+) -> Option<String> {
+    match context.file_path {
+        Some(file_path) if file_path == project_reference.file_path => None,
+        Some(file_path) => Some(project::relative_module_name(
+            file_path,
+            &project_reference.file_path,
+        )),
+        None => Some(project_reference.file_path.clone()),
+    }
+}
+
+/// `ProjectReference` を 式や型の中で使うときの文字列
+fn project_reference_to_string(
+    project_reference: &data::ProjectReference,
+    context: &Context,
+) -> String {
+    match project_reference_module_name(project_reference, context) {
+        Some(module_name) => {
+            module_name_to_identifer(&module_name) + "." + &project_reference.name.get()
+        }
+        None => project_reference.name.get(),
+    }
+}
+
+/// JSDoc の型の中で 他のモジュールの型を参照するときの文字列. インポートせずに使える
+///
+/// `import("express").Request`
+fn import_type_to_string(
+    module_name: &str,
+    name: &data::identifer::Identifer,
+    context: &Context,
+) -> String {
+    String::from("import(")
+        + &string_literal_value_to_string(module_name, context.is_ascii_only)
+        + ")."
+        + &name.get()
 }
 
 fn export_definition_to_string(
     export_definition: &data::ExportDefinition,
-    code_type: &data::CodeType,
+    context: &Context,
 ) -> String {
     match export_definition {
-        data::ExportDefinition::TypeAlias(type_alias) => match &code_type {
+        data::ExportDefinition::TypeAlias(type_alias) => match context.code_type {
             data::CodeType::JavaScript => String::new(),
-            data::CodeType::TypeScript => type_alias_to_string(type_alias),
+            data::CodeType::JavaScriptWithJsDoc => typedef_to_string(type_alias, context),
+            data::CodeType::TypeScript | data::CodeType::Declaration => {
+                type_alias_to_string(type_alias, context)
+            }
         },
-        data::ExportDefinition::Function(function) => {
-            export_function_to_string(function, code_type)
+        // 宣言ファイルでは 外部に公開しない値は出力しない
+        data::ExportDefinition::Function(function) => match context.code_type {
+            data::CodeType::Declaration => match function.visibility {
+                data::Visibility::Export => declare_function_to_string(function, context),
+                data::Visibility::Private => String::new(),
+            },
+            data::CodeType::JavaScript
+            | data::CodeType::JavaScriptWithJsDoc
+            | data::CodeType::TypeScript => export_function_to_string(function, context),
+        },
+        data::ExportDefinition::Variable(variable) => match context.code_type {
+            data::CodeType::Declaration => match variable.visibility {
+                data::Visibility::Export => declare_variable_to_string(variable, context),
+                data::Visibility::Private => String::new(),
+            },
+            data::CodeType::JavaScript
+            | data::CodeType::JavaScriptWithJsDoc
+            | data::CodeType::TypeScript => export_variable_to_string(variable, context),
+        },
+        data::ExportDefinition::ExportFrom(export_from) => {
+            export_from_to_string(export_from, context)
+        }
+        data::ExportDefinition::ExportAll(export_all) => export_all_to_string(export_all, context),
+        data::ExportDefinition::Default(default_export) => {
+            default_export_to_string(default_export, context)
+        }
+    }
+}
+
+/// ```ts
+/// export default expr;
+/// ```
+///
+/// ```js
+/// exports.default = expr;
+/// module.exports = expr;
+/// ```
+///
+/// CommonJS でどちらに代入するかは `GenerateOption` の `common_js_default_export` で指定する.
+///
+/// クラス式は `export default class A {}` のように クラス宣言として出力する.
+/// 宣言ファイルでも `type` を使わずに メンバーの型を出力する
+fn default_export_to_string(default_export: &data::DefaultExport, context: &Context) -> String {
+    if let data::Expr::Class(class_expr) = &default_export.expr {
+        let class = class_expr_to_string(class_expr, &Indent::zero(), context);
+        return document_to_string(&default_export.document)
+            + &match (context.code_type, context.module_format) {
+                (data::CodeType::Declaration, _) | (_, data::ModuleFormat::EsModule) => {
+                    String::from("export default ") + &class + "\n"
+                }
+                (_, data::ModuleFormat::CommonJs) if context.is_default_module_exports => {
+                    String::from("module.exports = ") + &class + ";\n"
+                }
+                (_, data::ModuleFormat::CommonJs) | (_, data::ModuleFormat::Umd(_)) => {
+                    String::from("exports.default = ") + &class + ";\n"
+                }
+            };
+    }
+    let expr = match context.code_type {
+        data::CodeType::JavaScriptWithJsDoc => {
+            inline_type_document(&default_export.r#type, context)
+                + "("
+                + &assignment_expr_to_string(&default_export.expr, &Indent::zero(), context)
+                + ")"
+        }
+        data::CodeType::JavaScript | data::CodeType::TypeScript | data::CodeType::Declaration => {
+            expr_to_string_in(
+                precedence::Position::DefaultExport,
+                &default_export.expr,
+                &Indent::zero(),
+                context,
+            )
+        }
+    };
+    document_to_string(&default_export.document)
+        + &match (context.code_type, context.module_format) {
+            // 宣言ファイルでは式を書けないので, 型をつけた変数を宣言して export する
+            (data::CodeType::Declaration, _) => {
+                String::from("declare const $default")
+                    + &type_annotation(&default_export.r#type, context)
+                    + ";\nexport default $default;\n"
+            }
+            (_, data::ModuleFormat::EsModule) => String::from("export default ") + &expr + ";\n",
+            (_, data::ModuleFormat::CommonJs) if context.is_default_module_exports => {
+                String::from("module.exports = ") + &expr + ";\n"
+            }
+            (_, data::ModuleFormat::CommonJs) | (_, data::ModuleFormat::Umd(_)) => {
+                String::from("exports.default = ") + &expr + ";\n"
+            }
+        }
+}
+
+/// ```ts
+/// export { a, b as c } from "./x";
+/// export type { T } from "./x";
+/// ```
+///
+/// ```js
+/// exports.a = $$$$2e$2fx.a;
+/// exports.c = $$$$2e$2fx.b;
+/// ```
+fn export_from_to_string(export_from: &data::ExportFrom, context: &Context) -> String {
+    match (
+        export_from.is_type_only,
+        context.code_type,
+        context.module_format,
+    ) {
+        (true, data::CodeType::JavaScript, _) | (true, data::CodeType::JavaScriptWithJsDoc, _) => {
+            String::new()
+        }
+        (_, _, data::ModuleFormat::EsModule) => {
+            String::from(if export_from.is_type_only {
+                "export type { "
+            } else {
+                "export { "
+            }) + &export_from
+                .name_list
+                .iter()
+                .map(|export_name| match &export_name.alias {
+                    Some(alias) => export_name.name.get() + " as " + &alias.get(),
+                    None => export_name.name.get(),
+                })
+                .collect::<Vec<String>>()
+                .join(", ")
+                + " } from "
+                + &string_literal_value_to_string(&export_from.module_name, context.is_ascii_only)
+                + ";\n"
+        }
+        (_, _, data::ModuleFormat::CommonJs) | (_, _, data::ModuleFormat::Umd(_)) => export_from
+            .name_list
+            .iter()
+            .map(|export_name| {
+                String::from("exports.")
+                    + &export_name
+                        .alias
+                        .as_ref()
+                        .unwrap_or(&export_name.name)
+                        .get()
+                    + " = "
+                    + &module_name_to_identifer(&export_from.module_name)
+                    + "."
+                    + &export_name.name.get()
+                    + ";\n"
+            })
+            .collect::<String>(),
+    }
+}
+
+/// ```ts
+/// export * from "./x";
+/// export * as ns from "./x";
+/// ```
+///
+/// ```js
+/// for (const key of Object.keys($$$$2e$2fx)) {
+///   if (key !== "default" && key !== "__esModule" && !Object.prototype.hasOwnProperty.call(exports, key)) {
+///     exports[key] = $$$$2e$2fx[key];
+///   }
+/// }
+/// exports.ns = $$$$2e$2fx;
+/// ```
+///
+/// `export *` は default を export しないので, CommonJS では `default` と `__esModule` を除いてコピーする.
+/// すでに export されている名前は上書きしない
+fn export_all_to_string(export_all: &data::ExportAll, context: &Context) -> String {
+    match (context.module_format, &export_all.namespace) {
+        (data::ModuleFormat::EsModule, Some(namespace)) => {
+            String::from("export * as ")
+                + &namespace.get()
+                + " from "
+                + &string_literal_value_to_string(&export_all.module_name, context.is_ascii_only)
+                + ";\n"
+        }
+        (data::ModuleFormat::EsModule, None) => {
+            String::from("export * from ")
+                + &string_literal_value_to_string(&export_all.module_name, context.is_ascii_only)
+                + ";\n"
         }
-        data::ExportDefinition::Variable(variable) => {
-            export_variable_to_string(variable, code_type)
+        (data::ModuleFormat::CommonJs, Some(namespace))
+        | (data::ModuleFormat::Umd(_), Some(namespace)) => {
+            String::from("exports.")
+                + &namespace.get()
+                + " = "
+                + &module_name_to_identifer(&export_all.module_name)
+                + ";\n"
+        }
+        (data::ModuleFormat::CommonJs, None) | (data::ModuleFormat::Umd(_), None) => {
+            let module_identifer = module_name_to_identifer(&export_all.module_name);
+            String::from("for (const key of Object.keys(")
+                + &module_identifer
+                + ")) {\n"
+                + "  if (key !== \"default\" && key !== \"__esModule\" && !Object.prototype.hasOwnProperty.call(exports, key)) {\n"
+                + "    exports[key] = "
+                + &module_identifer
+                + "[key];\n"
+                + "  }\n"
+                + "}\n"
         }
     }
 }
 
-fn type_alias_to_string(type_alias: &data::TypeAlias) -> String {
-    document_to_string(&type_alias.document)
-        + "export type "
-        + &type_alias.name.get()
+fn type_alias_to_string(type_alias: &data::TypeAlias, context: &Context) -> String {
+    document_to_string(
+        &(String::new()
+            + &type_alias.document
+            + &document_tag_list_to_document(&type_alias.document_tag_list, None, context)),
+    ) + (match type_alias.visibility {
+        data::Visibility::Export => "export type ",
+        data::Visibility::Private => "type ",
+    }) + &type_alias.name.get()
         + &type_parameter_list_to_string(
             &type_alias.type_parameter_list,
             &data::CodeType::TypeScript,
         )
         + " = "
-        + &type_to_string(&type_alias.r#type)
+        + &type_to_string(&type_alias.r#type, context)
         + ";\n\n"
 }
 
-fn export_function_to_string(function: &data::Function, code_type: &data::CodeType) -> String {
+/// JSDoc の `@typedef` で型を定義する
+///
+/// ```js
+/// /**
+///  * @template T
+///  * @typedef {{ readonly value: T }} Wrapper
+///  */
+/// ```
+fn typedef_to_string(type_alias: &data::TypeAlias, context: &Context) -> String {
+    document_to_string(
+        &(String::new()
+            + &type_alias.document
+            + &document_tag_list_to_document(&type_alias.document_tag_list, None, context)
+            + &type_parameter_list_to_document(&type_alias.type_parameter_list)
+            + "\n@typedef {"
+            + &type_to_string(&type_alias.r#type, context)
+            + "} "
+            + &type_alias.name.get()),
+    ) + "\n"
+}
+
+fn export_function_to_string(function: &data::Function, context: &Context) -> String {
+    let parameter_list = function
+        .parameter_list
+        .iter()
+        .map(|parameter| parameter_with_document_to_string(parameter, &Indent::zero(), context))
+        .collect::<Vec<String>>();
+    let head = FunctionHead {
+        is_async: function.is_async,
+        is_generator: function.is_generator,
+        type_parameter_list: &function.type_parameter_list,
+        parameter_list: &parameter_list,
+        return_type: &function.return_type,
+    };
+    function_document_to_string(function, context)
+        + &match function_style(&function.style, context) {
+            data::FunctionStyle::Arrow => {
+                String::from(export_const_keyword(&function.visibility, context))
+                    + &function.name.get()
+                    + " = "
+                    + &function_expr_to_string(
+                        &head,
+                        &function.statement_list,
+                        &Indent::zero(),
+                        context,
+                    )
+                    + &export_const_end(&function.name, &function.visibility, context)
+            }
+            data::FunctionStyle::Declaration => {
+                String::from(export_function_keyword(&function.visibility, context))
+                    + &function_declaration_to_string(
+                        &function.name,
+                        &head,
+                        &function.statement_list,
+                        &Indent::zero(),
+                        context,
+                    )
+                    + &export_function_end(&function.name, &function.visibility, context)
+            }
+        }
+}
+
+fn export_variable_to_string(variable: &data::Variable, context: &Context) -> String {
     document_to_string(
         &(String::new()
-            + &function.document
-            + &parameter_list_to_document(&function.parameter_list)),
-    ) + "export const "
-        + &function.name.get()
+            + &variable.document
+            + &document_tag_list_to_document(&variable.document_tag_list, None, context)
+            + &type_to_document(&variable.r#type, context)),
+    ) + export_const_keyword(&variable.visibility, context)
+        + &variable.name.get()
+        + &type_annotation(&variable.r#type, context)
         + " = "
-        + &type_parameter_list_to_string(&function.type_parameter_list, code_type)
-        + "("
-        + &function
-            .parameter_list
-            .iter()
-            .map(|parameter| parameter.name.get() + &type_annotation(&parameter.r#type, code_type))
-            .collect::<Vec<String>>()
-            .join(", ")
-        + ")"
-        + &type_annotation(&function.return_type, code_type)
-        + " => "
-        + &lambda_body_to_string(&function.statement_list, &Indent::zero(), code_type)
+        + &assignment_expr_to_string(&variable.expr, &Indent::zero(), context)
+        + &export_const_end(&variable.name, &variable.visibility, context)
+}
+
+/// 宣言ファイルでの関数の宣言. 関数の本体は出力しない
+///
+/// ```ts
+/// export declare const f: <T extends unknown>(a: A) => R;
+/// ```
+fn declare_function_to_string(function: &data::Function, context: &Context) -> String {
+    let type_parameter_and_parameter_list =
+        type_parameter_list_to_string(&function.type_parameter_list, context.code_type)
+            + "("
+            + &function
+                .parameter_list
+                .iter()
+                .map(|parameter| {
+                    parameter_with_document_to_string(parameter, &Indent::zero(), context)
+                })
+                .collect::<Vec<String>>()
+                .join(", ")
+            + ")";
+    function_document_to_string(function, context)
+        + &match function_style(&function.style, context) {
+            data::FunctionStyle::Arrow => {
+                String::from("export declare const ")
+                    + &function.name.get()
+                    + ": "
+                    + &type_parameter_and_parameter_list
+                    + " => "
+            }
+            // `export declare function f<T extends unknown>(a: A): R;`
+            data::FunctionStyle::Declaration => {
+                String::from("export declare function ")
+                    + &function.name.get()
+                    + &type_parameter_and_parameter_list
+                    + ": "
+            }
+        }
+        + &type_to_string(&function.return_type, context)
         + ";\n\n"
 }
 
-fn export_variable_to_string(variable: &data::Variable, code_type: &data::CodeType) -> String {
-    document_to_string(&variable.document)
-        + "export const "
+/// 宣言ファイルでの変数の宣言. 式は出力しない
+///
+/// ```ts
+/// export declare const v: T;
+/// ```
+fn declare_variable_to_string(variable: &data::Variable, context: &Context) -> String {
+    document_to_string(
+        &(String::new()
+            + &variable.document
+            + &document_tag_list_to_document(&variable.document_tag_list, None, context)),
+    ) + "export declare const "
         + &variable.name.get()
-        + &type_annotation(&variable.r#type, code_type)
-        + " = "
-        + &expr_to_string(&variable.expr, &Indent::zero(), code_type)
+        + &type_annotation(&variable.r#type, context)
         + ";\n\n"
 }
 
+/// ドキュメントコメント. ドキュメントの中の `*/` はコメントを閉じないように `*\/` にエスケープする
 fn document_to_string(document: &str) -> String {
-    let trimmed = document.trim();
+    let escaped = document.replace("*/", "*\\/");
+    let trimmed = escaped.trim();
     if trimmed.is_empty() {
         String::new()
     } else {
@@ -121,50 +851,275 @@ fn document_to_string(document: &str) -> String {
     }
 }
 
-fn parameter_list_to_document(parameter_list: &Vec<data::ParameterWithDocument>) -> String {
-    if parameter_list.is_empty() {
-        String::new()
-    } else {
-        String::from("\n")
-            + &parameter_list
-                .iter()
-                .map(|parameter| {
-                    if parameter.document.is_empty() {
-                        String::new()
-                    } else {
-                        String::from("@param ") + &parameter.name.get() + " " + &parameter.document
-                    }
-                })
-                .collect::<Vec<String>>()
-                .join("\n")
-    }
+fn parameter_with_document_to_string(
+    parameter: &data::ParameterWithDocument,
+    indent: &Indent,
+    context: &Context,
+) -> String {
+    parameter_to_string(
+        &parameter.name.get(),
+        &parameter.r#type,
+        &parameter.kind,
+        indent,
+        context,
+    )
 }
 
-/// 型パラメーターを文字列にする
-///
-/// - `<T extends unknown>`
-/// - `<ok extends unknown, error extends unknown>`
-///
+/// パラメーターを文字列にする
 ///
-/// extends unknown をつけた理由はJSXでも解釈できるようにするため
-fn type_parameter_list_to_string(
-    type_parameter_list: &Vec<data::identifer::Identifer>,
-    code_type: &data::CodeType,
+/// - `x: T`
+/// - `x?: T`
+/// - `x: T = expr`. 宣言ファイルでは `x?: T`
+/// - `...x: T`
+fn parameter_to_string(
+    name: &str,
+    r#type: &data::Type,
+    kind: &data::ParameterKind,
+    indent: &Indent,
+    context: &Context,
 ) -> String {
-    if *code_type == data::CodeType::JavaScript || type_parameter_list.is_empty() {
-        String::new()
-    } else {
-        String::from("<")
-            + &type_parameter_list
-                .iter()
-                .map(|type_parameter| type_parameter.get() + " extends unknown")
-                .collect::<Vec<String>>()
-                .join(", ")
-            + ">"
+    match kind {
+        data::ParameterKind::Required => String::from(name) + &type_annotation(r#type, context),
+        data::ParameterKind::Optional => {
+            String::from(name) + &optional_mark(context) + &type_annotation(r#type, context)
+        }
+        data::ParameterKind::Default(_) if *context.code_type == data::CodeType::Declaration => {
+            String::from(name) + "?" + &type_annotation(r#type, context)
+        }
+        data::ParameterKind::Default(default_expr) => {
+            String::from(name)
+                + &type_annotation(r#type, context)
+                + " = "
+                + &assignment_expr_to_string(default_expr, indent, context)
+        }
+        data::ParameterKind::Rest => String::from("...") + name + &type_annotation(r#type, context),
     }
 }
 
-fn type_to_string(r#type: &data::Type) -> String {
+/// TypeScript で省略可能なパラメーターにつける `?`
+fn optional_mark(context: &Context) -> String {
+    match context.code_type {
+        data::CodeType::JavaScript | data::CodeType::JavaScriptWithJsDoc => String::new(),
+        data::CodeType::TypeScript | data::CodeType::Declaration => String::from("?"),
+    }
+}
+
+/// 関数のドキュメント. JSDoc で型を表現する場合は 型パラメーターと戻り値の型も含める
+fn function_document_to_string(function: &data::Function, context: &Context) -> String {
+    document_to_string(
+        &(String::new()
+            + &function.document
+            + &(if *context.code_type == data::CodeType::JavaScriptWithJsDoc {
+                type_parameter_list_to_document(&function.type_parameter_list)
+            } else {
+                String::new()
+            })
+            + &parameter_list_to_document(&function.parameter_list, context)
+            + &(if function
+                .document_tag_list
+                .iter()
+                .any(|tag| matches!(tag, data::DocumentTag::Returns(_)))
+            {
+                String::new()
+            } else {
+                return_type_to_document(&function.return_type, context)
+            })
+            + &document_tag_list_to_document(
+                &function.document_tag_list,
+                Some(&function.return_type),
+                context,
+            )),
+    )
+}
+
+/// ドキュメントのタグ. 関数の場合は `return_type` を指定して,
+/// JSDoc で型を表現する場合は `@returns {Type} 説明` のように戻り値の型も出力する
+fn document_tag_list_to_document(
+    tag_list: &[data::DocumentTag],
+    return_type: Option<&data::Type>,
+    context: &Context,
+) -> String {
+    tag_list
+        .iter()
+        .map(|tag| match tag {
+            data::DocumentTag::Deprecated(reason) => tag_to_document("@deprecated", reason),
+            data::DocumentTag::Example(code) => {
+                String::from("\n@example\n```")
+                    + (match context.code_type {
+                        data::CodeType::TypeScript | data::CodeType::Declaration => "ts",
+                        data::CodeType::JavaScript | data::CodeType::JavaScriptWithJsDoc => "js",
+                    })
+                    + "\n"
+                    + code.trim_start_matches('\n').trim_end()
+                    + "\n```"
+            }
+            data::DocumentTag::See(reference) => tag_to_document("@see", reference),
+            data::DocumentTag::Returns(description) => match return_type {
+                Some(return_type) if *context.code_type == data::CodeType::JavaScriptWithJsDoc => {
+                    tag_to_document(
+                        &(String::from("@returns {") + &type_to_string(return_type, context) + "}"),
+                        description,
+                    )
+                }
+                _ => tag_to_document("@returns", description),
+            },
+            data::DocumentTag::Throws(description) => tag_to_document("@throws", description),
+            data::DocumentTag::Since(version) => tag_to_document("@since", version),
+        })
+        .collect()
+}
+
+/// `@tag 説明`. 説明が空なら `@tag` だけにする
+fn tag_to_document(tag: &str, text: &str) -> String {
+    if text.trim().is_empty() {
+        String::from("\n") + tag
+    } else {
+        String::from("\n") + tag + " " + text.trim()
+    }
+}
+
+/// 引数のドキュメント. JSDoc で型を表現する場合は ドキュメントがなくても `@param {Type} name` を出力する
+fn parameter_list_to_document(
+    parameter_list: &[data::ParameterWithDocument],
+    context: &Context,
+) -> String {
+    if parameter_list.is_empty() {
+        String::new()
+    } else if *context.code_type == data::CodeType::JavaScriptWithJsDoc {
+        parameter_list
+            .iter()
+            .map(|parameter| {
+                String::from("\n@param {")
+                    + &type_to_string(&parameter.r#type, context)
+                    + "} "
+                    + &(match parameter.kind {
+                        data::ParameterKind::Optional | data::ParameterKind::Default(_) => {
+                            String::from("[") + &parameter.name.get() + "]"
+                        }
+                        data::ParameterKind::Required | data::ParameterKind::Rest => {
+                            parameter.name.get()
+                        }
+                    })
+                    + (if parameter.document.is_empty() {
+                        ""
+                    } else {
+                        " "
+                    })
+                    + &parameter.document
+            })
+            .collect::<String>()
+    } else {
+        String::from("\n")
+            + &parameter_list
+                .iter()
+                .map(|parameter| {
+                    if parameter.document.is_empty() {
+                        String::new()
+                    } else {
+                        String::from("@param ") + &parameter.name.get() + " " + &parameter.document
+                    }
+                })
+                .collect::<Vec<String>>()
+                .join("\n")
+    }
+}
+
+/// JSDoc で型を表現する場合の ラムダ式やメソッドにつける `/** @param {number} a @returns {string} */ ` のような型のコメント.
+/// 分割代入のパラメーターは名前がないので, 関数の型と同じように `$0` で表現する. TypeScript は位置で対応させる
+fn function_expr_document(
+    type_parameter_list: &[data::identifer::Identifer],
+    parameter_list: &[data::Parameter],
+    return_type: Option<&data::Type>,
+    context: &Context,
+) -> String {
+    if *context.code_type != data::CodeType::JavaScriptWithJsDoc {
+        return String::new();
+    }
+    let tag_list = type_parameter_list
+        .iter()
+        .map(|type_parameter| String::from("@template ") + &type_parameter.get())
+        .chain(parameter_list.iter().enumerate().map(|(index, parameter)| {
+            let name = match &parameter.pattern {
+                data::BindingPattern::Identifer(identifer) => identifer.get(),
+                data::BindingPattern::Object(_) | data::BindingPattern::Array(_) => {
+                    format!("${}", index)
+                }
+            };
+            String::from("@param {")
+                + &type_to_string(&parameter.r#type, context)
+                + "} "
+                + &(match parameter.kind {
+                    data::ParameterKind::Optional | data::ParameterKind::Default(_) => {
+                        String::from("[") + &name + "]"
+                    }
+                    data::ParameterKind::Required | data::ParameterKind::Rest => name,
+                })
+        }))
+        .chain(return_type.map(|return_type| {
+            String::from("@returns {") + &type_to_string(return_type, context) + "}"
+        }))
+        .collect::<Vec<String>>();
+    if tag_list.is_empty() {
+        String::new()
+    } else {
+        String::from("/** ") + &tag_list.join(" ") + " */ "
+    }
+}
+
+/// JSDoc で型を表現する場合の `@template T`
+fn type_parameter_list_to_document(type_parameter_list: &[data::identifer::Identifer]) -> String {
+    type_parameter_list
+        .iter()
+        .map(|type_parameter| String::from("\n@template ") + &type_parameter.get())
+        .collect::<String>()
+}
+
+/// JSDoc で型を表現する場合の `@returns {Type}`
+fn return_type_to_document(return_type: &data::Type, context: &Context) -> String {
+    if *context.code_type == data::CodeType::JavaScriptWithJsDoc {
+        String::from("\n@returns {") + &type_to_string(return_type, context) + "}"
+    } else {
+        String::new()
+    }
+}
+
+/// JSDoc で型を表現する場合の `@type {Type}`
+fn type_to_document(r#type: &data::Type, context: &Context) -> String {
+    if *context.code_type == data::CodeType::JavaScriptWithJsDoc {
+        String::from("\n@type {") + &type_to_string(r#type, context) + "}"
+    } else {
+        String::new()
+    }
+}
+
+/// 型パラメーターを文字列にする
+///
+/// - `<T extends unknown>`
+/// - `<ok extends unknown, error extends unknown>`
+///
+///
+/// extends unknown をつけた理由はJSXでも解釈できるようにするため
+fn type_parameter_list_to_string(
+    type_parameter_list: &[data::identifer::Identifer],
+    code_type: &data::CodeType,
+) -> String {
+    if *code_type == data::CodeType::JavaScript
+        || *code_type == data::CodeType::JavaScriptWithJsDoc
+        || type_parameter_list.is_empty()
+    {
+        String::new()
+    } else {
+        String::from("<")
+            + &type_parameter_list
+                .iter()
+                .map(|type_parameter| type_parameter.get() + " extends unknown")
+                .collect::<Vec<String>>()
+                .join(", ")
+            + ">"
+    }
+}
+
+fn type_to_string(r#type: &data::Type, context: &Context) -> String {
     match r#type {
         data::Type::Number => String::from("number"),
 
@@ -180,24 +1135,24 @@ fn type_to_string(r#type: &data::Type) -> String {
 
         data::Type::Undefined => String::from("undefined"),
 
-        data::Type::Object(member_list) => type_object_to_string(&**member_list),
+        data::Type::Object(member_list) => type_object_to_string(member_list, context),
 
-        data::Type::Function(function_type) => type_function_to_string(function_type),
+        data::Type::Function(function_type) => type_function_to_string(function_type, context),
 
         data::Type::Union(type_list) => type_list
             .iter()
-            .map(|pattern| type_to_string(pattern))
+            .map(|r#type| type_to_string(r#type, context))
             .collect::<Vec<String>>()
             .join(" | "),
 
         data::Type::Intersection(intersection_type) => {
-            type_to_string(&intersection_type.left)
+            type_to_string(&intersection_type.left, context)
                 + " & "
-                + &type_to_string(&intersection_type.right)
+                + &type_to_string(&intersection_type.right, context)
         }
 
         data::Type::WithTypeParameter(type_with_parameter) => {
-            type_to_string(&type_with_parameter.r#type)
+            type_to_string(&type_with_parameter.r#type, context)
                 + &(if type_with_parameter.type_parameter_list.is_empty() {
                     String::new()
                 } else {
@@ -205,7 +1160,7 @@ fn type_to_string(r#type: &data::Type) -> String {
                         + &type_with_parameter
                             .type_parameter_list
                             .iter()
-                            .map(type_to_string)
+                            .map(|r#type| type_to_string(r#type, context))
                             .collect::<Vec<String>>()
                             .join(", ")
                         + ">"
@@ -215,32 +1170,57 @@ fn type_to_string(r#type: &data::Type) -> String {
         data::Type::ScopeInFile(identifer) => identifer.get(),
 
         data::Type::ScopeInGlobal(identifer) => identifer.get(),
-        data::Type::ImportedType(imported_type) => {
-            String::from("$$$") + &imported_type.module_name + "." + &imported_type.name.get()
+        data::Type::ImportedType(imported_type) => match context.code_type {
+            data::CodeType::JavaScriptWithJsDoc => {
+                import_type_to_string(&imported_type.module_name, &imported_type.name, context)
+            }
+            data::CodeType::JavaScript
+            | data::CodeType::TypeScript
+            | data::CodeType::Declaration => {
+                module_name_to_identifer(&imported_type.module_name)
+                    + "."
+                    + &imported_type.name.get()
+            }
+        },
+
+        data::Type::StringLiteral(string) => {
+            string_literal_value_to_string(string, context.is_ascii_only)
         }
 
-        data::Type::StringLiteral(string) => string_literal_value_to_string(string),
+        data::Type::ProjectType(project_reference) => match (
+            context.code_type,
+            project_reference_module_name(project_reference, context),
+        ) {
+            (data::CodeType::JavaScriptWithJsDoc, Some(module_name)) => {
+                import_type_to_string(&module_name, &project_reference.name, context)
+            }
+            _ => project_reference_to_string(project_reference, context),
+        },
     }
 }
 
 fn lambda_body_to_string(
-    statement_list: &Vec<data::Statement>,
+    statement_list: &[data::Statement],
     indent: &Indent,
-    code_type: &data::CodeType,
+    context: &Context,
 ) -> String {
     match statement_list.first() {
         Some(data::Statement::Return(expr)) if statement_list.len() == 1 => {
-            expr_to_string_with_combine_strength(LAMBDA_COMBINE_STRENGTH, expr, indent, code_type)
+            expr_to_string_in(precedence::Position::LambdaBody, expr, indent, context)
         }
-        _ => statement_list_to_string(statement_list, indent, code_type),
+        _ => statement_list_to_string(statement_list, indent, context),
     }
 }
 
-fn expr_to_string(expr: &data::Expr, indent: &Indent, code_type: &data::CodeType) -> String {
+fn expr_to_string(expr: &data::Expr, indent: &Indent, context: &Context) -> String {
     match expr {
         data::Expr::NumberLiteral(int32) => format!("{}", int32),
 
-        data::Expr::StringLiteral(string) => string_literal_value_to_string(string),
+        data::Expr::StringLiteral(string) => {
+            string_literal_value_to_string(string, context.is_ascii_only)
+        }
+
+        data::Expr::RegExpLiteral(regexp) => regexp_literal_to_string(regexp),
 
         data::Expr::BooleanLiteral(true) => String::from("true"),
 
@@ -251,92 +1231,245 @@ fn expr_to_string(expr: &data::Expr, indent: &Indent, code_type: &data::CodeType
         data::Expr::NullLiteral => String::from("null"),
 
         data::Expr::ArrayLiteral(array_item) => {
-            array_literal_to_string(array_item, indent, code_type)
+            array_literal_to_string(array_item, indent, context)
         }
 
         data::Expr::ObjectLiteral(member_list) => {
-            object_literal_to_string(member_list, indent, code_type)
+            object_literal_to_string(member_list, indent, context)
         }
 
         data::Expr::UnaryOperator(unary_operator_expr) => {
-            unary_operator_to_string(&unary_operator_expr.operator)
-                + &expr_to_string_with_combine_strength(
-                    UNARY_OPERATOR_COMBINE_STRENGTH,
-                    &unary_operator_expr.expr,
-                    indent,
-                    code_type,
-                )
+            unary_operator_expr_to_string(unary_operator_expr, indent, context)
         }
         data::Expr::BinaryOperator(binary_operator_expr) => {
-            binary_operator_expr_to_string(binary_operator_expr, indent, code_type)
+            binary_operator_expr_to_string(binary_operator_expr, indent, context)
         }
 
         data::Expr::ConditionalOperator(conditional_operator_expr) => {
-            conditional_operator_expr_to_string(conditional_operator_expr, indent, code_type)
+            conditional_operator_expr_to_string(conditional_operator_expr, indent, context)
         }
 
         data::Expr::Lambda(lambda) => {
-            type_parameter_list_to_string(&lambda.type_parameter_list, code_type)
-                + "("
-                + &lambda
-                    .parameter_list
-                    .iter()
-                    .map(|parameter| {
-                        parameter.name.get() + &type_annotation(&parameter.r#type, code_type)
-                    })
-                    .collect::<Vec<String>>()
-                    .join(", ")
-                + ")"
-                + &type_annotation(&lambda.return_type, code_type)
-                + " => "
-                + &lambda_body_to_string(&lambda.statement_list, indent, code_type)
+            function_expr_document(
+                &lambda.type_parameter_list,
+                &lambda.parameter_list,
+                Some(&lambda.return_type),
+                context,
+            ) + &function_expr_to_string(
+                &FunctionHead {
+                    is_async: lambda.is_async,
+                    is_generator: lambda.is_generator,
+                    type_parameter_list: &lambda.type_parameter_list,
+                    parameter_list: &lambda_parameter_string_list(
+                        &lambda.parameter_list,
+                        indent,
+                        context,
+                    ),
+                    return_type: &lambda.return_type,
+                },
+                &lambda.statement_list,
+                indent,
+                context,
+            )
         }
 
         data::Expr::Variable(identifer) => identifer.get(),
 
         data::Expr::GlobalObjects(identifer) => identifer.get(),
         data::Expr::ImportedVariable(imported_variable) => {
-            String::from("$$$")
-                + &imported_variable.module_name
+            module_name_to_identifer(&imported_variable.module_name)
                 + "."
                 + &imported_variable.name.get()
         }
 
+        data::Expr::ProjectVariable(project_reference) => {
+            project_reference_to_string(project_reference, context)
+        }
+
         data::Expr::Get(get_expr) => {
-            expr_to_string_with_combine_strength(
-                GET_COMBINE_STRENGTH,
+            expr_to_string_in(
+                precedence::Position::Member,
                 &get_expr.expr,
                 indent,
-                code_type,
-            ) + &index_access_to_string(&get_expr.property_expr, indent, code_type)
+                context,
+            ) + (if get_expr.is_optional_chain {
+                optional_index_access_to_string(&get_expr.property_expr, indent, context)
+            } else {
+                index_access_to_string(&get_expr.property_expr, indent, context)
+            })
+            .as_str()
         }
 
-        data::Expr::Call(call_expr) => call_expr_to_string(call_expr, indent, code_type),
+        data::Expr::Call(call_expr) => call_expr_to_string(call_expr, indent, context),
 
         data::Expr::New(call_expr) => {
-            String::from("new ") + &call_expr_to_string(call_expr, indent, code_type)
+            String::from("new ")
+                + &expr_to_string_in(
+                    precedence::Position::NewTarget,
+                    &call_expr.expr,
+                    indent,
+                    context,
+                )
+                + &call_parameter_list_to_string(&call_expr.parameter_list, indent, context)
         }
 
-        data::Expr::TypeAssertion(type_assertion) => {
-            expr_to_string(&type_assertion.expr, indent, code_type)
-                + " as "
-                + &type_to_string(&type_assertion.r#type)
+        data::Expr::Yield(yield_expr) => {
+            String::from(if yield_expr.is_delegate {
+                "yield*"
+            } else {
+                "yield"
+            }) + &(match &yield_expr.expr {
+                Some(expr) => String::from(" ") + &assignment_expr_to_string(expr, indent, context),
+                None => String::new(),
+            })
+        }
+
+        data::Expr::Class(class_expr) => class_expr_to_string(class_expr, indent, context),
+
+        data::Expr::This => String::from("this"),
+
+        data::Expr::Await(expr) => {
+            String::from("await ")
+                + &expr_to_string_in(precedence::Position::AwaitOperand, expr, indent, context)
         }
+
+        data::Expr::TypeAssertion(type_assertion) => match context.code_type {
+            data::CodeType::JavaScriptWithJsDoc => {
+                String::from("/** @type {")
+                    + &type_to_string(&type_assertion.r#type, context)
+                    + "} */ ("
+                    + &expr_to_string(&type_assertion.expr, indent, context)
+                    + ")"
+            }
+            // JavaScript では型アサーションを出力しない
+            data::CodeType::JavaScript => expr_to_string(&type_assertion.expr, indent, context),
+            data::CodeType::TypeScript | data::CodeType::Declaration => {
+                expr_to_string_in(
+                    precedence::Position::TypeAssertion,
+                    &type_assertion.expr,
+                    indent,
+                    context,
+                ) + " as "
+                    + &type_to_string(&type_assertion.r#type, context)
+            }
+        },
     }
 }
 
-fn expr_to_string_with_combine_strength(
-    outside_combine_strength: u8,
-    target: &data::Expr,
+/// ラムダ式やメソッドの `(a: number, b?: string)`
+fn lambda_parameter_list_to_string(
+    parameter_list: &[data::Parameter],
     indent: &Indent,
-    code_type: &data::CodeType,
+    context: &Context,
+) -> String {
+    String::from("(")
+        + &lambda_parameter_string_list(parameter_list, indent, context).join(", ")
+        + ")"
+}
+
+fn lambda_parameter_string_list(
+    parameter_list: &[data::Parameter],
+    indent: &Indent,
+    context: &Context,
+) -> Vec<String> {
+    parameter_list
+        .iter()
+        .map(|parameter| {
+            parameter_to_string(
+                &binding_pattern_to_string(&parameter.pattern, indent, context),
+                &parameter.r#type,
+                &parameter.kind,
+                indent,
+                context,
+            )
+        })
+        .collect()
+}
+
+/// 関数の本体以外の部分
+struct FunctionHead<'a> {
+    is_async: bool,
+    is_generator: bool,
+    type_parameter_list: &'a [data::identifer::Identifer],
+    /// 出力したパラメーター
+    parameter_list: &'a [String],
+    return_type: &'a data::Type,
+}
+
+/// 関数宣言
+///
+/// - `function f<T>(a: T): T {}`
+/// - `async function* f(): AsyncGenerator<number, void, undefined> {}`
+fn function_declaration_to_string(
+    name: &data::identifer::Identifer,
+    head: &FunctionHead,
+    statement_list: &[data::Statement],
+    indent: &Indent,
+    context: &Context,
+) -> String {
+    String::from(if head.is_async { "async " } else { "" })
+        + (if head.is_generator {
+            "function* "
+        } else {
+            "function "
+        })
+        + &name.get()
+        + &type_parameter_list_to_string(head.type_parameter_list, context.code_type)
+        + "("
+        + &head.parameter_list.join(", ")
+        + ")"
+        + &type_annotation(head.return_type, context)
+        + " "
+        + &statement_list_to_string(statement_list, indent, context)
+}
+
+/// 関数式. ジェネレーター関数はアロー関数で書けないため `function*` で出力する
+///
+/// - `async <T>(a: T): Promise<T> => a`
+/// - `async function* (a: number): AsyncGenerator<number, void, undefined> {}`
+fn function_expr_to_string(
+    head: &FunctionHead,
+    statement_list: &[data::Statement],
+    indent: &Indent,
+    context: &Context,
+) -> String {
+    let parameter_and_return_type =
+        type_parameter_list_to_string(head.type_parameter_list, context.code_type)
+            + "("
+            + &head.parameter_list.join(", ")
+            + ")"
+            + &type_annotation(head.return_type, context);
+    String::from(if head.is_async { "async " } else { "" })
+        + &(if head.is_generator {
+            String::from("function* ")
+                + &parameter_and_return_type
+                + " "
+                + &statement_list_to_string(statement_list, indent, context)
+        } else {
+            parameter_and_return_type
+                + " => "
+                + &lambda_body_to_string(statement_list, indent, context)
+        })
+}
+
+/// 式が置かれる場所に応じて 必要なら括弧で囲む
+fn expr_to_string_in(
+    position: precedence::Position,
+    expr: &data::Expr,
+    indent: &Indent,
+    context: &Context,
 ) -> String {
     enclose_in_parentheses_by_condition(
-        &expr_to_string(target, indent, code_type),
-        outside_combine_strength > expr_combine_strength(target),
+        &expr_to_string(expr, indent, context),
+        precedence::needs_parentheses(position, expr, context.code_type),
     )
 }
 
+/// 関数の引数や配列の要素, 変数の初期値など 代入式が置かれる場所の式
+fn assignment_expr_to_string(expr: &data::Expr, indent: &Indent, context: &Context) -> String {
+    expr_to_string_in(precedence::Position::Assignment, expr, indent, context)
+}
+
 fn enclose_in_parentheses_by_condition(str: &str, condition: bool) -> String {
     if condition {
         String::from("(") + str + ")"
@@ -345,67 +1478,15 @@ fn enclose_in_parentheses_by_condition(str: &str, condition: bool) -> String {
     }
 }
 
-/// https://developer.mozilla.org/ja/docs/Web/JavaScript/Reference/Operators/Operator_Precedence#Table
-fn expr_combine_strength(expr: &data::Expr) -> u8 {
-    match expr {
-        data::Expr::NumberLiteral(_)
-        | data::Expr::StringLiteral(_)
-        | data::Expr::BooleanLiteral(_)
-        | data::Expr::NullLiteral
-        | data::Expr::UndefinedLiteral
-        | data::Expr::ArrayLiteral(_)
-        | data::Expr::Variable(_)
-        | data::Expr::GlobalObjects(_)
-        | data::Expr::ImportedVariable(_) => 23,
-        data::Expr::Lambda(_) => LAMBDA_COMBINE_STRENGTH,
-        data::Expr::ObjectLiteral(_) => 21,
-        data::Expr::Get(_) => GET_COMBINE_STRENGTH,
-        data::Expr::Call(_) | data::Expr::New(_) => CALL_OR_NEW_COMBINE_STRENGTH,
-        data::Expr::UnaryOperator(_) => UNARY_OPERATOR_COMBINE_STRENGTH,
-        data::Expr::BinaryOperator(binary_operator_expr) => {
-            binary_operator_combine_strength(&binary_operator_expr.operator)
-        }
-        data::Expr::ConditionalOperator(_) => CONDITIONAL_OPERATOR_COMBINE_STRENGTH,
-        data::Expr::TypeAssertion(_) => 3,
-    }
-}
-
-const LAMBDA_COMBINE_STRENGTH: u8 = 22;
-const UNARY_OPERATOR_COMBINE_STRENGTH: u8 = 17;
-const GET_COMBINE_STRENGTH: u8 = 20;
-const CALL_OR_NEW_COMBINE_STRENGTH: u8 = 20;
-const CONDITIONAL_OPERATOR_COMBINE_STRENGTH: u8 = 4;
-
-/// https://developer.mozilla.org/ja/docs/Web/JavaScript/Reference/Operators/Operator_Precedence#Table
-fn binary_operator_combine_strength(binary_operator: &data::BinaryOperator) -> u8 {
-    match binary_operator {
-        data::BinaryOperator::Exponentiation => 16,
-        data::BinaryOperator::Multiplication
-        | data::BinaryOperator::Division
-        | data::BinaryOperator::Remainder => 15,
-        data::BinaryOperator::Addition | data::BinaryOperator::Subtraction => 14,
-        data::BinaryOperator::LeftShift
-        | data::BinaryOperator::SignedRightShift
-        | data::BinaryOperator::UnsignedRightShift => 13,
-        data::BinaryOperator::LessThan | data::BinaryOperator::LessThanOrEqual => 12,
-        data::BinaryOperator::Equal | data::BinaryOperator::NotEqual => 11,
-        data::BinaryOperator::BitwiseAnd => 10,
-        data::BinaryOperator::BitwiseXOr => 9,
-        data::BinaryOperator::BitwiseOr => 8,
-        data::BinaryOperator::LogicalAnd => 6,
-        data::BinaryOperator::LogicalOr => 5,
-    }
-}
-
 fn statement_list_to_string(
-    statement_list: &Vec<data::Statement>,
+    statement_list: &[data::Statement],
     indent: &Indent,
-    code_type: &data::CodeType,
+    context: &Context,
 ) -> String {
     String::from("{\n")
         + &statement_list
             .iter()
-            .map(|statement| statement_to_string(statement, &indent.add_one(), code_type))
+            .map(|statement| statement_to_string(statement, &indent.add_one(), context))
             .collect::<Vec<String>>()
             .join("\n")
         + "\n"
@@ -413,143 +1494,472 @@ fn statement_list_to_string(
         + "}"
 }
 
-fn statement_to_string(
+fn statement_to_string(statement: &data::Statement, indent: &Indent, context: &Context) -> String {
+    indent_number_to_string(indent)
+        + &statement_without_indent_to_string(statement, indent, context)
+}
+
+/// 先頭のインデントを含めない文. ラベルの後ろにも使う
+fn statement_without_indent_to_string(
     statement: &data::Statement,
     indent: &Indent,
-    code_type: &data::CodeType,
+    context: &Context,
 ) -> String {
-    indent_number_to_string(indent)
-        + &(match statement {
-            data::Statement::EvaluateExpr(expr) => expr_to_string(expr, indent, code_type) + ";",
-            data::Statement::Set(set_statement) => {
-                expr_to_string(&set_statement.target, indent, code_type)
-                    + " "
-                    + &(match &set_statement.operator_maybe {
-                        Some(operator) => binary_operator_to_string(operator),
-                        None => String::from(""),
-                    })
-                    + "= "
-                    + &expr_to_string(&set_statement.expr, indent, code_type)
-                    + ";"
-            }
-            data::Statement::If(if_statement) => {
-                String::from("if (")
-                    + &expr_to_string(&if_statement.condition, indent, code_type)
-                    + ") "
-                    + &statement_list_to_string(
-                        &if_statement.then_statement_list,
-                        indent,
-                        code_type,
-                    )
-            }
-            data::Statement::ThrowError(expr) => {
-                String::from("throw new Error(") + &expr_to_string(expr, indent, code_type) + ");"
-            }
-            data::Statement::Return(expr) => {
-                String::from("return ") + &expr_to_string(expr, indent, code_type) + ";"
-            }
-            data::Statement::ReturnVoid => String::from("return;"),
-            data::Statement::Continue => String::from("continue;"),
-            data::Statement::VariableDefinition(variable_definition_statement) => {
-                String::from(if variable_definition_statement.is_const {
-                    "const"
-                } else {
-                    "let"
-                }) + " "
-                    + &variable_definition_statement.name.get()
-                    + &type_annotation(&variable_definition_statement.r#type, code_type)
-                    + " = "
-                    + &expr_to_string(&variable_definition_statement.expr, indent, code_type)
-                    + ";"
-            }
-            data::Statement::FunctionDefinition(function_definition_statement) => {
-                function_definition_statement_to_string(
-                    function_definition_statement,
+    match statement {
+        data::Statement::EvaluateExpr(expr) => {
+            expr_to_string_in(precedence::Position::Statement, expr, indent, context) + ";"
+        }
+        data::Statement::Set(set_statement) => {
+            set_statement_to_string(set_statement, false, indent, context) + ";"
+        }
+        data::Statement::If(if_statement) => {
+            String::from("if (")
+                + &expr_to_string(&if_statement.condition, indent, context)
+                + ") "
+                + &statement_list_to_string(&if_statement.then_statement_list, indent, context)
+        }
+        data::Statement::ThrowError(expr) => {
+            String::from("throw new Error(")
+                + &assignment_expr_to_string(expr, indent, context)
+                + ");"
+        }
+        data::Statement::Return(expr) => {
+            String::from("return ") + &expr_to_string(expr, indent, context) + ";"
+        }
+        data::Statement::ReturnVoid => String::from("return;"),
+        data::Statement::Continue => String::from("continue;"),
+        data::Statement::VariableDefinition(variable_definition_statement) => {
+            variable_definition_to_string(variable_definition_statement, false, indent, context)
+                + ";"
+        }
+        data::Statement::FunctionDefinition(function_definition_statement) => {
+            function_definition_statement_to_string(function_definition_statement, indent, context)
+        }
+
+        data::Statement::For(for_statement) => {
+            String::from("for (let ")
+                + &for_statement.counter_variable_name.get()
+                + " = 0; "
+                + &for_statement.counter_variable_name.get()
+                + " < "
+                + &expr_to_string_in(
+                    precedence::Position::BinaryRight(&data::BinaryOperator::LessThan),
+                    &for_statement.until_expr,
                     indent,
-                    code_type,
+                    context,
                 )
-            }
-
-            data::Statement::For(for_statement) => {
-                String::from("for (let ")
-                    + &for_statement.counter_variable_name.get()
-                    + " = 0; "
-                    + &for_statement.counter_variable_name.get()
-                    + " < "
-                    + &expr_to_string(&for_statement.until_expr, indent, code_type)
-                    + "; "
-                    + &for_statement.counter_variable_name.get()
-                    + " += 1)"
-                    + &statement_list_to_string(&for_statement.statement_list, indent, code_type)
-            }
-            data::Statement::ForOf(for_of_statement) => {
-                String::from("for (const ")
-                    + &for_of_statement.element_variable_name.get()
-                    + " of "
-                    + &expr_to_string(&for_of_statement.iterable_expr, indent, code_type)
-                    + ")"
-                    + &statement_list_to_string(&for_of_statement.statement_list, indent, code_type)
-            }
-            data::Statement::WhileTrue(statement_list) => {
-                String::from("while (true) ")
-                    + &statement_list_to_string(statement_list, indent, code_type)
-            }
+                + "; "
+                + &for_statement.counter_variable_name.get()
+                + " += 1)"
+                + &statement_list_to_string(&for_statement.statement_list, indent, context)
+        }
+        data::Statement::ForOf(for_of_statement) => {
+            String::from(if for_of_statement.is_await {
+                "for await (const "
+            } else {
+                "for (const "
+            }) + &binding_pattern_to_string(&for_of_statement.element_pattern, indent, context)
+                + " of "
+                + &assignment_expr_to_string(&for_of_statement.iterable_expr, indent, context)
+                + ")"
+                + &statement_list_to_string(&for_of_statement.statement_list, indent, context)
+        }
+        data::Statement::WhileTrue(statement_list) => {
+            String::from("while (true) ")
+                + &statement_list_to_string(statement_list, indent, context)
+        }
 
-            data::Statement::Break => String::from("break"),
-            data::Statement::Switch(switch_statement) => {
-                switch_to_string(switch_statement, indent, code_type)
-            }
-        })
+        data::Statement::Break => String::from("break"),
+        data::Statement::Switch(switch_statement) => {
+            switch_to_string(switch_statement, indent, context)
+        }
+        data::Statement::ForLoop(for_loop_statement) => {
+            for_loop_to_string(for_loop_statement, indent, context)
+        }
+        data::Statement::While(while_statement) => {
+            String::from("while (")
+                + &expr_to_string(&while_statement.condition, indent, context)
+                + ") "
+                + &statement_list_to_string(&while_statement.statement_list, indent, context)
+        }
+        data::Statement::DoWhile(while_statement) => {
+            String::from("do ")
+                + &statement_list_to_string(&while_statement.statement_list, indent, context)
+                + " while ("
+                + &expr_to_string(&while_statement.condition, indent, context)
+                + ");"
+        }
+        data::Statement::ForIn(for_in_statement) => {
+            String::from("for (const ")
+                + &for_in_statement.key_variable_name.get()
+                + " in "
+                + &assignment_expr_to_string(&for_in_statement.object_expr, indent, context)
+                + ") "
+                + &statement_list_to_string(&for_in_statement.statement_list, indent, context)
+        }
+        data::Statement::Labeled(labeled_statement) => {
+            labeled_statement.label.get()
+                + ": "
+                + &statement_without_indent_to_string(&labeled_statement.statement, indent, context)
+        }
+        data::Statement::LabeledBreak(label) => String::from("break ") + &label.get() + ";",
+        data::Statement::LabeledContinue(label) => String::from("continue ") + &label.get() + ";",
+        data::Statement::Commented(commented_statement) => {
+            leading_comment_list_to_string(&commented_statement.leading_comment_list, indent)
+                + &statement_without_indent_to_string(
+                    &commented_statement.statement,
+                    indent,
+                    context,
+                )
+                + &match &commented_statement.trailing_comment {
+                    Some(text) => {
+                        String::from(" ")
+                            + &comment_to_line_list(&data::Comment::Line(text.clone()))
+                                .join(&(String::from("\n") + &indent_number_to_string(indent)))
+                    }
+                    None => String::new(),
+                }
+        }
+    }
 }
 
-fn binary_operator_to_string(binary_operator: &data::BinaryOperator) -> String {
-    String::from(match binary_operator {
-        data::BinaryOperator::Exponentiation => "**",
-        data::BinaryOperator::Multiplication => "*",
-        data::BinaryOperator::Division => "/",
-        data::BinaryOperator::Remainder => "%",
-        data::BinaryOperator::Addition => "+",
-        data::BinaryOperator::Subtraction => "-",
-        data::BinaryOperator::LeftShift => "<<",
-        data::BinaryOperator::SignedRightShift => ">>",
-        data::BinaryOperator::UnsignedRightShift => ">>>",
-        data::BinaryOperator::LessThan => "<",
-        data::BinaryOperator::LessThanOrEqual => "<=",
-        data::BinaryOperator::Equal => "===",
-        data::BinaryOperator::NotEqual => "!==",
-        data::BinaryOperator::BitwiseAnd => "&",
-        data::BinaryOperator::BitwiseXOr => "^",
-        data::BinaryOperator::BitwiseOr => "|",
-        data::BinaryOperator::LogicalAnd => "&&",
-        data::BinaryOperator::LogicalOr => "||",
-    })
+/// 前の行に出力するコメント. 最初の行のインデントは含めず, 各行の後ろに改行と次の行のインデントを付ける
+fn leading_comment_list_to_string(comment_list: &[data::Comment], indent: &Indent) -> String {
+    comment_list
+        .iter()
+        .flat_map(comment_to_line_list)
+        .map(|line| line + "\n" + &indent_number_to_string(indent))
+        .collect()
 }
 
-fn indent_number_to_string(indent: &Indent) -> String {
-    String::from("  ").repeat(indent.value as usize)
+/// コメントを行ごとの文字列にする
+///
+/// ```ts
+/// // line
+/// /* block */
+/// // eslint-disable-next-line no-console
+/// // @ts-expect-error reason
+/// ```
+fn comment_to_line_list(comment: &data::Comment) -> Vec<String> {
+    match comment {
+        data::Comment::Line(text) => comment_text_line_list(text)
+            .into_iter()
+            .map(|line| {
+                if line.is_empty() {
+                    String::from("//")
+                } else {
+                    String::from("// ") + &line
+                }
+            })
+            .collect(),
+        data::Comment::Block(text) => {
+            let line_list = comment_text_line_list(&text.replace("*/", "*\\/"));
+            match line_list.as_slice() {
+                [line] => vec![String::from("/* ") + line + " */"],
+                _ => std::iter::once(String::from("/*"))
+                    .chain(line_list.iter().map(|line| {
+                        if line.is_empty() {
+                            String::from(" *")
+                        } else {
+                            String::from(" * ") + line
+                        }
+                    }))
+                    .chain(std::iter::once(String::from(" */")))
+                    .collect(),
+            }
+        }
+        // ディレクティブは次の行に効くので 1行にまとめる
+        data::Comment::EslintDisableNextLine(rule_list) => vec![directive_to_string(
+            "eslint-disable-next-line",
+            &rule_list.join(", "),
+        )],
+        data::Comment::TsExpectError(reason) => {
+            vec![directive_to_string("@ts-expect-error", reason)]
+        }
+    }
 }
 
-/**
- * codeTypeがTypeScriptだった場合,`: string`のような型注釈をつける
- */
-fn type_annotation(r#type: &data::Type, code_type: &data::CodeType) -> String {
-    match code_type {
-        data::CodeType::JavaScript => String::new(),
-        data::CodeType::TypeScript => String::from(": ") + &type_to_string(&r#type),
+fn directive_to_string(directive: &str, text: &str) -> String {
+    let text = comment_text_line_list(text).join(" ");
+    if text.trim().is_empty() {
+        String::from("// ") + directive
+    } else {
+        String::from("// ") + directive + " " + text.trim()
     }
 }
 
-fn type_object_to_string(member_list: &Vec<data::MemberType>) -> String {
-    String::from("{ ")
-        + &member_list
+/// コメントの文字列を行に分ける. JavaScript の改行文字 `\r`, `\u2028`, `\u2029` も行の区切りにする
+fn comment_text_line_list(text: &str) -> Vec<String> {
+    text.trim()
+        .replace("\r\n", "\n")
+        .split(['\n', '\r', '\u{2028}', '\u{2029}'])
+        .map(|line| String::from(line.trim_end()))
+        .collect()
+}
+
+/// 代入文. 最後の `;` は含まない
+fn set_statement_to_string(
+    set_statement: &data::SetStatement,
+    is_for_init: bool,
+    indent: &Indent,
+    context: &Context,
+) -> String {
+    expr_to_string(&set_statement.target, indent, context)
+        + " "
+        + &(match &set_statement.operator_maybe {
+            Some(operator) => binary_operator_to_string(operator),
+            None => String::from(""),
+        })
+        + "= "
+        + &initializer_to_string(&set_statement.expr, is_for_init, indent, context)
+}
+
+/// ローカル変数定義. 最後の `;` は含まない
+fn variable_definition_to_string(
+    variable_definition_statement: &data::VariableDefinitionStatement,
+    is_for_init: bool,
+    indent: &Indent,
+    context: &Context,
+) -> String {
+    inline_type_document(&variable_definition_statement.r#type, context)
+        + (if variable_definition_statement.is_const {
+            "const"
+        } else {
+            "let"
+        })
+        + " "
+        + &binding_pattern_to_string(&variable_definition_statement.pattern, indent, context)
+        + &type_annotation(&variable_definition_statement.r#type, context)
+        + " = "
+        + &initializer_to_string(
+            &variable_definition_statement.expr,
+            is_for_init,
+            indent,
+            context,
+        )
+}
+
+/// 代入する式. for文の初期化の部分では `in` 演算子が forIn文の `in` と区別できないため括弧で囲む
+fn initializer_to_string(
+    expr: &data::Expr,
+    is_for_init: bool,
+    indent: &Indent,
+    context: &Context,
+) -> String {
+    if is_for_init && precedence::contains_in_operator(expr) {
+        String::from("(") + &expr_to_string(expr, indent, context) + ")"
+    } else {
+        assignment_expr_to_string(expr, indent, context)
+    }
+}
+
+/// `for (let i = 0; i < 10; i += 1) {}`
+fn for_loop_to_string(
+    for_loop_statement: &data::ForLoopStatement,
+    indent: &Indent,
+    context: &Context,
+) -> String {
+    let init = match &for_loop_statement.init {
+        Some(data::ForInit::VariableDefinition(variable_definition_statement)) => {
+            variable_definition_to_string(variable_definition_statement, true, indent, context)
+        }
+        Some(data::ForInit::Set(set_statement)) => {
+            set_statement_to_string(set_statement, true, indent, context)
+        }
+        Some(data::ForInit::Expr(expr)) => {
+            if precedence::contains_in_operator(expr) {
+                String::from("(") + &expr_to_string(expr, indent, context) + ")"
+            } else {
+                expr_to_string_in(precedence::Position::Statement, expr, indent, context)
+            }
+        }
+        None => String::from(""),
+    };
+    let test = match &for_loop_statement.test {
+        Some(expr) => String::from(" ") + &expr_to_string(expr, indent, context),
+        None => String::from(""),
+    };
+    let update = match &for_loop_statement.update {
+        Some(data::ForUpdate::Set(set_statement)) => {
+            String::from(" ") + &set_statement_to_string(set_statement, false, indent, context)
+        }
+        Some(data::ForUpdate::Expr(expr)) => {
+            String::from(" ") + &expr_to_string(expr, indent, context)
+        }
+        None => String::from(""),
+    };
+    String::from("for (")
+        + &init
+        + ";"
+        + &test
+        + ";"
+        + &update
+        + ") "
+        + &statement_list_to_string(&for_loop_statement.statement_list, indent, context)
+}
+
+/// 変数の定義やパラメーターのパターン
+///
+/// - `a`
+/// - `{ a, b: renamed, c = 0, ...rest }`
+/// - `[x, , y = 1, ...rest]`
+fn binding_pattern_to_string(
+    binding_pattern: &data::BindingPattern,
+    indent: &Indent,
+    context: &Context,
+) -> String {
+    match binding_pattern {
+        data::BindingPattern::Identifer(identifer) => identifer.get(),
+        data::BindingPattern::Object(object_pattern) => {
+            let member_list = object_pattern
+                .member_list
+                .iter()
+                .map(|member| {
+                    (match &member.pattern {
+                        data::BindingPattern::Identifer(identifer)
+                            if identifer.get() == member.key =>
+                        {
+                            member.key.clone()
+                        }
+                        pattern => {
+                            property_name_to_string(&member.key, context)
+                                + ": "
+                                + &binding_pattern_to_string(pattern, indent, context)
+                        }
+                    }) + &default_expr_to_string(&member.default_expr, indent, context)
+                })
+                .chain(
+                    object_pattern
+                        .rest
+                        .iter()
+                        .map(|rest| String::from("...") + &rest.get()),
+                )
+                .collect::<Vec<String>>();
+            if member_list.is_empty() {
+                String::from("{}")
+            } else {
+                String::from("{ ") + &member_list.join(", ") + " }"
+            }
+        }
+        data::BindingPattern::Array(array_pattern) => {
+            let item_list = array_pattern
+                .item_list
+                .iter()
+                .map(|item| match item {
+                    Some(item) => {
+                        binding_pattern_to_string(&item.pattern, indent, context)
+                            + &default_expr_to_string(&item.default_expr, indent, context)
+                    }
+                    None => String::new(),
+                })
+                .chain(
+                    array_pattern
+                        .rest
+                        .iter()
+                        .map(|rest| String::from("...") + &rest.get()),
+                )
+                .collect::<Vec<String>>();
+            // 最後の要素を読み飛ばすときは `[a, ,]` のように, カンマを1つ多くつける必要がある
+            let is_last_hole = array_pattern.rest.is_none()
+                && matches!(array_pattern.item_list.last(), Some(None));
+            String::from("[") + &item_list.join(", ") + (if is_last_hole { ",]" } else { "]" })
+        }
+    }
+}
+
+/// パターンのデフォルト値 ` = 0`
+fn default_expr_to_string(
+    default_expr: &Option<data::Expr>,
+    indent: &Indent,
+    context: &Context,
+) -> String {
+    match default_expr {
+        Some(default_expr) => {
+            String::from(" = ") + &assignment_expr_to_string(default_expr, indent, context)
+        }
+        None => String::new(),
+    }
+}
+
+pub(crate) fn binary_operator_to_string(binary_operator: &data::BinaryOperator) -> String {
+    String::from(match binary_operator {
+        data::BinaryOperator::Exponentiation => "**",
+        data::BinaryOperator::Multiplication => "*",
+        data::BinaryOperator::Division => "/",
+        data::BinaryOperator::Remainder => "%",
+        data::BinaryOperator::Addition => "+",
+        data::BinaryOperator::Subtraction => "-",
+        data::BinaryOperator::LeftShift => "<<",
+        data::BinaryOperator::SignedRightShift => ">>",
+        data::BinaryOperator::UnsignedRightShift => ">>>",
+        data::BinaryOperator::LessThan => "<",
+        data::BinaryOperator::LessThanOrEqual => "<=",
+        data::BinaryOperator::GreaterThan => ">",
+        data::BinaryOperator::GreaterThanOrEqual => ">=",
+        data::BinaryOperator::In => "in",
+        data::BinaryOperator::InstanceOf => "instanceof",
+        data::BinaryOperator::Equal => "===",
+        data::BinaryOperator::NotEqual => "!==",
+        data::BinaryOperator::LooseEqual => "==",
+        data::BinaryOperator::LooseNotEqual => "!=",
+        data::BinaryOperator::BitwiseAnd => "&",
+        data::BinaryOperator::BitwiseXOr => "^",
+        data::BinaryOperator::BitwiseOr => "|",
+        data::BinaryOperator::LogicalAnd => "&&",
+        data::BinaryOperator::LogicalOr => "||",
+        data::BinaryOperator::NullishCoalescing => "??",
+        data::BinaryOperator::Comma => ",",
+    })
+}
+
+fn indent_number_to_string(indent: &Indent) -> String {
+    String::from("  ").repeat(indent.value as usize)
+}
+
+/**
+ * codeTypeがTypeScriptだった場合,`: string`のような型注釈をつける
+ */
+fn type_annotation(r#type: &data::Type, context: &Context) -> String {
+    match context.code_type {
+        data::CodeType::JavaScript | data::CodeType::JavaScriptWithJsDoc => String::new(),
+        data::CodeType::TypeScript | data::CodeType::Declaration => {
+            String::from(": ") + &type_to_string(r#type, context)
+        }
+    }
+}
+
+/// codeTypeがJSDocで型を表現するものだった場合, `/** @type {string} */ ` のような型のコメントをつける
+fn inline_type_document(r#type: &data::Type, context: &Context) -> String {
+    if *context.code_type == data::CodeType::JavaScriptWithJsDoc {
+        String::from("/** @type {") + &type_to_string(r#type, context) + "} */ "
+    } else {
+        String::new()
+    }
+}
+
+fn type_object_to_string(member_list: &[data::MemberType], context: &Context) -> String {
+    String::from("{ ")
+        + &member_list
             .iter()
             .map(|member| {
-                document_to_string(&member.document)
-                    + "readonly "
-                    + &property_name_to_string(&member.name)
-                    + &(if member.required { "" } else { "?" })
+                // JSDoc の中ではコメントを入れ子にできないので, メンバーのドキュメントは出力しない
+                (if *context.code_type == data::CodeType::JavaScriptWithJsDoc {
+                    String::new()
+                } else {
+                    document_to_string(
+                        &(String::new()
+                            + &member.document
+                            + &document_tag_list_to_document(
+                                &member.document_tag_list,
+                                None,
+                                context,
+                            )),
+                    )
+                }) + "readonly "
+                    + &property_name_to_string(&member.name, context)
+                    + (if member.required { "" } else { "?" })
                     + ": "
-                    + &type_to_string(&member.r#type)
+                    + &type_to_string(&member.r#type, context)
             })
             .collect::<Vec<String>>()
             .join("; ")
@@ -557,7 +1967,7 @@ fn type_object_to_string(member_list: &Vec<data::MemberType>) -> String {
 }
 
 /// 関数の引数と戻り値の型を文字列にする
-fn type_function_to_string(function_type: &data::FunctionType) -> String {
+fn type_function_to_string(function_type: &data::FunctionType, context: &Context) -> String {
     type_parameter_list_to_string(
         &function_type.type_parameter_list,
         &data::CodeType::TypeScript,
@@ -565,21 +1975,105 @@ fn type_function_to_string(function_type: &data::FunctionType) -> String {
         + &function_type
             .parameter_list
             .iter()
+            .map(|parameter| ("", parameter))
+            .chain(
+                function_type
+                    .optional_parameter_list
+                    .iter()
+                    .map(|parameter| ("?", parameter)),
+            )
             .enumerate()
-            .map(|(index, parameter)| format!("${}: {}", index, type_to_string(parameter)))
+            .map(|(index, (mark, parameter))| {
+                format!("${}{}: {}", index, mark, type_to_string(parameter, context))
+            })
+            .chain(function_type.rest_parameter.iter().map(|parameter| {
+                format!(
+                    "...${}: {}",
+                    function_type.parameter_list.len()
+                        + function_type.optional_parameter_list.len(),
+                    type_to_string(parameter, context)
+                )
+            }))
             .collect::<Vec<String>>()
             .join(", ")
         + ") => "
-        + &type_to_string(&function_type.return_type)
+        + &type_to_string(&function_type.return_type, context)
+}
+
+/// ダブルクオートの文字列リテラル. 制御文字, 改行文字, 見分けにくい空白や書式文字はエスケープする.
+/// `is_ascii_only` なら ASCII 以外の文字も `\uXXXX` か `\u{XXXXX}` にエスケープする.
+/// Rust の文字列は単独のサロゲートを含まないので サロゲートは考えなくてよい
+fn string_literal_value_to_string(string: &str, is_ascii_only: bool) -> String {
+    let mut result = String::from("\"");
+    let mut char_iter = string.chars().peekable();
+    while let Some(c) = char_iter.next() {
+        match c {
+            '\\' => result.push_str("\\\\"),
+            '"' => result.push_str("\\\""),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            '\u{8}' => result.push_str("\\b"),
+            '\u{b}' => result.push_str("\\v"),
+            '\u{c}' => result.push_str("\\f"),
+            // 直後に数字があると `\01` のような 8進数のエスケープとして読まれてしまう
+            '\0' if !char_iter.peek().is_some_and(char::is_ascii_digit) => result.push_str("\\0"),
+            _ if c.is_control() && (c as u32) <= 0xff => {
+                result.push_str(&format!("\\x{:02X}", c as u32))
+            }
+            _ if is_invisible_char(c) || (is_ascii_only && !c.is_ascii()) => {
+                if (c as u32) <= 0xffff {
+                    result.push_str(&format!("\\u{:04X}", c as u32))
+                } else {
+                    result.push_str(&format!("\\u{{{:X}}}", c as u32))
+                }
+            }
+            _ => result.push(c),
+        }
+    }
+    result + "\""
 }
 
-fn string_literal_value_to_string(string: &str) -> String {
-    String::from("\"")
-        + &string
-            .replace("\\", "\\\\")
-            .replace("\"", "\\\"")
-            .replace("\n", "\\n")
-        + "\""
+/// そのまま出力すると 見えなかったり 空白や改行と見分けにくかったりする文字.
+/// 行区切り文字, 段落区切り文字, 双方向テキストの制御文字, ゼロ幅の文字, ASCII 以外の空白
+fn is_invisible_char(c: char) -> bool {
+    matches!(
+        c,
+        '\u{a0}'
+            | '\u{ad}'
+            | '\u{61c}'
+            | '\u{1680}'
+            | '\u{180e}'
+            | '\u{2000}'..='\u{200f}'
+            | '\u{2028}'..='\u{202f}'
+            | '\u{205f}'..='\u{206f}'
+            | '\u{3000}'
+            | '\u{feff}'
+            | '\u{fff9}'..='\u{fffb}'
+    )
+}
+
+/// 正規表現リテラル. パターンの `/` と改行をエスケープする. 空のパターンは `//` がコメントになるので `(?:)` にする
+fn regexp_literal_to_string(regexp: &data::RegExpLiteral) -> String {
+    if regexp.pattern.is_empty() {
+        return String::from("/(?:)/") + &regexp.flags;
+    }
+    let mut pattern = String::new();
+    let mut is_escaped = false;
+    for c in regexp.pattern.chars() {
+        // `\` の直後の改行は `\n` のように エスケープの文字だけを出力する
+        let escape_prefix = if is_escaped { "" } else { "\\" };
+        match c {
+            '/' if !is_escaped => pattern.push_str("\\/"),
+            '\n' => pattern.push_str(&(String::from(escape_prefix) + "n")),
+            '\r' => pattern.push_str(&(String::from(escape_prefix) + "r")),
+            '\u{2028}' => pattern.push_str(&(String::from(escape_prefix) + "u2028")),
+            '\u{2029}' => pattern.push_str(&(String::from(escape_prefix) + "u2029")),
+            _ => pattern.push(c),
+        }
+        is_escaped = !is_escaped && c == '\\';
+    }
+    String::from("/") + &pattern + "/" + &regexp.flags
 }
 
 #[test]
@@ -587,7 +2081,8 @@ fn test_escape_in_string_literal() {
     assert_eq!(
         string_literal_value_to_string(
             r#"\\ a
-\"""#
+\"""#,
+            false
         ),
         String::from(r#""\\\\ a\n\\\"\"""#)
     );
@@ -596,241 +2091,2298 @@ fn test_escape_in_string_literal() {
             r#"
         改行
         "ダブルクオーテーション"
-"#
+"#,
+            false
         ),
         String::from(r#""\n        改行\n        \"ダブルクオーテーション\"\n""#)
     )
 }
 
-fn array_literal_to_string(
-    item_list: &Vec<data::ArrayItem>,
-    indent: &Indent,
-    code_type: &data::CodeType,
-) -> String {
-    String::from("[")
-        + &item_list
-            .iter()
-            .map(|item| {
-                String::from(if item.spread { "..." } else { "" })
-                    + &expr_to_string(&item.expr, indent, code_type)
-            })
-            .collect::<Vec<String>>()
-            .join(", ")
-        + "]"
+#[test]
+fn test_escape_control_and_unicode() {
+    assert_eq!(
+        string_literal_value_to_string("\r\t\u{8}\u{b}\u{c}\0a\u{0}1\u{1b}\u{7f}\u{85}", false),
+        r#""\r\t\b\v\f\0a\x001\x1B\x7F\x85""#
+    );
+    assert_eq!(
+        string_literal_value_to_string(
+            "a\u{2028}b\u{2029}\u{202e}\u{200b}\u{feff}\u{a0}あ😀",
+            false
+        ),
+        r#""a\u2028b\u2029\u202E\u200B\uFEFF\u00A0あ😀""#
+    );
+    assert_eq!(
+        string_literal_value_to_string("é あ😀 ~", true),
+        r#""\u00E9 \u3042\u{1F600} ~""#
+    );
 }
 
-fn object_literal_to_string(
-    member_list: &Vec<data::Member>,
-    indent: &Indent,
-    code_type: &data::CodeType,
-) -> String {
-    String::from("{ ")
-        + &member_list
-            .iter()
-            .map(|member| match member {
-                data::Member::Spread(expr) => {
-                    String::from("...") + &expr_to_string(expr, indent, code_type)
-                }
+#[test]
+fn test_jsdoc_typedef() {
+    let code = crate::json::from_json(
+        r#"{"formatVersion":4,"code":{"exportDefinitionList":[
+            {"TypeAlias":{"name":"Wrapper","typeParameterList":["T"],"document":"包む","type":
+                {"Object":[{"name":"value","required":true,"document":"値","type":{"ScopeInFile":"T"}}]}}},
+            {"Variable":{"name":"request","document":"","type":{"ImportedType":{"moduleName":"express","name":"Request"}},
+                "expr":{"TypeAssertion":{"expr":"NullLiteral","type":{"ImportedType":{"moduleName":"express","name":"Request"}}}}}}
+        ],"statementList":[]}}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        to_string(&code, &data::CodeType::JavaScriptWithJsDoc),
+        r#"/* eslint-disable */
+/* generated by js-ts-code-generator. Do not edit! */
 
-                data::Member::KeyValue(data::KeyValue {
-                    key,
-                    value: data::Expr::Variable(value_identifer),
-                }) if *key == value_identifer.get() => key.clone(),
-                data::Member::KeyValue(data::KeyValue { key, value }) => {
-                    (property_name_to_string(key))
-                        + ": "
-                        + &expr_to_string(value, indent, code_type)
-                }
-            })
-            .collect::<Vec<String>>()
-            .join(", ")
-        + " "
-        + "}"
-}
+// @ts-check
 
-fn unary_operator_to_string(unary_operator: &data::UnaryOperator) -> String {
-    String::from(match unary_operator {
-        data::UnaryOperator::Minus => "-",
-        data::UnaryOperator::BitwiseNot => "~",
-        data::UnaryOperator::LogicalNot => "!",
-    })
-}
+/**
+ * 包む
+ * @template T
+ * @typedef {{ readonly value: T }} Wrapper
+ */
 
-fn binary_operator_expr_to_string(
-    binary_operator_expr: &data::BinaryOperatorExpr,
-    indent: &Indent,
-    code_type: &data::CodeType,
-) -> String {
-    let operator_expr_combine_strength =
-        binary_operator_combine_strength(&binary_operator_expr.operator);
-    let left_expr_combine_strength = expr_combine_strength(&binary_operator_expr.left);
-    let right_expr_combine_strength = expr_combine_strength(&binary_operator_expr.right);
-    let associativity = binary_operator_associativity(&binary_operator_expr.operator);
-
-    (enclose_in_parentheses_by_condition(
-        &expr_to_string(&binary_operator_expr.left, indent, code_type),
-        operator_expr_combine_strength > left_expr_combine_strength
-            || (operator_expr_combine_strength == left_expr_combine_strength
-                && associativity == Associativity::RightToLeft),
-    )) + " "
-        + &binary_operator_to_string(&binary_operator_expr.operator)
-        + " "
-        + &(enclose_in_parentheses_by_condition(
-            &expr_to_string(&binary_operator_expr.right, indent, code_type),
-            operator_expr_combine_strength > right_expr_combine_strength
-                || (operator_expr_combine_strength == right_expr_combine_strength
-                    && associativity == Associativity::LeftToRight),
-        ))
-}
 
-fn conditional_operator_expr_to_string(
-    conditional_operator: &data::ConditionalOperatorExpr,
-    indent: &Indent,
-    code_type: &data::CodeType,
-) -> String {
-    expr_to_string_with_combine_strength(
-        CONDITIONAL_OPERATOR_COMBINE_STRENGTH,
-        &conditional_operator.condition,
-        indent,
-        code_type,
-    ) + "?"
-        + &expr_to_string_with_combine_strength(
-            CONDITIONAL_OPERATOR_COMBINE_STRENGTH,
-            &conditional_operator.then_expr,
-            indent,
-            code_type,
-        )
-        + ":"
-        + &expr_to_string_with_combine_strength(
-            CONDITIONAL_OPERATOR_COMBINE_STRENGTH,
-            &conditional_operator.else_expr,
-            indent,
-            code_type,
-        )
-}
 
-fn call_expr_to_string(
-    call_expr: &data::CallExpr,
-    indent: &Indent,
-    code_type: &data::CodeType,
-) -> String {
-    expr_to_string_with_combine_strength(
-        CALL_OR_NEW_COMBINE_STRENGTH,
-        &call_expr.expr,
-        indent,
-        code_type,
-    ) + "("
-        + &call_expr
-            .parameter_list
-            .iter()
-            .map(|parameter| expr_to_string(parameter, indent, code_type))
-            .collect::<Vec<String>>()
-            .join(", ")
-        + ")"
-}
+/**
+ * @type {import("express").Request}
+ */
+export const request = /** @type {import("express").Request} */ (null);
 
-///
-/// ```ts
-/// list[0]
-/// data.name
-///  ```
-/// の部分indexのExprがstringLiteralで識別子に使える文字なら`.name`のようになる
-///
-fn index_access_to_string(
-    index_expr: &data::Expr,
-    indent: &Indent,
-    code_type: &data::CodeType,
-) -> String {
-    match index_expr {
-        data::Expr::StringLiteral(string) if data::identifer::is_safe_property_name(string) => {
-            String::from(".") + string
-        }
-        _ => String::from("[") + &expr_to_string(index_expr, indent, code_type) + "]",
-    }
+"#
+    );
 }
 
-fn function_definition_statement_to_string(
-    function_definition: &data::FunctionDefinitionStatement,
-    indent: &Indent,
-    code_type: &data::CodeType,
-) -> String {
-    String::from("const ")
-        + &function_definition.name.get()
-        + " = "
-        + &type_parameter_list_to_string(&function_definition.type_parameter_list, code_type)
-        + "("
-        + &function_definition
-            .parameter_list
-            .iter()
-            .map(|parameter| parameter.name.get() + &type_annotation(&parameter.r#type, code_type))
-            .collect::<Vec<String>>()
-            .join(", ")
-        + ")"
-        + &type_annotation(&function_definition.return_type, code_type)
-        + " => "
-        + &lambda_body_to_string(&function_definition.statement_list, indent, code_type)
-        + ";"
-}
+#[test]
+fn test_jsdoc_function_expr() {
+    let code = crate::json::from_json(
+        r#"{"formatVersion":4,"code":{"exportDefinitionList":[
+            {"Variable":{"name":"pick","document":"","type":{"ScopeInFile":"Pick"},"expr":{"Lambda":{
+                "parameterList":[
+                    {"pattern":{"Identifer":"list"},"type":{"WithTypeParameter":{"type":{"ScopeInGlobal":"ReadonlyArray"},
+                        "typeParameterList":[{"ScopeInFile":"T"}]}}},
+                    {"pattern":{"Object":{"memberList":[{"key":"index","pattern":{"Identifer":"index"}}]}},
+                        "type":{"Object":[{"name":"index","required":true,"document":"","type":"Number"}]}},
+                    {"pattern":{"Identifer":"fallback"},"type":{"ScopeInFile":"T"},"kind":"Optional"}
+                ],
+                "typeParameterList":["T"],"returnType":{"Union":[{"ScopeInFile":"T"},"Undefined"]},
+                "statementList":[{"Return":{"Get":{"expr":{"Variable":"list"},"propertyExpr":{"Variable":"index"}}}}]}}}},
+            {"Variable":{"name":"counter","document":"","type":{"ScopeInFile":"Counter"},"expr":{"ObjectLiteral":[
+                {"Method":{"key":{"Name":"add"},"typeParameterList":[],"parameterList":[
+                    {"pattern":{"Identifer":"x"},"type":"Number"}],"returnType":"Void","statementList":[]}},
+                {"Getter":{"key":{"Name":"value"},"returnType":"Number",
+                    "statementList":[{"Return":{"NumberLiteral":0}}]}},
+                {"Setter":{"key":{"Name":"value"},"parameter":{"pattern":{"Identifer":"newValue"},"type":"Number"},
+                    "statementList":[]}}
+            ]}}}
+        ],"statementList":[
+            {"FunctionDefinition":{"name":"identity","typeParameterList":["T"],
+                "parameterList":[{"name":"x","document":"","type":{"ScopeInFile":"T"}}],"returnType":{"ScopeInFile":"T"},
+                "statementList":[{"Return":{"Variable":"x"}}]}}
+        ]}}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        to_string(&code, &data::CodeType::JavaScriptWithJsDoc),
+        r#"/* eslint-disable */
+/* generated by js-ts-code-generator. Do not edit! */
 
-fn switch_to_string(
-    switch: &data::SwitchStatement,
-    indent: &Indent,
-    code_type: &data::CodeType,
-) -> String {
-    let case_indent = indent.add_one();
-    String::from("switch (")
-        + &expr_to_string(&switch.expr, indent, code_type)
-        + ") {\n"
-        + &switch
-            .pattern_list
-            .iter()
-            .map(|pattern| {
-                indent_number_to_string(&case_indent)
-                    + "case "
-                    + &string_literal_value_to_string(&pattern.case_string)
-                    + ": "
-                    + &statement_list_to_string(&pattern.statement_list, &case_indent, code_type)
-            })
-            .collect::<Vec<String>>()
-            .join("\n")
-        + "\n"
-        + &indent_number_to_string(indent)
-        + "}"
-}
+// @ts-check
 
-fn property_name_to_string(property_name: &str) -> String {
-    if data::identifer::is_safe_property_name(property_name) {
-        String::from(property_name)
+/**
+ * @type {Pick}
+ */
+export const pick = /** @template T @param {ReadonlyArray<T>} list @param {{ readonly index: number }} $1 @param {T} [fallback] @returns {T | undefined} */ (list, { index }, fallback) => list[index];
+
+
+
+/**
+ * @type {Counter}
+ */
+export const counter = { /** @param {number} x @returns {void} */ add(x) {
+
+}, /** @returns {number} */ get value() {
+  return 0;
+}, /** @param {number} newValue */ set value(newValue) {
+
+} };
+
+{
+  /**
+   * @template T
+   * @param {T} x
+   * @returns {T}
+   */
+  const identity = (x) => x;
+}"#
+    );
+}
+
+#[test]
+fn test_private_definition() {
+    let code = crate::json::from_json(
+        r#"{"formatVersion":4,"code":{"exportDefinitionList":[
+            {"TypeAlias":{"name":"Id","typeParameterList":[],"document":"","type":"String","visibility":"Private"}},
+            {"Variable":{"name":"prefix","document":"","type":{"ScopeInFile":"Id"},"expr":{"StringLiteral":"id-"},"visibility":"Private"}},
+            {"Variable":{"name":"first","document":"","type":{"ScopeInFile":"Id"},"expr":{"BinaryOperator":{
+                "operator":"Addition","left":{"Variable":"prefix"},"right":{"StringLiteral":"1"}}}}}
+        ],"statementList":[]}}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        to_string(&code, &data::CodeType::TypeScript),
+        r#"/* eslint-disable */
+/* generated by js-ts-code-generator. Do not edit! */
+
+type Id = string;
+
+
+const prefix: Id = "id-";
+
+
+export const first: Id = prefix + "1";
+
+"#
+    );
+    assert_eq!(
+        to_string_with_option(
+            &code,
+            &data::GenerateOption {
+                code_type: data::CodeType::JavaScript,
+                module_format: data::ModuleFormat::CommonJs,
+                function_style: data::FunctionStyle::Arrow,
+                is_ascii_only: false,
+                common_js_default_export: data::CommonJsDefaultExport::ExportsDefault,
+            }
+        ),
+        r#"/* eslint-disable */
+/* generated by js-ts-code-generator. Do not edit! */
+
+
+const prefix = "id-";
+
+
+const first = prefix + "1";
+exports.first = first;
+
+"#
+    );
+    assert_eq!(
+        to_string(&code, &data::CodeType::Declaration),
+        r#"/* eslint-disable */
+/* generated by js-ts-code-generator. Do not edit! */
+
+type Id = string;
+
+
+
+export declare const first: Id;
+
+export {};
+"#
+    );
+}
+
+#[test]
+fn test_barrel() {
+    let code = crate::json::from_json(
+        r#"{"formatVersion":4,"code":{"exportDefinitionList":[
+            {"TypeAlias":{"name":"User","typeParameterList":[],"document":"","type":"String"}},
+            {"Variable":{"name":"user","document":"","type":"String","expr":{"StringLiteral":"a"}}},
+            {"Variable":{"name":"helper","document":"","type":"String","expr":{"StringLiteral":"b"},"visibility":"Private"}},
+            {"ExportAll":{"moduleName":"./other","namespace":"other"}}
+        ],"statementList":[]}}"#,
+    )
+    .unwrap();
+    let mut barrel = crate::util::barrel(&[("./user", &code)]);
+    barrel
+        .export_definition_list
+        .push(data::ExportDefinition::ExportAll(data::ExportAll {
+            module_name: String::from("./all"),
+            namespace: None,
+        }));
+    assert_eq!(
+        to_string(&barrel, &data::CodeType::TypeScript),
+        r#"/* eslint-disable */
+/* generated by js-ts-code-generator. Do not edit! */
+
+export { user, other } from "./user";
+
+export type { User } from "./user";
+
+export * from "./all";
+"#
+    );
+    assert_eq!(
+        to_string(&barrel, &data::CodeType::JavaScript),
+        r#"/* eslint-disable */
+/* generated by js-ts-code-generator. Do not edit! */
+
+export { user, other } from "./user";
+
+
+export * from "./all";
+"#
+    );
+    assert_eq!(
+        to_string_with_option(
+            &barrel,
+            &data::GenerateOption {
+                code_type: data::CodeType::JavaScript,
+                module_format: data::ModuleFormat::CommonJs,
+                function_style: data::FunctionStyle::Arrow,
+                is_ascii_only: false,
+                common_js_default_export: data::CommonJsDefaultExport::ExportsDefault,
+            }
+        ),
+        r#"/* eslint-disable */
+/* generated by js-ts-code-generator. Do not edit! */
+
+const $$$$2e$2fuser = require("./user");
+const $$$$2e$2fall = require("./all");
+exports.user = $$$$2e$2fuser.user;
+exports.other = $$$$2e$2fuser.other;
+
+
+for (const key of Object.keys($$$$2e$2fall)) {
+  if (key !== "default" && key !== "__esModule" && !Object.prototype.hasOwnProperty.call(exports, key)) {
+    exports[key] = $$$$2e$2fall[key];
+  }
+}
+"#
+    );
+}
+
+#[test]
+fn test_export_all_common_js() {
+    let option = data::GenerateOption {
+        code_type: data::CodeType::JavaScript,
+        module_format: data::ModuleFormat::CommonJs,
+        function_style: data::FunctionStyle::Arrow,
+        is_ascii_only: false,
+        common_js_default_export: data::CommonJsDefaultExport::ExportsDefault,
+    };
+    let config = crate::json::from_json(
+        r#"{"formatVersion":4,"code":{"exportDefinitionList":[
+            {"Variable":{"name":"port","document":"","type":"Number","expr":{"NumberLiteral":3000}}},
+            {"Default":{"document":"","type":"Number","expr":{"Variable":"port"}}}
+        ],"statementList":[]}}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        to_string_with_option(&config, &option),
+        r#"/* eslint-disable */
+/* generated by js-ts-code-generator. Do not edit! */
+
+const port = 3000;
+exports.port = port;
+
+
+exports.default = port;
+"#
+    );
+    // `./config` の default は export されない
+    let barrel = crate::json::from_json(
+        r#"{"formatVersion":4,"code":{"exportDefinitionList":[
+            {"ExportAll":{"moduleName":"./config","namespace":null}}
+        ],"statementList":[]}}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        to_string_with_option(&barrel, &option),
+        r#"/* eslint-disable */
+/* generated by js-ts-code-generator. Do not edit! */
+
+const $$$$2e$2fconfig = require("./config");
+for (const key of Object.keys($$$$2e$2fconfig)) {
+  if (key !== "default" && key !== "__esModule" && !Object.prototype.hasOwnProperty.call(exports, key)) {
+    exports[key] = $$$$2e$2fconfig[key];
+  }
+}
+"#
+    );
+}
+
+#[test]
+fn test_default_export() {
+    let code = crate::json::from_json(
+        r#"{"formatVersion":4,"code":{"exportDefinitionList":[
+            {"Default":{"document":"設定","type":{"Object":[{"name":"port","required":true,"document":"","type":"Number"}]},
+                "expr":{"ObjectLiteral":[{"KeyValue":{"key":"port","value":{"NumberLiteral":3000}}}]}}}
+        ],"statementList":[]}}"#,
+    )
+    .unwrap();
+    let common_js = data::GenerateOption {
+        code_type: data::CodeType::JavaScript,
+        module_format: data::ModuleFormat::CommonJs,
+        function_style: data::FunctionStyle::Arrow,
+        is_ascii_only: false,
+        common_js_default_export: data::CommonJsDefaultExport::ExportsDefault,
+    };
+    assert_eq!(
+        to_string(&code, &data::CodeType::TypeScript),
+        r#"/* eslint-disable */
+/* generated by js-ts-code-generator. Do not edit! */
+
+
+/**
+ * 設定
+ */
+export default { port: 3000 };
+"#
+    );
+    assert_eq!(
+        to_string(&code, &data::CodeType::Declaration),
+        r#"/* eslint-disable */
+/* generated by js-ts-code-generator. Do not edit! */
+
+
+/**
+ * 設定
+ */
+declare const $default: { readonly port: number };
+export default $default;
+"#
+    );
+    let module_exports = data::GenerateOption {
+        code_type: data::CodeType::JavaScript,
+        module_format: data::ModuleFormat::CommonJs,
+        function_style: data::FunctionStyle::Arrow,
+        is_ascii_only: false,
+        common_js_default_export: data::CommonJsDefaultExport::ModuleExports,
+    };
+    assert!(
+        to_string_with_option(&code, &common_js).ends_with("exports.default = { port: 3000 };\n")
+    );
+    assert!(to_string_with_option(&code, &module_exports)
+        .ends_with("module.exports = { port: 3000 };\n"));
+    assert_eq!(
+        crate::validation::validate_with_option(&code, &module_exports),
+        vec![]
+    );
+
+    let code = crate::json::from_json(
+        r#"{"formatVersion":4,"code":{"exportDefinitionList":[
+            {"Variable":{"name":"port","document":"","type":"Number","expr":{"NumberLiteral":3000}}},
+            {"Default":{"document":"","type":"Number","expr":{"Variable":"port"}}}
+        ],"statementList":[]}}"#,
+    )
+    .unwrap();
+    assert!(to_string_with_option(&code, &common_js).ends_with("exports.default = port;\n"));
+    // 他に export する値があると `module.exports` に代入できない
+    assert_eq!(
+        crate::validation::validate_with_option(&code, &module_exports),
+        vec![data::ValidationError::NamedExportWithModuleExports]
+    );
+    assert_eq!(
+        crate::validation::validate_with_option(
+            &code,
+            &data::GenerateOption {
+                module_format: data::ModuleFormat::EsModule,
+                ..module_exports
+            }
+        ),
+        vec![]
+    );
+}
+
+#[test]
+fn test_module_format() {
+    let code = crate::json::from_json(
+        r#"{"formatVersion":4,"code":{"exportDefinitionList":[
+            {"Variable":{"name":"app","document":"","type":"Number","expr":{"Call":{
+                "expr":{"ImportedVariable":{"moduleName":"express","name":"express"}},"parameterList":[]}}}}
+        ],"statementList":[]}}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        to_string_with_option(
+            &code,
+            &data::GenerateOption {
+                code_type: data::CodeType::JavaScript,
+                module_format: data::ModuleFormat::CommonJs,
+                function_style: data::FunctionStyle::Arrow,
+                is_ascii_only: false,
+                common_js_default_export: data::CommonJsDefaultExport::ExportsDefault,
+            }
+        ),
+        r#"/* eslint-disable */
+/* generated by js-ts-code-generator. Do not edit! */
+
+const $$$express = require("express");
+const app = $$$express.express();
+exports.app = app;
+
+"#
+    );
+    assert_eq!(
+        to_string_with_option(
+            &code,
+            &data::GenerateOption {
+                code_type: data::CodeType::JavaScript,
+                module_format: data::ModuleFormat::Umd(String::from("sample")),
+                function_style: data::FunctionStyle::Arrow,
+                is_ascii_only: false,
+                common_js_default_export: data::CommonJsDefaultExport::ExportsDefault,
+            }
+        ),
+        r#"/* eslint-disable */
+/* generated by js-ts-code-generator. Do not edit! */
+
+(function (root, factory) {
+  if (typeof define === "function" && define.amd) {
+    define(["exports", "express"], factory);
+  } else if (typeof exports === "object") {
+    factory(exports, require("express"));
+  } else {
+    factory((root["sample"] = {}), root["express"]);
+  }
+})(typeof self !== "undefined" ? self : this, function (exports, $$$express) {
+  const app = $$$express.express();
+  exports.app = app;
+
+});
+"#
+    );
+    // TypeScript は常に ES Modules で出力する
+    assert_eq!(
+        to_string_with_option(
+            &code,
+            &data::GenerateOption {
+                code_type: data::CodeType::TypeScript,
+                module_format: data::ModuleFormat::CommonJs,
+                function_style: data::FunctionStyle::Arrow,
+                is_ascii_only: false,
+                common_js_default_export: data::CommonJsDefaultExport::ExportsDefault,
+            }
+        ),
+        to_string(&code, &data::CodeType::TypeScript)
+    );
+}
+
+fn array_literal_to_string(
+    item_list: &[data::ArrayItem],
+    indent: &Indent,
+    context: &Context,
+) -> String {
+    String::from("[") + &array_item_list_to_string(item_list, indent, context) + "]"
+}
+
+/// 配列リテラルの要素や 関数呼び出しのパラメーター. スプレッドの対象も代入式なので `...(a, b)` 以外は括弧で囲まない
+/// コメントが付いた要素があれば 1行に1つの要素を出力する
+fn array_item_list_to_string(
+    item_list: &[data::ArrayItem],
+    indent: &Indent,
+    context: &Context,
+) -> String {
+    let item_to_string = |item: &data::ArrayItem, item_indent: &Indent| {
+        leading_comment_list_to_string(&item.comment_list, item_indent)
+            + (if item.spread { "..." } else { "" })
+            + &assignment_expr_to_string(&item.expr, item_indent, context)
+    };
+    if item_list.iter().any(|item| !item.comment_list.is_empty()) {
+        let item_indent = indent.add_one();
+        String::from("\n")
+            + &item_list
+                .iter()
+                .map(|item| {
+                    indent_number_to_string(&item_indent) + &item_to_string(item, &item_indent)
+                })
+                .collect::<Vec<String>>()
+                .join(",\n")
+            + "\n"
+            + &indent_number_to_string(indent)
+    } else {
+        item_list
+            .iter()
+            .map(|item| item_to_string(item, indent))
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+}
+
+/// コメントが付いたメンバーがあれば 1行に1つのメンバーを出力する
+fn object_literal_to_string(
+    member_list: &[data::Member],
+    indent: &Indent,
+    context: &Context,
+) -> String {
+    if member_list
+        .iter()
+        .any(|member| matches!(member, data::Member::Commented(_)))
+    {
+        let member_indent = indent.add_one();
+        String::from("{\n")
+            + &member_list
+                .iter()
+                .map(|member| {
+                    indent_number_to_string(&member_indent)
+                        + &member_to_string(member, &member_indent, context)
+                })
+                .collect::<Vec<String>>()
+                .join(",\n")
+            + "\n"
+            + &indent_number_to_string(indent)
+            + "}"
+    } else {
+        String::from("{ ")
+            + &member_list
+                .iter()
+                .map(|member| member_to_string(member, indent, context))
+                .collect::<Vec<String>>()
+                .join(", ")
+            + " "
+            + "}"
+    }
+}
+
+fn member_to_string(member: &data::Member, indent: &Indent, context: &Context) -> String {
+    match member {
+        data::Member::Spread(expr) => {
+            String::from("...") + &assignment_expr_to_string(expr, indent, context)
+        }
+
+        data::Member::KeyValue(data::KeyValue {
+            key,
+            value: data::Expr::Variable(value_identifer),
+        }) if *key == value_identifer.get() => key.clone(),
+        // `__proto__: value` はプロトタイプを設定してしまう
+        data::Member::KeyValue(data::KeyValue { key, value }) if key == "__proto__" => {
+            String::from("[\"__proto__\"]: ") + &assignment_expr_to_string(value, indent, context)
+        }
+        data::Member::KeyValue(data::KeyValue { key, value }) => {
+            (property_name_to_string(key, context))
+                + ": "
+                + &assignment_expr_to_string(value, indent, context)
+        }
+        data::Member::ComputedKeyValue(computed_key_value) => {
+            String::from("[")
+                + &assignment_expr_to_string(&computed_key_value.key_expr, indent, context)
+                + "]: "
+                + &assignment_expr_to_string(&computed_key_value.value, indent, context)
+        }
+        data::Member::Method(method) => method_to_string(
+            method,
+            "",
+            &property_key_to_string(&method.key, indent, context),
+            indent,
+            context,
+        ),
+        data::Member::Getter(getter) => getter_to_string(
+            getter,
+            "",
+            &property_key_to_string(&getter.key, indent, context),
+            indent,
+            context,
+        ),
+        data::Member::Setter(setter) => setter_to_string(
+            setter,
+            "",
+            &property_key_to_string(&setter.key, indent, context),
+            indent,
+            context,
+        ),
+        data::Member::Commented(commented_member) => {
+            leading_comment_list_to_string(&commented_member.comment_list, indent)
+                + &member_to_string(&commented_member.member, indent, context)
+        }
+    }
+}
+
+/// メソッド `key() {}`. `key` は出力したキー, `modifier` はクラスのメンバーにつける `static `
+fn method_to_string(
+    method: &data::ObjectMethod,
+    modifier: &str,
+    key: &str,
+    indent: &Indent,
+    context: &Context,
+) -> String {
+    function_expr_document(
+        &method.type_parameter_list,
+        &method.parameter_list,
+        Some(&method.return_type),
+        context,
+    ) + modifier
+        + (if method.is_async { "async " } else { "" })
+        + (if method.is_generator { "*" } else { "" })
+        + key
+        + &type_parameter_list_to_string(&method.type_parameter_list, context.code_type)
+        + &lambda_parameter_list_to_string(&method.parameter_list, indent, context)
+        + &type_annotation(&method.return_type, context)
+        + " "
+        + &statement_list_to_string(&method.statement_list, indent, context)
+}
+
+/// ゲッター `get key() {}`. `key` は出力したキー, `modifier` はクラスのメンバーにつける `static `
+fn getter_to_string(
+    getter: &data::ObjectGetter,
+    modifier: &str,
+    key: &str,
+    indent: &Indent,
+    context: &Context,
+) -> String {
+    function_expr_document(&[], &[], Some(&getter.return_type), context)
+        + modifier
+        + "get "
+        + key
+        + "()"
+        + &type_annotation(&getter.return_type, context)
+        + " "
+        + &statement_list_to_string(&getter.statement_list, indent, context)
+}
+
+/// セッター `set key(value) {}`. `key` は出力したキー, `modifier` はクラスのメンバーにつける `static `
+fn setter_to_string(
+    setter: &data::ObjectSetter,
+    modifier: &str,
+    key: &str,
+    indent: &Indent,
+    context: &Context,
+) -> String {
+    function_expr_document(&[], std::slice::from_ref(&setter.parameter), None, context)
+        + modifier
+        + "set "
+        + key
+        + &lambda_parameter_list_to_string(std::slice::from_ref(&setter.parameter), indent, context)
+        + " "
+        + &statement_list_to_string(&setter.statement_list, indent, context)
+}
+
+/// クラス式. 宣言ファイルでは メンバーの本体と初期値を出力せずに型だけを出力する
+///
+/// ```ts
+/// class A extends B {
+///   a: number = 0;
+///   constructor(a: number) {
+///     super(a);
+///   }
+///   static f(): void {
+///   }
+/// }
+/// ```
+fn class_expr_to_string(
+    class_expr: &data::ClassExpr,
+    indent: &Indent,
+    context: &Context,
+) -> String {
+    let member_indent = indent.add_one();
+    let member_string_list = class_expr
+        .constructor
+        .iter()
+        .map(|constructor| class_constructor_to_string(constructor, &member_indent, context))
+        .chain(
+            class_expr
+                .member_list
+                .iter()
+                .map(|member| class_member_to_string(member, &member_indent, context)),
+        )
+        .collect::<Vec<String>>();
+    String::from("class")
+        + &(match &class_expr.name {
+            Some(name) => String::from(" ") + &name.get(),
+            None => String::new(),
+        })
+        + &(match &class_expr.extends {
+            Some(extends) => {
+                String::from(" extends ")
+                    + &expr_to_string_in(precedence::Position::Member, extends, indent, context)
+            }
+            None => String::new(),
+        })
+        + &(if member_string_list.is_empty() {
+            String::from(" {}")
+        } else {
+            String::from(" {\n")
+                + &member_string_list
+                    .iter()
+                    .map(|member_string| {
+                        indent_number_to_string(&member_indent) + member_string + "\n"
+                    })
+                    .collect::<String>()
+                + &indent_number_to_string(indent)
+                + "}"
+        })
+}
+
+/// コンストラクタ. `super()` を呼ぶ場合は 本体の最初に出力する
+fn class_constructor_to_string(
+    constructor: &data::ClassConstructor,
+    indent: &Indent,
+    context: &Context,
+) -> String {
+    let parameter_list =
+        lambda_parameter_list_to_string(&constructor.parameter_list, indent, context);
+    if *context.code_type == data::CodeType::Declaration {
+        return String::from("constructor") + &parameter_list + ";";
+    }
+    let statement_indent = indent.add_one();
+    function_expr_document(&[], &constructor.parameter_list, None, context)
+        + "constructor"
+        + &parameter_list
+        + " {\n"
+        + &constructor
+            .super_parameter_list
+            .iter()
+            .map(|super_parameter_list| {
+                indent_number_to_string(&statement_indent)
+                    + "super"
+                    + &call_parameter_list_to_string(
+                        super_parameter_list,
+                        &statement_indent,
+                        context,
+                    )
+                    + ";"
+            })
+            .chain(
+                constructor
+                    .statement_list
+                    .iter()
+                    .map(|statement| statement_to_string(statement, &statement_indent, context)),
+            )
+            .collect::<Vec<String>>()
+            .join("\n")
+        + "\n"
+        + &indent_number_to_string(indent)
+        + "}"
+}
+
+/// クラスのメンバー. キーが `constructor` なら コンストラクタにならないように `["constructor"]` と出力する
+fn class_member_to_string(
+    member: &data::ClassMember,
+    indent: &Indent,
+    context: &Context,
+) -> String {
+    let key_to_string = |key: &data::PropertyKey| match key {
+        data::PropertyKey::Name(name) if name == "constructor" => String::from("[\"constructor\"]"),
+        _ => property_key_to_string(key, indent, context),
+    };
+    let modifier = if member.is_static { "static " } else { "" };
+    let is_declaration = *context.code_type == data::CodeType::Declaration;
+    match &member.kind {
+        data::ClassMemberKind::Field(field) => {
+            inline_type_document(&field.r#type, context)
+                + modifier
+                + &key_to_string(&field.key)
+                + &type_annotation(&field.r#type, context)
+                + &(match &field.value {
+                    Some(value) if !is_declaration => {
+                        String::from(" = ") + &assignment_expr_to_string(value, indent, context)
+                    }
+                    _ => String::new(),
+                })
+                + ";"
+        }
+        // 宣言ファイルでは async や ジェネレーターの `*` をつけられない
+        data::ClassMemberKind::Method(method) if is_declaration => {
+            String::from(modifier)
+                + &key_to_string(&method.key)
+                + &type_parameter_list_to_string(&method.type_parameter_list, context.code_type)
+                + &lambda_parameter_list_to_string(&method.parameter_list, indent, context)
+                + &type_annotation(&method.return_type, context)
+                + ";"
+        }
+        data::ClassMemberKind::Getter(getter) if is_declaration => {
+            String::from(modifier)
+                + "get "
+                + &key_to_string(&getter.key)
+                + "()"
+                + &type_annotation(&getter.return_type, context)
+                + ";"
+        }
+        data::ClassMemberKind::Setter(setter) if is_declaration => {
+            String::from(modifier)
+                + "set "
+                + &key_to_string(&setter.key)
+                + &lambda_parameter_list_to_string(
+                    std::slice::from_ref(&setter.parameter),
+                    indent,
+                    context,
+                )
+                + ";"
+        }
+        data::ClassMemberKind::Method(method) => method_to_string(
+            method,
+            modifier,
+            &key_to_string(&method.key),
+            indent,
+            context,
+        ),
+        data::ClassMemberKind::Getter(getter) => getter_to_string(
+            getter,
+            modifier,
+            &key_to_string(&getter.key),
+            indent,
+            context,
+        ),
+        data::ClassMemberKind::Setter(setter) => setter_to_string(
+            setter,
+            modifier,
+            &key_to_string(&setter.key),
+            indent,
+            context,
+        ),
+    }
+}
+
+fn unary_operator_to_string(unary_operator: &data::UnaryOperator) -> String {
+    String::from(match unary_operator {
+        data::UnaryOperator::Minus => "-",
+        data::UnaryOperator::BitwiseNot => "~",
+        data::UnaryOperator::LogicalNot => "!",
+        data::UnaryOperator::Plus => "+",
+        data::UnaryOperator::TypeOf => "typeof ",
+        data::UnaryOperator::Void => "void ",
+        data::UnaryOperator::Delete => "delete ",
+        data::UnaryOperator::PrefixIncrement | data::UnaryOperator::PostfixIncrement => "++",
+        data::UnaryOperator::PrefixDecrement | data::UnaryOperator::PostfixDecrement => "--",
+    })
+}
+
+fn unary_operator_expr_to_string(
+    unary_operator_expr: &data::UnaryOperatorExpr,
+    indent: &Indent,
+    context: &Context,
+) -> String {
+    let operand = expr_to_string_in(
+        precedence::Position::UnaryOperand(&unary_operator_expr.operator),
+        &unary_operator_expr.expr,
+        indent,
+        context,
+    );
+    match unary_operator_expr.operator {
+        data::UnaryOperator::PostfixIncrement | data::UnaryOperator::PostfixDecrement => {
+            operand + &unary_operator_to_string(&unary_operator_expr.operator)
+        }
+        _ => unary_operator_to_string(&unary_operator_expr.operator) + &operand,
+    }
+}
+
+fn binary_operator_expr_to_string(
+    binary_operator_expr: &data::BinaryOperatorExpr,
+    indent: &Indent,
+    context: &Context,
+) -> String {
+    let operator = &binary_operator_expr.operator;
+    expr_to_string_in(
+        precedence::Position::BinaryLeft(operator),
+        &binary_operator_expr.left,
+        indent,
+        context,
+    ) + (if *operator == data::BinaryOperator::Comma {
+        ""
+    } else {
+        " "
+    }) + &binary_operator_to_string(operator)
+        + " "
+        + &expr_to_string_in(
+            precedence::Position::BinaryRight(operator),
+            &binary_operator_expr.right,
+            indent,
+            context,
+        )
+}
+
+fn conditional_operator_expr_to_string(
+    conditional_operator: &data::ConditionalOperatorExpr,
+    indent: &Indent,
+    context: &Context,
+) -> String {
+    expr_to_string_in(
+        precedence::Position::ConditionalCondition,
+        &conditional_operator.condition,
+        indent,
+        context,
+    ) + " ? "
+        + &expr_to_string_in(
+            precedence::Position::ConditionalBranch,
+            &conditional_operator.then_expr,
+            indent,
+            context,
+        )
+        + " : "
+        + &expr_to_string_in(
+            precedence::Position::ConditionalBranch,
+            &conditional_operator.else_expr,
+            indent,
+            context,
+        )
+}
+
+fn call_expr_to_string(call_expr: &data::CallExpr, indent: &Indent, context: &Context) -> String {
+    expr_to_string_in(
+        precedence::Position::Member,
+        &call_expr.expr,
+        indent,
+        context,
+    ) + (if call_expr.is_optional_chain {
+        "?."
+    } else {
+        ""
+    }) + &call_parameter_list_to_string(&call_expr.parameter_list, indent, context)
+}
+
+/// `a?.b.c` のように optional chaining を含むプロパティアクセスや関数呼び出しの連なりかどうか
+pub(crate) fn is_optional_chain(expr: &data::Expr) -> bool {
+    match expr {
+        data::Expr::Get(get_expr) => {
+            get_expr.is_optional_chain || is_optional_chain(&get_expr.expr)
+        }
+        data::Expr::Call(call_expr) => {
+            call_expr.is_optional_chain || is_optional_chain(&call_expr.expr)
+        }
+        _ => false,
+    }
+}
+
+fn call_parameter_list_to_string(
+    parameter_list: &[data::ArrayItem],
+    indent: &Indent,
+    context: &Context,
+) -> String {
+    String::from("(") + &array_item_list_to_string(parameter_list, indent, context) + ")"
+}
+
+///
+/// ```ts
+/// list[0]
+/// data.name
+///  ```
+/// の部分indexのExprがstringLiteralで識別子に使える文字なら`.name`のようになる
+///
+fn index_access_to_string(index_expr: &data::Expr, indent: &Indent, context: &Context) -> String {
+    match index_expr {
+        data::Expr::StringLiteral(string) if data::identifer::is_safe_property_name(string) => {
+            String::from(".") + string
+        }
+        _ => String::from("[") + &expr_to_string(index_expr, indent, context) + "]",
+    }
+}
+
+/// optional chaining でのプロパティアクセス `data?.name`, `list?.[0]`
+fn optional_index_access_to_string(
+    index_expr: &data::Expr,
+    indent: &Indent,
+    context: &Context,
+) -> String {
+    match index_expr {
+        data::Expr::StringLiteral(string) if data::identifer::is_safe_property_name(string) => {
+            String::from("?.") + string
+        }
+        _ => String::from("?.[") + &expr_to_string(index_expr, indent, context) + "]",
+    }
+}
+
+fn function_definition_statement_to_string(
+    function_definition: &data::FunctionDefinitionStatement,
+    indent: &Indent,
+    context: &Context,
+) -> String {
+    let parameter_list = function_definition
+        .parameter_list
+        .iter()
+        .map(|parameter| parameter_with_document_to_string(parameter, indent, context))
+        .collect::<Vec<String>>();
+    let head = FunctionHead {
+        is_async: function_definition.is_async,
+        is_generator: function_definition.is_generator,
+        type_parameter_list: &function_definition.type_parameter_list,
+        parameter_list: &parameter_list,
+        return_type: &function_definition.return_type,
+    };
+    function_definition_document(function_definition, indent, context)
+        + &match function_style(&function_definition.style, context) {
+            data::FunctionStyle::Arrow => {
+                String::from("const ")
+                    + &function_definition.name.get()
+                    + " = "
+                    + &function_expr_to_string(
+                        &head,
+                        &function_definition.statement_list,
+                        indent,
+                        context,
+                    )
+                    + ";"
+            }
+            data::FunctionStyle::Declaration => function_declaration_to_string(
+                &function_definition.name,
+                &head,
+                &function_definition.statement_list,
+                indent,
+                context,
+            ),
+        }
+}
+
+/// JSDoc で型を表現する場合の 関数の定義の文の前のドキュメント. エクスポートする関数と同じように型を出力する.
+/// 最初の行のインデントは含めず, 後ろに改行と次の行のインデントを付ける
+fn function_definition_document(
+    function_definition: &data::FunctionDefinitionStatement,
+    indent: &Indent,
+    context: &Context,
+) -> String {
+    if *context.code_type != data::CodeType::JavaScriptWithJsDoc {
+        return String::new();
+    }
+    document_to_string(
+        &(type_parameter_list_to_document(&function_definition.type_parameter_list)
+            + &parameter_list_to_document(&function_definition.parameter_list, context)
+            + &return_type_to_document(&function_definition.return_type, context)),
+    )
+    .trim_start_matches('\n')
+    .lines()
+    .map(|line| String::from(line) + "\n" + &indent_number_to_string(indent))
+    .collect()
+}
+
+/// ```ts
+/// switch (a) {
+///   case "x":
+///   case "y":
+///     f();
+///     break;
+///   default: {
+///     const exhaustiveCheck: never = a;
+///     void exhaustiveCheck;
+///   }
+/// }
+/// ```
+fn switch_to_string(switch: &data::SwitchStatement, indent: &Indent, context: &Context) -> String {
+    let case_indent = indent.add_one();
+    let is_exhaustive_check = switch.exhaustive_type.is_some()
+        && (*context.code_type == data::CodeType::TypeScript
+            || *context.code_type == data::CodeType::Declaration);
+    String::from("switch (")
+        + &expr_to_string(&switch.expr, indent, context)
+        + ") {\n"
+        + &switch
+            .pattern_list
+            .iter()
+            .map(|pattern| {
+                pattern
+                    .case_list
+                    .iter()
+                    .map(|case_expr| {
+                        indent_number_to_string(&case_indent)
+                            + "case "
+                            + &expr_to_string(case_expr, &case_indent, context)
+                            + ":"
+                    })
+                    .collect::<Vec<String>>()
+                    .join("\n")
+                    + &case_body_to_string(&pattern.statement_list, None, &case_indent, context)
+            })
+            .chain(
+                match (&switch.default_statement_list, is_exhaustive_check) {
+                    (Some(statement_list), _) => Some(statement_list.as_slice()),
+                    (None, true) => Some([].as_slice()),
+                    (None, false) => None,
+                }
+                .map(|statement_list| {
+                    indent_number_to_string(&case_indent)
+                        + "default:"
+                        + &case_body_to_string(
+                            statement_list,
+                            if is_exhaustive_check {
+                                Some(&switch.expr)
+                            } else {
+                                None
+                            },
+                            &case_indent,
+                            context,
+                        )
+                }),
+            )
+            .collect::<Vec<String>>()
+            .join("\n")
+        + "\n"
+        + &indent_number_to_string(indent)
+        + "}"
+}
+
+/// 変数や関数の定義の文かどうか. コメントが付いていても定義とみなす
+pub(crate) fn is_declaration(statement: &data::Statement) -> bool {
+    match statement {
+        data::Statement::VariableDefinition(_) | data::Statement::FunctionDefinition(_) => true,
+        data::Statement::Commented(commented_statement) => {
+            is_declaration(&commented_statement.statement)
+        }
+        _ => false,
+    }
+}
+
+/// case の後の文. 変数の定義があるときは 他の case とスコープを分けるためにブロックで囲む.
+/// `exhaustive_check_expr` を指定すると `never` 型の変数に代入する定義を先頭に出力する.
+/// `noUnusedLocals` でエラーにならないように 定義した変数を `void` で使う
+fn case_body_to_string(
+    statement_list: &[data::Statement],
+    exhaustive_check_expr: Option<&data::Expr>,
+    case_indent: &Indent,
+    context: &Context,
+) -> String {
+    let body_indent = case_indent.add_one();
+    let statement_string_list = exhaustive_check_expr
+        .map(|expr| {
+            indent_number_to_string(&body_indent)
+                + "const exhaustiveCheck: never = "
+                + &assignment_expr_to_string(expr, &body_indent, context)
+                + ";\n"
+                + &indent_number_to_string(&body_indent)
+                + "void exhaustiveCheck;"
+        })
+        .into_iter()
+        .chain(
+            statement_list
+                .iter()
+                .map(|statement| statement_to_string(statement, &body_indent, context)),
+        )
+        .collect::<Vec<String>>();
+    if statement_string_list.is_empty() {
+        return String::new();
+    }
+    let is_block = exhaustive_check_expr.is_some() || statement_list.iter().any(is_declaration);
+    if is_block {
+        String::from(" {\n")
+            + &statement_string_list.join("\n")
+            + "\n"
+            + &indent_number_to_string(case_indent)
+            + "}"
+    } else {
+        String::from("\n") + &statement_string_list.join("\n")
+    }
+}
+
+/// プロパティ名. `"0"` のような数値は `0` と出力する
+fn property_name_to_string(property_name: &str, context: &Context) -> String {
+    if data::identifer::is_safe_property_name(property_name)
+        || is_numeric_property_name(property_name)
+    {
+        String::from(property_name)
     } else {
-        string_literal_value_to_string(property_name)
-    }
-}
-
-#[derive(Eq, PartialEq)]
-pub enum Associativity {
-    LeftToRight,
-    RightToLeft,
-}
-
-fn binary_operator_associativity(binary_operator: &data::BinaryOperator) -> Associativity {
-    match binary_operator {
-        data::BinaryOperator::Exponentiation => Associativity::RightToLeft,
-        data::BinaryOperator::Multiplication
-        | data::BinaryOperator::Division
-        | data::BinaryOperator::Remainder
-        | data::BinaryOperator::Addition
-        | data::BinaryOperator::Subtraction
-        | data::BinaryOperator::LeftShift
-        | data::BinaryOperator::SignedRightShift
-        | data::BinaryOperator::UnsignedRightShift
-        | data::BinaryOperator::LessThan
-        | data::BinaryOperator::LessThanOrEqual
-        | data::BinaryOperator::Equal
-        | data::BinaryOperator::NotEqual
-        | data::BinaryOperator::BitwiseAnd
-        | data::BinaryOperator::BitwiseXOr
-        | data::BinaryOperator::BitwiseOr
-        | data::BinaryOperator::LogicalAnd
-        | data::BinaryOperator::LogicalOr => Associativity::LeftToRight,
+        string_literal_value_to_string(property_name, context.is_ascii_only)
+    }
+}
+
+/// 数値リテラルとして書いても 同じプロパティ名になるかどうか
+fn is_numeric_property_name(property_name: &str) -> bool {
+    property_name
+        .parse::<u32>()
+        .is_ok_and(|number| number.to_string() == property_name)
+}
+
+/// メソッドやゲッター, セッターのキー
+fn property_key_to_string(
+    property_key: &data::PropertyKey,
+    indent: &Indent,
+    context: &Context,
+) -> String {
+    match property_key {
+        data::PropertyKey::Name(name) => property_name_to_string(name, context),
+        data::PropertyKey::Computed(expr) => {
+            String::from("[") + &assignment_expr_to_string(expr, indent, context) + "]"
+        }
+    }
+}
+
+#[test]
+fn test_binding_pattern() {
+    let code = crate::json::from_json(
+        r#"{"formatVersion":4,"code":{"exportDefinitionList":[
+            {"Variable":{"name":"greet","document":"","type":{"Function":{"typeParameterList":[],
+                "parameterList":[{"ScopeInFile":"User"}],"returnType":"Void"}},"expr":{"Lambda":{
+                "parameterList":[{"pattern":{"Object":{"memberList":[
+                    {"key":"name","pattern":{"Identifer":"name"}},
+                    {"key":"age","pattern":{"Identifer":"userAge"},"defaultExpr":{"NumberLiteral":0}},
+                    {"key":"tag-list","pattern":{"Array":{"itemList":[null,{"pattern":{"Identifer":"second"}}]}}}
+                ],"rest":"others"}},"type":{"ScopeInFile":"User"}}],
+                "typeParameterList":[],"returnType":"Void","statementList":[
+                    {"VariableDefinition":{"pattern":{"Array":{"itemList":[
+                        {"pattern":{"Identifer":"first"},"defaultExpr":{"StringLiteral":"a"}},
+                        null,
+                        {"pattern":{"Object":{"memberList":[{"key":"x","pattern":{"Identifer":"x"}}]}}}
+                    ],"rest":"tail"}},"type":{"ScopeInFile":"Tuple"},"expr":{"Variable":"others"},"isConst":true}},
+                    {"ForOf":{"elementPattern":{"Array":{"itemList":[{"pattern":{"Identifer":"key"}},null]}},
+                        "iterableExpr":{"Variable":"tail"},"statementList":[]}}
+                ]}}}}
+        ],"statementList":[]}}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        to_string(&code, &data::CodeType::TypeScript),
+        r#"/* eslint-disable */
+/* generated by js-ts-code-generator. Do not edit! */
+
+export const greet: ($0: User) => void = ({ name, age: userAge = 0, "tag-list": [, second], ...others }: User): void => {
+  const [first = "a", , { x }, ...tail]: Tuple = others;
+  for (const [key, ,] of tail){
+
+  }
+};
+
+"#
+    );
+    assert!(to_string(&code, &data::CodeType::JavaScript).contains(
+        "export const greet = ({ name, age: userAge = 0, \"tag-list\": [, second], ...others }) => {\n  const [first = \"a\", , { x }, ...tail] = others;"
+    ));
+}
+
+#[test]
+fn test_parameter_kind() {
+    let code = crate::json::from_json(
+        r#"{"formatVersion":4,"code":{"exportDefinitionList":[
+            {"Function":{"name":"join","document":"","typeParameterList":[],"parameterList":[
+                {"name":"first","document":"","type":"String"},
+                {"name":"separator","document":"区切り","type":"String","kind":{"Default":{"StringLiteral":","}}},
+                {"name":"last","document":"","type":"String","kind":"Optional"},
+                {"name":"rest","document":"","type":{"WithTypeParameter":{"type":{"ScopeInGlobal":"ReadonlyArray"},
+                    "typeParameterList":["String"]}},"kind":"Rest"}
+            ],"returnType":"String","statementList":[{"Return":{"Variable":"first"}}]}},
+            {"TypeAlias":{"name":"Join","typeParameterList":[],"document":"","type":{"Function":{
+                "typeParameterList":[],"parameterList":["String"],"optionalParameterList":["String"],
+                "restParameter":{"WithTypeParameter":{"type":{"ScopeInGlobal":"ReadonlyArray"},"typeParameterList":["String"]}},
+                "returnType":"String"}}}}
+        ],"statementList":[]}}"#,
+    )
+    .unwrap();
+    let type_script = to_string(&code, &data::CodeType::TypeScript);
+    assert!(type_script.contains(
+        r#"export const join = (first: string, separator: string = ",", last?: string, ...rest: ReadonlyArray<string>): string => first;"#
+    ));
+    assert!(type_script.contains(
+        "export type Join = ($0: string, $1?: string, ...$2: ReadonlyArray<string>) => string;"
+    ));
+    assert!(to_string(&code, &data::CodeType::JavaScript)
+        .contains(r#"export const join = (first, separator = ",", last, ...rest) => first;"#));
+    assert!(
+        to_string(&code, &data::CodeType::JavaScriptWithJsDoc).contains(
+            r#"/**
+ * @param {string} first
+ * @param {string} [separator] 区切り
+ * @param {string} [last]
+ * @param {ReadonlyArray<string>} rest
+ * @returns {string}
+ */"#
+        )
+    );
+    assert!(to_string(&code, &data::CodeType::Declaration).contains(
+        "export declare const join: (first: string, separator?: string, last?: string, ...rest: ReadonlyArray<string>) => string;"
+    ));
+}
+
+#[test]
+fn test_optional_chain_and_nullish_coalescing() {
+    let variable = |name: &str| data::Expr::Variable(data::identifer::from_string(name));
+    let set = |operator: data::BinaryOperator| {
+        data::Statement::Set(data::SetStatement {
+            target: variable("a"),
+            operator_maybe: Some(operator),
+            expr: variable("b"),
+        })
+    };
+    let code = data::Code {
+        export_definition_list: vec![],
+        statement_list: vec![
+            data::Statement::EvaluateExpr(crate::util::nullish_coalescing(
+                crate::util::optional_get(
+                    crate::util::get(variable("request"), "headers"),
+                    "accept",
+                ),
+                data::Expr::StringLiteral(String::from("*/*")),
+            )),
+            data::Statement::EvaluateExpr(data::Expr::Call(Box::new(data::CallExpr {
+                expr: data::Expr::Get(Box::new(data::GetExpr {
+                    expr: variable("list"),
+                    property_expr: data::Expr::NumberLiteral(0),
+                    is_optional_chain: true,
+                })),
+                parameter_list: vec![],
+                is_optional_chain: true,
+            }))),
+            data::Statement::EvaluateExpr(crate::util::nullish_coalescing(
+                crate::util::logical_and(variable("a"), variable("b")),
+                crate::util::logical_or(variable("c"), variable("d")),
+            )),
+            data::Statement::EvaluateExpr(crate::util::logical_or(
+                variable("a"),
+                crate::util::nullish_coalescing(variable("b"), variable("c")),
+            )),
+            data::Statement::EvaluateExpr(data::Expr::New(Box::new(data::CallExpr {
+                expr: crate::util::optional_get(variable("factory"), "Class"),
+                parameter_list: vec![],
+                is_optional_chain: false,
+            }))),
+            set(data::BinaryOperator::NullishCoalescing),
+            set(data::BinaryOperator::LogicalOr),
+            set(data::BinaryOperator::LogicalAnd),
+        ],
+    };
+    assert_eq!(
+        to_string(&code, &data::CodeType::TypeScript),
+        r#"/* eslint-disable */
+/* generated by js-ts-code-generator. Do not edit! */
+
+{
+  request.headers?.accept ?? "*/*";
+  list?.[0]?.();
+  (a && b) ?? (c || d);
+  a || (b ?? c);
+  new (factory?.Class)();
+  a ??= b;
+  a ||= b;
+  a &&= b;
+}"#
+    );
+}
+
+#[test]
+fn test_operator() {
+    use crate::util;
+    let variable = |name: &str| data::Expr::Variable(data::identifer::from_string(name));
+    let code = data::Code {
+        export_definition_list: vec![],
+        statement_list: vec![
+            util::minus(util::minus(variable("a"))),
+            util::minus(data::Expr::NumberLiteral(-1)),
+            util::unary_plus(util::prefix_increment(variable("a"))),
+            util::minus(util::postfix_decrement(variable("a"))),
+            util::exponentiation(util::type_of(variable("a")), data::Expr::NumberLiteral(2)),
+            util::exponentiation(data::Expr::NumberLiteral(-2), util::minus(variable("a"))),
+            util::exponentiation(util::prefix_increment(variable("a")), variable("b")),
+            util::minus(util::exponentiation(variable("a"), variable("b"))),
+            util::type_of(util::postfix_increment(util::get(variable("a"), "b"))),
+            util::delete(util::get(variable("a"), "b")),
+            util::void_operator(data::Expr::NumberLiteral(0)),
+            util::exponentiation(
+                variable("a"),
+                util::exponentiation(variable("b"), variable("c")),
+            ),
+            util::exponentiation(
+                util::exponentiation(variable("a"), variable("b")),
+                variable("c"),
+            ),
+            util::loose_not_equal(
+                util::greater_than(variable("a"), variable("b")),
+                util::greater_than_or_equal(variable("c"), variable("d")),
+            ),
+            util::logical_and(
+                util::in_operator(data::Expr::StringLiteral(String::from("b")), variable("a")),
+                util::instance_of(variable("a"), variable("A")),
+            ),
+            util::loose_equal(variable("a"), data::Expr::NullLiteral),
+            util::comma(util::comma(variable("a"), variable("b")), variable("c")),
+            util::call_method(
+                variable("f"),
+                "call",
+                vec![util::comma(variable("a"), variable("b")), variable("c")],
+            ),
+        ]
+        .into_iter()
+        .map(data::Statement::EvaluateExpr)
+        .collect(),
+    };
+    assert_eq!(
+        to_string(&code, &data::CodeType::JavaScript),
+        r#"/* eslint-disable */
+/* generated by js-ts-code-generator. Do not edit! */
+
+{
+  -(-a);
+  -(-1);
+  +(++a);
+  -a--;
+  (typeof a) ** 2;
+  (-2) ** -a;
+  ++a ** b;
+  -(a ** b);
+  typeof a.b++;
+  delete a.b;
+  void 0;
+  a ** b ** c;
+  (a ** b) ** c;
+  a > b != c >= d;
+  "b" in a && a instanceof A;
+  a == null;
+  a, b, c;
+  f.call((a, b), c);
+}"#
+    );
+}
+
+#[test]
+fn test_parentheses() {
+    use crate::util;
+    let variable = |name: &str| data::Expr::Variable(data::identifer::from_string(name));
+    let code = data::Code {
+        export_definition_list: vec![],
+        statement_list: vec![
+            util::less_than(
+                data::Expr::TypeAssertion(Box::new(data::TypeAssertion {
+                    expr: variable("a"),
+                    r#type: data::Type::Number,
+                })),
+                variable("b"),
+            ),
+            data::Expr::New(Box::new(data::CallExpr {
+                expr: data::Expr::Call(Box::new(data::CallExpr {
+                    expr: variable("f"),
+                    parameter_list: vec![],
+                    is_optional_chain: false,
+                })),
+                parameter_list: vec![],
+                is_optional_chain: false,
+            })),
+            util::get(data::Expr::ObjectLiteral(Box::default()), "a"),
+            util::call_method(data::Expr::NumberLiteral(1), "toString", vec![]),
+            data::Expr::Lambda(Box::new(data::LambdaExpr {
+                parameter_list: vec![],
+                type_parameter_list: vec![],
+                return_type: data::Type::Object(Box::default()),
+                statement_list: vec![data::Statement::Return(data::Expr::ObjectLiteral(
+                    Box::default(),
+                ))],
+                is_async: false,
+                is_generator: false,
+            })),
+        ]
+        .into_iter()
+        .map(data::Statement::EvaluateExpr)
+        .collect(),
+    };
+    assert_eq!(
+        to_string(&code, &data::CodeType::TypeScript),
+        r#"/* eslint-disable */
+/* generated by js-ts-code-generator. Do not edit! */
+
+{
+  (a as number) < b;
+  new (f())();
+  ({  }.a);
+  (1).toString();
+  (): {  } => ({  });
+}"#
+    );
+}
+
+#[test]
+fn test_loop() {
+    let code = crate::json::from_json(
+        r#"{"formatVersion":4,"code":{"exportDefinitionList":[],"statementList":[
+            {"ForLoop":{"init":{"VariableDefinition":{"pattern":{"Identifer":"i"},"type":"Number",
+                "expr":{"NumberLiteral":0},"isConst":false}},
+                "test":{"BinaryOperator":{"operator":"LessThan","left":{"Variable":"i"},"right":{"NumberLiteral":10}}},
+                "update":{"Set":{"target":{"Variable":"i"},"operatorMaybe":"Addition","expr":{"NumberLiteral":2}}},
+                "statementList":[]}},
+            {"ForLoop":{"init":{"VariableDefinition":{"pattern":{"Identifer":"has"},"type":"Boolean",
+                "expr":{"BinaryOperator":{"operator":"In","left":{"StringLiteral":"key"},"right":{"Variable":"object"}}},
+                "isConst":false}},"test":null,
+                "update":{"Expr":{"UnaryOperator":{"operator":"PostfixIncrement","expr":{"Variable":"count"}}}},
+                "statementList":[]}},
+            {"ForLoop":{"init":null,"test":null,"update":null,"statementList":[{"ReturnVoid":null}]}},
+            {"While":{"condition":{"Variable":"isRunning"},"statementList":[]}},
+            {"DoWhile":{"condition":{"Variable":"isRunning"},"statementList":[]}},
+            {"ForIn":{"keyVariableName":"key","objectExpr":{"Variable":"object"},"statementList":[]}},
+            {"Labeled":{"label":"outer","statement":{"ForOf":{"elementPattern":{"Identifer":"row"},
+                "iterableExpr":{"Variable":"table"},"statementList":[
+                    {"ForOf":{"elementPattern":{"Identifer":"cell"},"iterableExpr":{"Variable":"row"},"statementList":[
+                        {"If":{"condition":{"Variable":"cell"},"thenStatementList":[{"LabeledContinue":"outer"}]}},
+                        {"LabeledBreak":"outer"}
+                    ]}}
+                ]}}}}
+        ]}}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        to_string(&code, &data::CodeType::TypeScript),
+        r#"/* eslint-disable */
+/* generated by js-ts-code-generator. Do not edit! */
+
+{
+  for (let i: number = 0; i < 10; i += 2) {
+
+  }
+  for (let has: boolean = ("key" in object);; count++) {
+
+  }
+  for (;;) {
+    return;
+  }
+  while (isRunning) {
+
+  }
+  do {
+
+  } while (isRunning);
+  for (const key in object) {
+
+  }
+  outer: for (const row of table){
+    for (const cell of row){
+      if (cell) {
+        continue outer;
+      }
+      break outer;
+    }
+  }
+}"#
+    );
+}
+
+#[test]
+fn test_switch() {
+    let code = crate::json::from_json(
+        r#"{"formatVersion":4,"code":{"exportDefinitionList":[],"statementList":[
+            {"Switch":{"expr":{"Variable":"color"},"patternList":[
+                {"caseList":[{"StringLiteral":"red"},{"StringLiteral":"green"}],"statementList":[
+                    {"EvaluateExpr":{"Call":{"expr":{"Variable":"warm"},"parameterList":[]}}},
+                    "Break"
+                ]},
+                {"caseList":[{"StringLiteral":"blue"}],"statementList":[]},
+                {"caseList":[{"StringLiteral":"black"}],"statementList":[
+                    {"VariableDefinition":{"pattern":{"Identifer":"level"},"type":"Number",
+                        "expr":{"NumberLiteral":0},"isConst":true}},
+                    {"Return":{"Variable":"level"}}
+                ]}
+            ],"exhaustiveType":{"Union":[{"StringLiteral":"red"},{"StringLiteral":"green"},
+                {"StringLiteral":"blue"},{"StringLiteral":"black"}]}}},
+            {"Switch":{"expr":{"Variable":"count"},"patternList":[
+                {"caseList":[{"NumberLiteral":0},{"BinaryOperator":{"operator":"Addition",
+                    "left":{"Variable":"limit"},"right":{"NumberLiteral":1}}}],"statementList":["ReturnVoid"]}
+            ],"defaultStatementList":[{"ThrowError":{"StringLiteral":"unknown"}}]}}
+        ]}}"#,
+    )
+    .unwrap();
+    assert_eq!(crate::validation::validate(&code), vec![]);
+    assert_eq!(
+        to_string(&code, &data::CodeType::TypeScript),
+        r#"/* eslint-disable */
+/* generated by js-ts-code-generator. Do not edit! */
+
+{
+  switch (color) {
+    case "red":
+    case "green":
+      warm();
+      break
+    case "blue":
+    case "black": {
+      const level: number = 0;
+      return level;
+    }
+    default: {
+      const exhaustiveCheck: never = color;
+      void exhaustiveCheck;
+    }
+  }
+  switch (count) {
+    case 0:
+    case limit + 1:
+      return;
+    default:
+      throw new Error("unknown");
+  }
+}"#
+    );
+    assert!(to_string(&code, &data::CodeType::JavaScript).contains(
+        r#"    case "black": {
+      const level = 0;
+      return level;
+    }
+  }
+  switch (count) {"#
+    ));
+}
+
+#[test]
+fn test_object_literal_member() {
+    let code = crate::json::from_json(
+        r#"{"formatVersion":4,"code":{"exportDefinitionList":[],"statementList":[
+            {"EvaluateExpr":{"ObjectLiteral":[
+                {"KeyValue":{"key":"0","value":{"StringLiteral":"zero"}}},
+                {"KeyValue":{"key":"01","value":{"StringLiteral":"zero one"}}},
+                {"KeyValue":{"key":"__proto__","value":"NullLiteral"}},
+                {"KeyValue":{"key":"__proto__","value":{"Variable":"__proto__"}}},
+                {"ComputedKeyValue":{"keyExpr":{"BinaryOperator":{"operator":"Comma",
+                    "left":{"Variable":"a"},"right":{"Variable":"b"}}},"value":{"NumberLiteral":1}}},
+                {"Method":{"key":{"Name":"add"},"typeParameterList":[],"parameterList":[
+                    {"pattern":{"Identifer":"x"},"type":"Number"}],"returnType":"Number",
+                    "statementList":[{"Return":{"Variable":"x"}}]}},
+                {"Method":{"key":{"Computed":{"Get":{"expr":{"GlobalObjects":"Symbol"},
+                    "propertyExpr":{"StringLiteral":"iterator"}}}},"typeParameterList":[],"parameterList":[],
+                    "returnType":"Void","statementList":[]}},
+                {"Getter":{"key":{"Name":"full-name"},"returnType":"String",
+                    "statementList":[{"Return":{"StringLiteral":""}}]}},
+                {"Setter":{"key":{"Name":"value"},"parameter":{"pattern":{"Identifer":"newValue"},"type":"String"},
+                    "statementList":["ReturnVoid"]}}
+            ]}}
+        ]}}"#,
+    )
+    .unwrap();
+    assert_eq!(crate::validation::validate(&code), vec![]);
+    assert_eq!(
+        to_string(&code, &data::CodeType::TypeScript),
+        r#"/* eslint-disable */
+/* generated by js-ts-code-generator. Do not edit! */
+
+{
+  ({ 0: "zero", "01": "zero one", ["__proto__"]: null, __proto__, [(a, b)]: 1, add(x: number): number {
+    return x;
+  }, [Symbol.iterator](): void {
+
+  }, get "full-name"(): string {
+    return "";
+  }, set value(newValue: string) {
+    return;
+  } });
+}"#
+    );
+}
+
+#[test]
+fn test_class() {
+    let code = crate::json::from_json(
+        r#"{"formatVersion":4,"code":{"exportDefinitionList":[
+            {"Default":{"document":"数を数える","type":"Never","expr":{"Class":{"name":"Counter",
+                "extends":{"ImportedVariable":{"moduleName":"./base","name":"Base"}},
+                "constructor":{"parameterList":[{"pattern":{"Identifer":"count"},"type":"Number"}],
+                    "superParameterList":[{"expr":{"StringLiteral":"counter"}}],
+                    "statementList":[{"Set":{"target":{"Get":{"expr":"This","propertyExpr":{"StringLiteral":"count"}}},
+                        "operatorMaybe":null,"expr":{"Variable":"count"}}}]},
+                "memberList":[
+                    {"kind":{"Field":{"key":{"Name":"count"},"type":"Number","value":{"NumberLiteral":0}}}},
+                    {"isStatic":true,"kind":{"Field":{"key":{"Name":"constructor"},"type":"String","value":null}}},
+                    {"kind":{"Method":{"key":{"Name":"increment"},"typeParameterList":[],"parameterList":[],
+                        "returnType":"Number","statementList":[{"Return":{"UnaryOperator":{"operator":"PrefixIncrement",
+                            "expr":{"Get":{"expr":"This","propertyExpr":{"StringLiteral":"count"}}}}}}]}}},
+                    {"isStatic":true,"kind":{"Method":{"key":{"Name":"create"},"typeParameterList":[],"parameterList":[],
+                        "returnType":{"WithTypeParameter":{"type":{"ScopeInGlobal":"Promise"},
+                            "typeParameterList":[{"ScopeInFile":"Counter"}]}},
+                        "statementList":[{"Return":{"New":{"expr":"This",
+                            "parameterList":[{"expr":{"NumberLiteral":0}}]}}}],"isAsync":true}}},
+                    {"kind":{"Getter":{"key":{"Name":"double"},"returnType":"Number",
+                        "statementList":[{"Return":{"BinaryOperator":{"operator":"Multiplication",
+                            "left":{"Get":{"expr":"This","propertyExpr":{"StringLiteral":"count"}}},
+                            "right":{"NumberLiteral":2}}}}]}}},
+                    {"kind":{"Setter":{"key":{"Name":"double"},"parameter":{"pattern":{"Identifer":"value"},"type":"Number"},
+                        "statementList":[]}}}
+                ]}}}}
+        ],"statementList":[
+            {"EvaluateExpr":{"Get":{"expr":{"Class":{"memberList":[]}},"propertyExpr":{"StringLiteral":"name"}}}}
+        ]}}"#,
+    )
+    .unwrap();
+    assert_eq!(crate::validation::validate(&code), vec![]);
+    assert_eq!(
+        to_string(&code, &data::CodeType::TypeScript),
+        r#"/* eslint-disable */
+/* generated by js-ts-code-generator. Do not edit! */
+
+import * as $$$$2e$2fbase from "./base";
+
+/**
+ * 数を数える
+ */
+export default class Counter extends $$$$2e$2fbase.Base {
+  constructor(count: number) {
+    super("counter");
+    this.count = count;
+  }
+  count: number = 0;
+  static ["constructor"]: string;
+  increment(): number {
+    return ++this.count;
+  }
+  static async create(): Promise<Counter> {
+    return new this(0);
+  }
+  get double(): number {
+    return this.count * 2;
+  }
+  set double(value: number) {
+
+  }
+}
+{
+  (class {}.name);
+}"#
+    );
+    assert_eq!(
+        to_string(&code, &data::CodeType::JavaScriptWithJsDoc),
+        r#"/* eslint-disable */
+/* generated by js-ts-code-generator. Do not edit! */
+
+// @ts-check
+import * as $$$$2e$2fbase from "./base";
+
+/**
+ * 数を数える
+ */
+export default class Counter extends $$$$2e$2fbase.Base {
+  /** @param {number} count */ constructor(count) {
+    super("counter");
+    this.count = count;
+  }
+  /** @type {number} */ count = 0;
+  /** @type {string} */ static ["constructor"];
+  /** @returns {number} */ increment() {
+    return ++this.count;
+  }
+  /** @returns {Promise<Counter>} */ static async create() {
+    return new this(0);
+  }
+  /** @returns {number} */ get double() {
+    return this.count * 2;
+  }
+  /** @param {number} value */ set double(value) {
+
+  }
+}
+{
+  (class {}.name);
+}"#
+    );
+    // 宣言ファイルでは `type` を使わずに クラスのメンバーの型を出力する
+    assert_eq!(
+        to_string(&code, &data::CodeType::Declaration),
+        r#"/* eslint-disable */
+/* generated by js-ts-code-generator. Do not edit! */
+
+import * as $$$$2e$2fbase from "./base";
+
+/**
+ * 数を数える
+ */
+export default class Counter extends $$$$2e$2fbase.Base {
+  constructor(count: number);
+  count: number;
+  static ["constructor"]: string;
+  increment(): number;
+  static create(): Promise<Counter>;
+  get double(): number;
+  set double(value: number);
+}
+"#
+    );
+    assert!(to_string_with_option(
+        &code,
+        &data::GenerateOption {
+            code_type: data::CodeType::JavaScript,
+            module_format: data::ModuleFormat::CommonJs,
+            function_style: data::FunctionStyle::Arrow,
+            is_ascii_only: false,
+            common_js_default_export: data::CommonJsDefaultExport::ModuleExports,
+        }
+    )
+    .contains(
+        "\nmodule.exports = class Counter extends $$$$2e$2fbase.Base {\n  constructor(count) {\n"
+    ));
+
+    // `class` で始まるが クラス式そのものではない式は クラス宣言として読まれないように括弧で囲む
+    let code = crate::json::from_json(
+        r#"{"formatVersion":4,"code":{"exportDefinitionList":[
+            {"Default":{"document":"","type":"String","expr":{"Get":{"expr":{"Class":{"memberList":[]}},
+                "propertyExpr":{"StringLiteral":"name"}}}}}
+        ],"statementList":[]}}"#,
+    )
+    .unwrap();
+    assert!(to_string(&code, &data::CodeType::TypeScript)
+        .ends_with("\nexport default (class {}.name);\n"));
+}
+
+#[test]
+fn test_generator() {
+    let code = crate::json::from_json(
+        r#"{"formatVersion":4,"code":{"exportDefinitionList":[
+            {"Function":{"name":"range","document":"","typeParameterList":[],
+                "parameterList":[{"name":"end","document":"","type":"Number"}],
+                "returnType":{"WithTypeParameter":{"type":{"ScopeInGlobal":"Generator"},
+                    "typeParameterList":["Number","Void","Undefined"]}},
+                "statementList":[
+                    {"EvaluateExpr":{"BinaryOperator":{"operator":"Addition",
+                        "left":{"Yield":{"expr":{"Variable":"end"},"isDelegate":false}},"right":{"NumberLiteral":1}}}},
+                    {"EvaluateExpr":{"Yield":{"expr":{"ArrayLiteral":[]},"isDelegate":true}}},
+                    {"EvaluateExpr":{"Yield":{"expr":null,"isDelegate":false}}}
+                ],"isGenerator":true}}
+        ],"statementList":[
+            {"EvaluateExpr":{"Call":{"expr":{"Lambda":{"parameterList":[],"typeParameterList":[],
+                "returnType":{"WithTypeParameter":{"type":{"ScopeInGlobal":"AsyncGenerator"},
+                    "typeParameterList":["String","Void","Undefined"]}},
+                "statementList":[
+                    {"ForOf":{"elementPattern":{"Identifer":"line"},"iterableExpr":{"Variable":"stream"},
+                        "statementList":[{"EvaluateExpr":{"Yield":{"expr":{"Variable":"line"},"isDelegate":false}}}],
+                        "isAwait":true}}
+                ],"isAsync":true,"isGenerator":true}},"parameterList":[]}}},
+            {"EvaluateExpr":{"Lambda":{"parameterList":[],"typeParameterList":[],"returnType":{
+                "WithTypeParameter":{"type":{"ScopeInGlobal":"Promise"},"typeParameterList":["Number"]}},
+                "statementList":[{"Return":{"BinaryOperator":{"operator":"Exponentiation",
+                    "left":{"Await":{"Variable":"promise"}},"right":{"NumberLiteral":2}}}}],"isAsync":true}}},
+            {"EvaluateExpr":{"ObjectLiteral":[{"Method":{"key":{"Name":"items"},"typeParameterList":[],
+                "parameterList":[],"returnType":{"WithTypeParameter":{"type":{"ScopeInGlobal":"AsyncGenerator"},
+                    "typeParameterList":["Number","Void","Undefined"]}},
+                "statementList":[],"isAsync":true,"isGenerator":true}}]}}
+        ]}}"#,
+    )
+    .unwrap();
+    assert_eq!(crate::validation::validate(&code), vec![]);
+    assert_eq!(
+        to_string(&code, &data::CodeType::TypeScript),
+        r#"/* eslint-disable */
+/* generated by js-ts-code-generator. Do not edit! */
+
+export const range = function* (end: number): Generator<number, void, undefined> {
+  (yield end) + 1;
+  yield* [];
+  yield;
+};
+
+{
+  (async function* (): AsyncGenerator<string, void, undefined> {
+    for await (const line of stream){
+      yield line;
+    }
+  }());
+  async (): Promise<number> => (await promise) ** 2;
+  ({ async *items(): AsyncGenerator<number, void, undefined> {
+
+  } });
+}"#
+    );
+}
+
+#[test]
+fn test_function_style() {
+    let code = crate::json::from_json(
+        r#"{"formatVersion":4,"code":{"exportDefinitionList":[
+            {"Function":{"name":"isEven","document":"偶数かどうか","typeParameterList":["t"],
+                "parameterList":[{"name":"n","document":"","type":"Number"}],"returnType":"Boolean",
+                "statementList":[{"Return":{"Call":{"expr":{"Variable":"isOdd"},"parameterList":[{"expr":{"Variable":"n"}}]}}}]}},
+            {"Function":{"name":"isOdd","document":"","typeParameterList":[],
+                "parameterList":[{"name":"n","document":"","type":"Number"}],"returnType":"Boolean",
+                "statementList":[{"Return":{"BooleanLiteral":true}}],"visibility":"Private","style":"Arrow"}}
+        ],"statementList":[
+            {"FunctionDefinition":{"name":"f","typeParameterList":[],"parameterList":[],
+                "returnType":"Void","statementList":[],"isAsync":true}}
+        ]}}"#,
+    )
+    .unwrap();
+    let option = |code_type, module_format| data::GenerateOption {
+        code_type,
+        module_format,
+        function_style: data::FunctionStyle::Declaration,
+        is_ascii_only: false,
+        common_js_default_export: data::CommonJsDefaultExport::ExportsDefault,
+    };
+    assert_eq!(
+        to_string_with_option(
+            &code,
+            &option(data::CodeType::TypeScript, data::ModuleFormat::EsModule)
+        ),
+        r#"/* eslint-disable */
+/* generated by js-ts-code-generator. Do not edit! */
+
+
+/**
+ * 偶数かどうか
+ */
+export function isEven<t extends unknown>(n: number): boolean {
+  return isOdd(n);
+}
+
+
+const isOdd = (n: number): boolean => true;
+
+{
+  async function f(): void {
+
+  }
+}"#
+    );
+    assert_eq!(
+        to_string_with_option(
+            &code,
+            &option(data::CodeType::JavaScript, data::ModuleFormat::CommonJs)
+        ),
+        r#"/* eslint-disable */
+/* generated by js-ts-code-generator. Do not edit! */
+
+
+/**
+ * 偶数かどうか
+ */
+function isEven(n) {
+  return isOdd(n);
+}
+exports.isEven = isEven;
+
+
+const isOdd = (n) => true;
+
+{
+  async function f() {
+
+  }
+}"#
+    );
+    assert_eq!(
+        to_string_with_option(
+            &code,
+            &option(
+                data::CodeType::JavaScriptWithJsDoc,
+                data::ModuleFormat::EsModule
+            )
+        ),
+        r#"/* eslint-disable */
+/* generated by js-ts-code-generator. Do not edit! */
+
+// @ts-check
+
+/**
+ * 偶数かどうか
+ * @template t
+ * @param {number} n
+ * @returns {boolean}
+ */
+export function isEven(n) {
+  return isOdd(n);
+}
+
+
+
+/**
+ * @param {number} n
+ * @returns {boolean}
+ */
+const isOdd = (n) => true;
+
+{
+  /**
+   * @returns {void}
+   */
+  async function f() {
+
+  }
+}"#
+    );
+    assert_eq!(
+        to_string_with_option(
+            &code,
+            &option(data::CodeType::Declaration, data::ModuleFormat::EsModule)
+        ),
+        r#"/* eslint-disable */
+/* generated by js-ts-code-generator. Do not edit! */
+
+
+/**
+ * 偶数かどうか
+ */
+export declare function isEven<t extends unknown>(n: number): boolean;
+
+
+"#
+    );
+}
+
+#[test]
+fn test_regexp_literal() {
+    let code = data::Code {
+        export_definition_list: vec![],
+        statement_list: vec![
+            data::Statement::EvaluateExpr(crate::util::call_method(
+                crate::util::regexp("^a/b[/]\\/c$", "gu"),
+                "test",
+                vec![data::Expr::Variable(data::identifer::from_string("s"))],
+            )),
+            data::Statement::EvaluateExpr(crate::util::division(
+                data::Expr::Variable(data::identifer::from_string("a")),
+                crate::util::regexp("", "g"),
+            )),
+            data::Statement::EvaluateExpr(crate::util::regexp("a\nb\\\n\u{2028}", "")),
+        ],
+    };
+    assert_eq!(crate::validation::validate(&code), vec![]);
+    assert_eq!(
+        to_string(&code, &data::CodeType::TypeScript),
+        r#"/* eslint-disable */
+/* generated by js-ts-code-generator. Do not edit! */
+
+{
+  /^a\/b[\/]\/c$/gu.test(s);
+  a / /(?:)/g;
+  /a\nb\n\u2028/;
+}"#
+    );
+}
+
+#[test]
+fn test_spread_parameter() {
+    let variable = |name: &str| data::Expr::Variable(data::identifer::from_string(name));
+    let code = data::Code {
+        export_definition_list: vec![],
+        statement_list: vec![
+            data::Statement::EvaluateExpr(data::Expr::Call(Box::new(data::CallExpr {
+                expr: variable("f"),
+                parameter_list: vec![
+                    crate::util::array_item(variable("a")),
+                    crate::util::spread(variable("args")),
+                    crate::util::spread(crate::util::comma(variable("b"), variable("c"))),
+                ],
+                is_optional_chain: true,
+            }))),
+            data::Statement::EvaluateExpr(data::Expr::New(Box::new(data::CallExpr {
+                expr: variable("Foo"),
+                parameter_list: vec![crate::util::spread(data::Expr::ConditionalOperator(
+                    Box::new(data::ConditionalOperatorExpr {
+                        condition: variable("a"),
+                        then_expr: variable("xs"),
+                        else_expr: data::Expr::ArrayLiteral(Box::default()),
+                    }),
+                ))],
+                is_optional_chain: false,
+            }))),
+        ],
+    };
+    assert_eq!(
+        to_string(&code, &data::CodeType::TypeScript),
+        r#"/* eslint-disable */
+/* generated by js-ts-code-generator. Do not edit! */
+
+{
+  f?.(a, ...args, ...(b, c));
+  new Foo(...a ? xs : []);
+}"#
+    );
+}
+
+#[test]
+fn test_comment() {
+    let code = crate::json::from_json(
+        r#"{"formatVersion":4,"code":{"exportDefinitionList":[
+            {"Variable":{"name":"config","document":"a */ b","type":{"ScopeInGlobal":"Config"},"expr":{"ObjectLiteral":[
+                {"KeyValue":{"key":"a","value":{"NumberLiteral":1}}},
+                {"Commented":{"commentList":[{"Line":"ポート番号"}],"member":{"KeyValue":{"key":"port","value":{"ArrayLiteral":[
+                    {"expr":{"NumberLiteral":80}},
+                    {"expr":{"NumberLiteral":8080},"commentList":[{"Block":"開発用 */"}]}
+                ]}}}}}
+            ]}}}
+        ],"statementList":[
+            {"Commented":{"leadingCommentList":[{"Line":"1行目\n\n3行目"},{"Block":"a\nb"}],
+                "statement":{"Labeled":{"label":"outer","statement":{"Commented":{"leadingCommentList":[],
+                    "statement":{"WhileTrue":[
+                        {"Commented":{"leadingCommentList":[{"EslintDisableNextLine":["no-console"]},{"TsExpectError":""}],
+                            "statement":{"EvaluateExpr":{"Call":{"expr":{"Variable":"f"},"parameterList":[
+                                {"expr":{"Variable":"a"},"commentList":[{"Line":"引数"}]}
+                            ]}}},
+                            "trailingComment":"末尾\n2行目"}},
+                        {"LabeledContinue":"outer"}
+                    ]},"trailingComment":null}}}},
+                "trailingComment":"ループ"}},
+            {"Switch":{"expr":{"Variable":"a"},"patternList":[{"caseList":[{"StringLiteral":"x"}],"statementList":[
+                {"Commented":{"leadingCommentList":[{"EslintDisableNextLine":[]}],
+                    "statement":{"VariableDefinition":{"pattern":{"Identifer":"b"},"type":"Number","expr":{"NumberLiteral":0},"isConst":true}}}},
+                "Break"
+            ]}]}}
+        ]}}"#,
+    )
+    .unwrap();
+    assert_eq!(crate::validation::validate(&code), vec![]);
+    assert_eq!(
+        to_string(&code, &data::CodeType::TypeScript),
+        r#"/* eslint-disable */
+/* generated by js-ts-code-generator. Do not edit! */
+
+
+/**
+ * a *\/ b
+ */
+export const config: Config = {
+  a: 1,
+  // ポート番号
+  port: [
+    80,
+    /* 開発用 *\/ */
+    8080
+  ]
+};
+
+{
+  // 1行目
+  //
+  // 3行目
+  /*
+   * a
+   * b
+   */
+  outer: while (true) {
+    // eslint-disable-next-line no-console
+    // @ts-expect-error
+    f(
+      // 引数
+      a
+    ); // 末尾
+    // 2行目
+    continue outer;
+  } // ループ
+  switch (a) {
+    case "x": {
+      // eslint-disable-next-line
+      const b: number = 0;
+      break
     }
+  }
+}"#
+    );
+}
+
+#[test]
+fn test_document_tag() {
+    let code = crate::json::from_json(
+        r#"{"formatVersion":4,"code":{"exportDefinitionList":[
+            {"TypeAlias":{"name":"User","typeParameterList":[],"document":"ユーザー","documentTagList":[{"Since":"1.2.0"}],
+                "type":{"Object":[{"name":"id","required":true,"type":"String","document":"/* ID */",
+                    "documentTagList":[{"Deprecated":""},{"See":"https://example.com/id"}]}]}}},
+            {"Function":{"name":"parse","document":"文字列を読む","documentTagList":[
+                    {"Example":"\nparse(\"a\");\n\nparse(\"*/\");\n"},
+                    {"Returns":"読み取った値"},
+                    {"Throws":"{SyntaxError} 形式が正しくないとき"}
+                ],"typeParameterList":[],
+                "parameterList":[{"name":"text","document":"","type":"String"}],"returnType":"Number",
+                "statementList":[{"Return":{"NumberLiteral":0}}]}},
+            {"Variable":{"name":"limit","document":"","documentTagList":[{"Deprecated":"maxLimit を使う"}],
+                "type":"Number","expr":{"NumberLiteral":1}}}
+        ],"statementList":[]}}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        to_string(&code, &data::CodeType::TypeScript),
+        r#"/* eslint-disable */
+/* generated by js-ts-code-generator. Do not edit! */
+
+
+/**
+ * ユーザー
+ * @since 1.2.0
+ */
+export type User = { 
+/**
+ * /* ID *\/
+ * @deprecated
+ * @see https://example.com/id
+ */
+readonly id: string };
+
+
+
+/**
+ * 文字列を読む
+ *
+ * @example
+ * ```ts
+ * parse("a");
+ *
+ * parse("*\/");
+ * ```
+ * @returns 読み取った値
+ * @throws {SyntaxError} 形式が正しくないとき
+ */
+export const parse = (text: string): number => 0;
+
+
+
+/**
+ * @deprecated maxLimit を使う
+ */
+export const limit: number = 1;
+
+"#
+    );
+    assert_eq!(
+        to_string(&code, &data::CodeType::JavaScriptWithJsDoc),
+        r#"/* eslint-disable */
+/* generated by js-ts-code-generator. Do not edit! */
+
+// @ts-check
+
+/**
+ * ユーザー
+ * @since 1.2.0
+ * @typedef {{ readonly id: string }} User
+ */
+
+
+
+/**
+ * 文字列を読む
+ * @param {string} text
+ * @example
+ * ```js
+ * parse("a");
+ *
+ * parse("*\/");
+ * ```
+ * @returns {number} 読み取った値
+ * @throws {SyntaxError} 形式が正しくないとき
+ */
+export const parse = (text) => 0;
+
+
+
+/**
+ * @deprecated maxLimit を使う
+ * @type {number}
+ */
+export const limit = 1;
+
+"#
+    );
+}
+
+#[test]
+fn test_ascii_only() {
+    let code = crate::json::from_json(
+        r#"{"formatVersion":4,"code":{"exportDefinitionList":[
+            {"TypeAlias":{"name":"Color","typeParameterList":[],"document":"","type":
+                {"Union":[{"StringLiteral":"赤"},{"StringLiteral":"blue"}]}}}
+        ],"statementList":[
+            {"EvaluateExpr":{"ObjectLiteral":[{"KeyValue":{"key":"名前","value":{"StringLiteral":"café\u2028"}}},
+                {"KeyValue":{"key":"name","value":{"Get":{"expr":{"Variable":"a"},"propertyExpr":{"StringLiteral":"値"}}}}}]}},
+            {"Switch":{"expr":{"Variable":"color"},"patternList":[{"caseList":[{"StringLiteral":"赤"}],"statementList":["Break"]}]}}
+        ]}}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        to_string_with_option(
+            &code,
+            &data::GenerateOption {
+                code_type: data::CodeType::TypeScript,
+                module_format: data::ModuleFormat::EsModule,
+                function_style: data::FunctionStyle::Arrow,
+                is_ascii_only: true,
+                common_js_default_export: data::CommonJsDefaultExport::ExportsDefault,
+            }
+        ),
+        r#"/* eslint-disable */
+/* generated by js-ts-code-generator. Do not edit! */
+
+export type Color = "\u8D64" | "blue";
+
+{
+  ({ "\u540D\u524D": "caf\u00E9\u2028", name: a["\u5024"] });
+  switch (color) {
+    case "\u8D64":
+      break
+  }
+}"#
+    );
+    assert_eq!(
+        to_string(&code, &data::CodeType::TypeScript),
+        r#"/* eslint-disable */
+/* generated by js-ts-code-generator. Do not edit! */
+
+export type Color = "赤" | "blue";
+
+{
+  ({ "名前": "café\u2028", name: a["値"] });
+  switch (color) {
+    case "赤":
+      break
+  }
+}"#
+    );
 }
@@ -5,6 +5,16 @@ pub fn get(expr: Expr, property_name: &str) -> Expr {
     Expr::Get(Box::new(GetExpr {
         expr,
         property_expr: Expr::StringLiteral(String::from(property_name)),
+        is_optional_chain: false,
+    }))
+}
+
+/// optional chaining でプロパティの値を取得する `expr?.propertyName`
+pub fn optional_get(expr: Expr, property_name: &str) -> Expr {
+    Expr::Get(Box::new(GetExpr {
+        expr,
+        property_expr: Expr::StringLiteral(String::from(property_name)),
+        is_optional_chain: true,
     }))
 }
 
@@ -12,10 +22,29 @@ pub fn get(expr: Expr, property_name: &str) -> Expr {
 pub fn call_method(expr: Expr, method_name: &str, parameter_list: Vec<Expr>) -> Expr {
     Expr::Call(Box::new(CallExpr {
         expr: get(expr, method_name),
-        parameter_list,
+        parameter_list: parameter_list.into_iter().map(array_item).collect(),
+        is_optional_chain: false,
     }))
 }
 
+/// 配列リテラルの要素や 関数呼び出しのパラメーター `a`
+pub fn array_item(expr: Expr) -> ArrayItem {
+    ArrayItem {
+        expr,
+        spread: false,
+        comment_list: vec![],
+    }
+}
+
+/// スプレッドした配列リテラルの要素や 関数呼び出しのパラメーター `...a`
+pub fn spread(expr: Expr) -> ArrayItem {
+    ArrayItem {
+        expr,
+        spread: true,
+        comment_list: vec![],
+    }
+}
+
 /// 単項マイナス演算子 `-a`
 pub fn minus(expr: Expr) -> Expr {
     Expr::UnaryOperator(Box::new(UnaryOperatorExpr {
@@ -43,13 +72,77 @@ pub fn logical_not(expr: Expr) -> Expr {
     }))
 }
 
+/// 単項プラス演算子 `+a`
+pub fn unary_plus(expr: Expr) -> Expr {
+    Expr::UnaryOperator(Box::new(UnaryOperatorExpr {
+        operator: UnaryOperator::Plus,
+        expr,
+    }))
+}
+
+/// 型の名前を文字列で得る `typeof a`
+pub fn type_of(expr: Expr) -> Expr {
+    Expr::UnaryOperator(Box::new(UnaryOperatorExpr {
+        operator: UnaryOperator::TypeOf,
+        expr,
+    }))
+}
+
+/// 式を評価して undefined を返す `void a`
+pub fn void_operator(expr: Expr) -> Expr {
+    Expr::UnaryOperator(Box::new(UnaryOperatorExpr {
+        operator: UnaryOperator::Void,
+        expr,
+    }))
+}
+
+/// プロパティを削除する `delete a.b`
+pub fn delete(expr: Expr) -> Expr {
+    Expr::UnaryOperator(Box::new(UnaryOperatorExpr {
+        operator: UnaryOperator::Delete,
+        expr,
+    }))
+}
+
+/// 前置インクリメント `++a`
+pub fn prefix_increment(expr: Expr) -> Expr {
+    Expr::UnaryOperator(Box::new(UnaryOperatorExpr {
+        operator: UnaryOperator::PrefixIncrement,
+        expr,
+    }))
+}
+
+/// 前置デクリメント `--a`
+pub fn prefix_decrement(expr: Expr) -> Expr {
+    Expr::UnaryOperator(Box::new(UnaryOperatorExpr {
+        operator: UnaryOperator::PrefixDecrement,
+        expr,
+    }))
+}
+
+/// 後置インクリメント `a++`
+pub fn postfix_increment(expr: Expr) -> Expr {
+    Expr::UnaryOperator(Box::new(UnaryOperatorExpr {
+        operator: UnaryOperator::PostfixIncrement,
+        expr,
+    }))
+}
+
+/// 後置デクリメント `a--`
+pub fn postfix_decrement(expr: Expr) -> Expr {
+    Expr::UnaryOperator(Box::new(UnaryOperatorExpr {
+        operator: UnaryOperator::PostfixDecrement,
+        expr,
+    }))
+}
+
 /// べき乗
 /// ```ts
 /// a ** b
 /// ```
 pub fn exponentiation(left: Expr, right: Expr) -> Expr {
     Expr::BinaryOperator(Box::new(BinaryOperatorExpr {
-        operator: BinaryOperator::Multiplication,
+        operator: BinaryOperator::Exponentiation,
         left,
         right,
     }))
@@ -183,6 +276,58 @@ pub fn less_than_or_equal(left: Expr, right: Expr) -> Expr {
         right,
     }))
 }
+
+/**
+ * より大きい `a > b`
+ * @param left 左辺
+ * @param right 右辺
+ */
+pub fn greater_than(left: Expr, right: Expr) -> Expr {
+    Expr::BinaryOperator(Box::new(BinaryOperatorExpr {
+        operator: BinaryOperator::GreaterThan,
+        left,
+        right,
+    }))
+}
+
+/**
+ * 以上 `a >= b`
+ * @param left 左辺
+ * @param right 右辺
+ */
+pub fn greater_than_or_equal(left: Expr, right: Expr) -> Expr {
+    Expr::BinaryOperator(Box::new(BinaryOperatorExpr {
+        operator: BinaryOperator::GreaterThanOrEqual,
+        left,
+        right,
+    }))
+}
+
+/**
+ * プロパティを持っているか `a in b`
+ * @param left 左辺
+ * @param right 右辺
+ */
+pub fn in_operator(left: Expr, right: Expr) -> Expr {
+    Expr::BinaryOperator(Box::new(BinaryOperatorExpr {
+        operator: BinaryOperator::In,
+        left,
+        right,
+    }))
+}
+
+/**
+ * インスタンスかどうか `a instanceof b`
+ * @param left 左辺
+ * @param right 右辺
+ */
+pub fn instance_of(left: Expr, right: Expr) -> Expr {
+    Expr::BinaryOperator(Box::new(BinaryOperatorExpr {
+        operator: BinaryOperator::InstanceOf,
+        left,
+        right,
+    }))
+}
 /**
  * 等号 `a === b`
  * @param left 左辺
@@ -209,6 +354,32 @@ pub fn not_equal(left: Expr, right: Expr) -> Expr {
     }))
 }
 
+/**
+ * 型変換をする等号 `a == b`
+ * @param left 左辺
+ * @param right 右辺
+ */
+pub fn loose_equal(left: Expr, right: Expr) -> Expr {
+    Expr::BinaryOperator(Box::new(BinaryOperatorExpr {
+        operator: BinaryOperator::LooseEqual,
+        left,
+        right,
+    }))
+}
+
+/**
+ * 型変換をする不等号 `a != b`
+ * @param left 左辺
+ * @param right 右辺
+ */
+pub fn loose_not_equal(left: Expr, right: Expr) -> Expr {
+    Expr::BinaryOperator(Box::new(BinaryOperatorExpr {
+        operator: BinaryOperator::LooseNotEqual,
+        left,
+        right,
+    }))
+}
+
 /**
  * ビットAND `a & b`
  * @param left 左辺
@@ -273,6 +444,69 @@ pub fn logical_or(left: Expr, right: Expr) -> Expr {
     }))
 }
 
+/**
+ * Null合体 `a ?? b`
+ * @param left 左辺
+ * @param right 右辺
+ */
+pub fn nullish_coalescing(left: Expr, right: Expr) -> Expr {
+    Expr::BinaryOperator(Box::new(BinaryOperatorExpr {
+        operator: BinaryOperator::NullishCoalescing,
+        left,
+        right,
+    }))
+}
+
+/**
+ * カンマ演算子 `a, b`
+ * @param left 左辺
+ * @param right 右辺
+ */
+pub fn comma(left: Expr, right: Expr) -> Expr {
+    Expr::BinaryOperator(Box::new(BinaryOperatorExpr {
+        operator: BinaryOperator::Comma,
+        left,
+        right,
+    }))
+}
+
+/**
+ * `yield expr`. ジェネレーター関数の中でのみ使える
+ */
+pub fn yield_value(expr: Expr) -> Expr {
+    Expr::Yield(Box::new(YieldExpr {
+        expr: Some(expr),
+        is_delegate: false,
+    }))
+}
+
+/**
+ * `yield* iterable`. 他のイテレーターに委譲する
+ */
+pub fn yield_delegate(iterable: Expr) -> Expr {
+    Expr::Yield(Box::new(YieldExpr {
+        expr: Some(iterable),
+        is_delegate: true,
+    }))
+}
+
+/**
+ * `await expr`. async 関数の中でのみ使える
+ */
+pub fn await_value(expr: Expr) -> Expr {
+    Expr::Await(Box::new(expr))
+}
+
+/**
+ * 正規表現リテラル `/pattern/flags`. パターンは `new RegExp(pattern)` に渡す文字列と同じ
+ */
+pub fn regexp(pattern: &str, flags: &str) -> Expr {
+    Expr::RegExpLiteral(RegExpLiteral {
+        pattern: String::from(pattern),
+        flags: String::from(flags),
+    })
+}
+
 /**
  * ```ts
  * Number.parseInt(parameter)
@@ -310,6 +544,7 @@ pub fn new_date() -> Expr {
     Expr::New(Box::new(CallExpr {
         expr: Expr::GlobalObjects(identifer::from_string("Date")),
         parameter_list: vec![],
+        is_optional_chain: false,
     }))
 }
 
@@ -321,7 +556,8 @@ pub fn new_date() -> Expr {
 pub fn new_uint8array(length_or_iterable: Expr) -> Expr {
     Expr::New(Box::new(CallExpr {
         expr: Expr::GlobalObjects(identifer::from_string("Uint8Array")),
-        parameter_list: vec![length_or_iterable],
+        parameter_list: vec![array_item(length_or_iterable)],
+        is_optional_chain: false,
     }))
 }
 
@@ -333,7 +569,8 @@ pub fn new_uint8array(length_or_iterable: Expr) -> Expr {
 pub fn new_map(init_key_value_list: Expr) -> Expr {
     Expr::New(Box::new(CallExpr {
         expr: Expr::GlobalObjects(identifer::from_string("Map")),
-        parameter_list: vec![init_key_value_list],
+        parameter_list: vec![array_item(init_key_value_list)],
+        is_optional_chain: false,
     }))
 }
 
@@ -345,7 +582,8 @@ pub fn new_map(init_key_value_list: Expr) -> Expr {
 pub fn new_set(init_value_list: Expr) -> Expr {
     Expr::New(Box::new(CallExpr {
         expr: Expr::GlobalObjects(identifer::from_string("Set")),
-        parameter_list: vec![init_value_list],
+        parameter_list: vec![array_item(init_value_list)],
+        is_optional_chain: false,
     }))
 }
 
@@ -399,6 +637,26 @@ pub fn promise_type(return_type: Type) -> Type {
     }))
 }
 
+/**
+ * `Generator<yieldType, returnType, nextType>`
+ */
+pub fn generator_type(yield_type: Type, return_type: Type, next_type: Type) -> Type {
+    Type::WithTypeParameter(Box::new(TypeWithTypeParameter {
+        r#type: Type::ScopeInGlobal(identifer::from_string("Generator")),
+        type_parameter_list: vec![yield_type, return_type, next_type],
+    }))
+}
+
+/**
+ * `AsyncGenerator<yieldType, returnType, nextType>`
+ */
+pub fn async_generator_type(yield_type: Type, return_type: Type, next_type: Type) -> Type {
+    Type::WithTypeParameter(Box::new(TypeWithTypeParameter {
+        r#type: Type::ScopeInGlobal(identifer::from_string("AsyncGenerator")),
+        type_parameter_list: vec![yield_type, return_type, next_type],
+    }))
+}
+
 /**
  * `Date`
  */
@@ -445,3 +703,86 @@ pub fn readonly_set_type(element_type: Type) -> Type {
         type_parameter_list: vec![element_type],
     }))
 }
+
+/// 複数のモジュールで export されている名前を まとめて export するコード (barrel) を作る.
+/// `module_list` は モジュール名とそのモジュールのコードの組のリスト
+///
+/// ```ts
+/// export { a, b } from "./x";
+/// export type { T } from "./x";
+/// ```
+///
+/// `export * from` を含むモジュールは export される名前が分からないので, `export * from` にする
+pub fn barrel(module_list: &[(&str, &Code)]) -> Code {
+    let mut export_definition_list = Vec::new();
+    for (module_name, code) in module_list {
+        let has_export_all =
+            code.export_definition_list
+                .iter()
+                .any(|export_definition| match export_definition {
+                    ExportDefinition::ExportAll(export_all) => export_all.namespace.is_none(),
+                    _ => false,
+                });
+        if has_export_all {
+            export_definition_list.push(ExportDefinition::ExportAll(ExportAll {
+                module_name: module_name.to_string(),
+                namespace: None,
+            }));
+            continue;
+        }
+        let mut value_name_list = Vec::new();
+        let mut type_name_list = Vec::new();
+        for export_definition in &code.export_definition_list {
+            match export_definition {
+                ExportDefinition::TypeAlias(type_alias) => {
+                    if type_alias.visibility == Visibility::Export {
+                        type_name_list.push(type_alias.name.clone());
+                    }
+                }
+                ExportDefinition::Function(function) => {
+                    if function.visibility == Visibility::Export {
+                        value_name_list.push(function.name.clone());
+                    }
+                }
+                ExportDefinition::Variable(variable) => {
+                    if variable.visibility == Visibility::Export {
+                        value_name_list.push(variable.name.clone());
+                    }
+                }
+                ExportDefinition::ExportFrom(export_from) => {
+                    for export_name in &export_from.name_list {
+                        let name = export_name.alias.as_ref().unwrap_or(&export_name.name);
+                        if export_from.is_type_only {
+                            type_name_list.push(name.clone());
+                        } else {
+                            value_name_list.push(name.clone());
+                        }
+                    }
+                }
+                ExportDefinition::ExportAll(export_all) => {
+                    if let Some(namespace) = &export_all.namespace {
+                        value_name_list.push(namespace.clone());
+                    }
+                }
+                // default export は 複数のモジュールのものをまとめられないので含めない
+                ExportDefinition::Default(_) => {}
+            }
+        }
+        for (name_list, is_type_only) in [(value_name_list, false), (type_name_list, true)] {
+            if !name_list.is_empty() {
+                export_definition_list.push(ExportDefinition::ExportFrom(ExportFrom {
+                    module_name: module_name.to_string(),
+                    name_list: name_list
+                        .into_iter()
+                        .map(|name| ExportName { name, alias: None })
+                        .collect(),
+                    is_type_only,
+                }));
+            }
+        }
+    }
+    Code {
+        export_definition_list,
+        statement_list: vec![],
+    }
+}
@@ -0,0 +1,1594 @@
+use crate::data;
+use crate::data::ValidationError;
+
+/// コードを検証する. 問題がなければ空のリストを返す
+///
+/// JSON や bincode から読み取った `data::Code` は `identifer::from_string` を通っていないため,
+/// 識別子として使えない文字列が含まれている可能性がある
+pub fn validate(code: &data::Code) -> Vec<ValidationError> {
+    let mut error_list = Vec::new();
+    // 名前と, それが型の名前空間のものかどうか. 型と値は名前空間が別なので, 同じ名前でも問題ない
+    let mut local_name_list: Vec<(String, bool)> = Vec::new();
+    let mut export_name_list: Vec<(String, bool)> = Vec::new();
+    for export_definition in &code.export_definition_list {
+        // `export { a } from` はファイルの中の束縛を作らないので, ファイルの中の名前と export される名前は別々に比べる
+        let mut duplicate_name_list: Vec<String> = Vec::new();
+        for (name_list, defined_name_list) in [
+            (
+                &mut local_name_list,
+                local_definition_name_list(export_definition),
+            ),
+            (
+                &mut export_name_list,
+                export_definition_name_list(export_definition),
+            ),
+        ] {
+            for defined_name in defined_name_list {
+                if !name_list.contains(&defined_name) {
+                    name_list.push(defined_name);
+                } else if !duplicate_name_list.contains(&defined_name.0) {
+                    duplicate_name_list.push(defined_name.0);
+                }
+            }
+        }
+        for name in duplicate_name_list {
+            error_list.push(if name == "default" {
+                ValidationError::MultipleDefaultExport
+            } else {
+                ValidationError::DuplicateExportName(name)
+            });
+        }
+        export_definition_validate(export_definition, &mut error_list);
+    }
+    function_body_validate(
+        &code.statement_list,
+        FunctionContext::OUTSIDE,
+        &mut error_list,
+    );
+    error_list
+}
+
+/// コードを検証し, さらに `option` で出力できるかを検証する. 問題がなければ空のリストを返す
+pub fn validate_with_option(
+    code: &data::Code,
+    option: &data::GenerateOption,
+) -> Vec<ValidationError> {
+    let mut error_list = validate(code);
+    error_list.extend(option_validate(code, option));
+    error_list
+}
+
+/// `option` で出力できるかを検証する. コード自体の検証は `validate` で行う
+pub fn option_validate(code: &data::Code, option: &data::GenerateOption) -> Vec<ValidationError> {
+    let is_common_js = option.module_format == data::ModuleFormat::CommonJs
+        && matches!(
+            option.code_type,
+            data::CodeType::JavaScript | data::CodeType::JavaScriptWithJsDoc
+        );
+    let has_default_export = code
+        .export_definition_list
+        .iter()
+        .any(|export_definition| matches!(export_definition, data::ExportDefinition::Default(_)));
+    // `module.exports` を置き換えると, それまでに `exports` に代入した値が失われる
+    if is_common_js
+        && option.common_js_default_export == data::CommonJsDefaultExport::ModuleExports
+        && has_default_export
+        && crate::to_string::has_named_value_export(code)
+    {
+        vec![ValidationError::NamedExportWithModuleExports]
+    } else {
+        vec![]
+    }
+}
+
+/// 検証している文や式を囲んでいる 一番内側の関数の種類
+#[derive(Clone, Copy)]
+struct FunctionContext {
+    /// async 関数の中. `await` と `for await` を使える
+    is_async: bool,
+
+    /// ジェネレーター関数の中. `yield` を使える
+    is_generator: bool,
+}
+
+impl FunctionContext {
+    /// 関数の外側 (トップレベル) や, ゲッター, セッター, パラメーターのデフォルト値
+    const OUTSIDE: FunctionContext = FunctionContext {
+        is_async: false,
+        is_generator: false,
+    };
+}
+
+/// トップレベルの定義がファイルの中に作る束縛の名前と, それが型の名前空間のものかどうか
+fn local_definition_name_list(export_definition: &data::ExportDefinition) -> Vec<(String, bool)> {
+    match export_definition {
+        data::ExportDefinition::TypeAlias(type_alias) => vec![(type_alias.name.get(), true)],
+        data::ExportDefinition::Function(function) => vec![(function.name.get(), false)],
+        data::ExportDefinition::Variable(variable) => vec![(variable.name.get(), false)],
+        // `export default class A {}` は クラス名の値と型を作る
+        data::ExportDefinition::Default(data::DefaultExport {
+            expr: data::Expr::Class(class_expr),
+            ..
+        }) => match &class_expr.name {
+            Some(name) => vec![(name.get(), false), (name.get(), true)],
+            None => vec![],
+        },
+        data::ExportDefinition::ExportFrom(_)
+        | data::ExportDefinition::ExportAll(_)
+        | data::ExportDefinition::Default(_) => vec![],
+    }
+}
+
+/// トップレベルの定義が export する名前と, それが型の名前空間のものかどうか
+fn export_definition_name_list(export_definition: &data::ExportDefinition) -> Vec<(String, bool)> {
+    match export_definition {
+        data::ExportDefinition::TypeAlias(data::TypeAlias {
+            visibility: data::Visibility::Export,
+            ..
+        })
+        | data::ExportDefinition::Function(data::Function {
+            visibility: data::Visibility::Export,
+            ..
+        })
+        | data::ExportDefinition::Variable(data::Variable {
+            visibility: data::Visibility::Export,
+            ..
+        }) => local_definition_name_list(export_definition),
+        data::ExportDefinition::TypeAlias(_)
+        | data::ExportDefinition::Function(_)
+        | data::ExportDefinition::Variable(_) => vec![],
+        data::ExportDefinition::ExportFrom(export_from) => export_from
+            .name_list
+            .iter()
+            .map(|export_name| {
+                (
+                    export_name
+                        .alias
+                        .as_ref()
+                        .unwrap_or(&export_name.name)
+                        .get(),
+                    export_from.is_type_only,
+                )
+            })
+            .collect(),
+        data::ExportDefinition::ExportAll(export_all) => match &export_all.namespace {
+            Some(namespace) => vec![(namespace.get(), false)],
+            None => vec![],
+        },
+        data::ExportDefinition::Default(_) => vec![(String::from("default"), false)],
+    }
+}
+
+fn export_definition_validate(
+    export_definition: &data::ExportDefinition,
+    error_list: &mut Vec<ValidationError>,
+) {
+    match export_definition {
+        data::ExportDefinition::TypeAlias(type_alias) => {
+            identifer_validate(&type_alias.name, error_list);
+            identifer_list_validate(&type_alias.type_parameter_list, error_list);
+            type_validate(&type_alias.r#type, error_list);
+        }
+        data::ExportDefinition::Function(function) => {
+            identifer_validate(&function.name, error_list);
+            identifer_list_validate(&function.type_parameter_list, error_list);
+            parameter_with_document_list_validate(&function.parameter_list, error_list);
+            type_validate(&function.return_type, error_list);
+            function_body_validate(
+                &function.statement_list,
+                FunctionContext {
+                    is_async: function.is_async,
+                    is_generator: function.is_generator,
+                },
+                error_list,
+            );
+        }
+        data::ExportDefinition::Variable(variable) => {
+            identifer_validate(&variable.name, error_list);
+            type_validate(&variable.r#type, error_list);
+            expr_validate(&variable.expr, FunctionContext::OUTSIDE, error_list);
+        }
+        data::ExportDefinition::ExportFrom(export_from) => {
+            for export_name in &export_from.name_list {
+                export_name_validate(&export_name.name, error_list);
+                if let Some(alias) = &export_name.alias {
+                    export_name_validate(alias, error_list);
+                }
+            }
+        }
+        data::ExportDefinition::ExportAll(export_all) => {
+            if let Some(namespace) = &export_all.namespace {
+                identifer_validate(namespace, error_list);
+            }
+        }
+        data::ExportDefinition::Default(default_export) => {
+            type_validate(&default_export.r#type, error_list);
+            expr_validate(&default_export.expr, FunctionContext::OUTSIDE, error_list);
+        }
+    }
+}
+
+/// `export { a as b } from` の名前. 識別子に加えて `default` も使える
+fn export_name_validate(
+    identifer: &data::identifer::Identifer,
+    error_list: &mut Vec<ValidationError>,
+) {
+    if identifer.get() != "default" {
+        identifer_validate(identifer, error_list);
+    }
+}
+
+fn identifer_validate(
+    identifer: &data::identifer::Identifer,
+    error_list: &mut Vec<ValidationError>,
+) {
+    if !data::identifer::is_identifer(&identifer.get()) {
+        error_list.push(ValidationError::InvalidIdentifer(identifer.get()));
+    }
+}
+
+fn identifer_list_validate(
+    identifer_list: &[data::identifer::Identifer],
+    error_list: &mut Vec<ValidationError>,
+) {
+    for identifer in identifer_list {
+        identifer_validate(identifer, error_list);
+    }
+}
+
+fn parameter_with_document_list_validate(
+    parameter_list: &[data::ParameterWithDocument],
+    error_list: &mut Vec<ValidationError>,
+) {
+    for parameter in parameter_list {
+        identifer_validate(&parameter.name, error_list);
+        type_validate(&parameter.r#type, error_list);
+    }
+    parameter_kind_list_validate(
+        &parameter_list
+            .iter()
+            .map(|parameter| &parameter.kind)
+            .collect::<Vec<&data::ParameterKind>>(),
+        error_list,
+    );
+}
+
+/// 必須のパラメーターが省略可能なパラメーターの後にないか, 残余パラメーターが最後にあるかを検証する.
+/// デフォルト値は式として検証する
+fn parameter_kind_list_validate(
+    kind_list: &[&data::ParameterKind],
+    error_list: &mut Vec<ValidationError>,
+) {
+    let mut is_after_optional = false;
+    for (index, kind) in kind_list.iter().enumerate() {
+        match kind {
+            data::ParameterKind::Required => {
+                if is_after_optional {
+                    error_list.push(ValidationError::RequiredParameterAfterOptional(index));
+                }
+            }
+            data::ParameterKind::Optional => is_after_optional = true,
+            data::ParameterKind::Default(default_expr) => {
+                is_after_optional = true;
+                // パラメーターのデフォルト値では `yield` を使えない
+                expr_validate(default_expr, FunctionContext::OUTSIDE, error_list);
+            }
+            data::ParameterKind::Rest => {
+                if index + 1 != kind_list.len() {
+                    error_list.push(ValidationError::RestParameterNotLast(index));
+                }
+            }
+        }
+    }
+}
+
+fn lambda_parameter_list_validate(
+    parameter_list: &[data::Parameter],
+    error_list: &mut Vec<ValidationError>,
+) {
+    for parameter in parameter_list {
+        binding_pattern_validate(&parameter.pattern, FunctionContext::OUTSIDE, error_list);
+        type_validate(&parameter.r#type, error_list);
+    }
+    parameter_kind_list_validate(
+        &parameter_list
+            .iter()
+            .map(|parameter| &parameter.kind)
+            .collect::<Vec<&data::ParameterKind>>(),
+        error_list,
+    );
+}
+
+fn property_key_validate(
+    property_key: &data::PropertyKey,
+    function_context: FunctionContext,
+    error_list: &mut Vec<ValidationError>,
+) {
+    if let data::PropertyKey::Computed(expr) = property_key {
+        expr_validate(expr, function_context, error_list);
+    }
+}
+
+fn statement_list_validate(
+    statement_list: &[data::Statement],
+    function_context: FunctionContext,
+    error_list: &mut Vec<ValidationError>,
+) {
+    for statement in statement_list {
+        statement_validate(statement, function_context, error_list);
+    }
+}
+
+fn statement_validate(
+    statement: &data::Statement,
+    function_context: FunctionContext,
+    error_list: &mut Vec<ValidationError>,
+) {
+    match statement {
+        data::Statement::EvaluateExpr(expr)
+        | data::Statement::ThrowError(expr)
+        | data::Statement::Return(expr) => expr_validate(expr, function_context, error_list),
+        data::Statement::Set(set_statement) => {
+            set_statement_validate(set_statement, function_context, error_list)
+        }
+        data::Statement::If(if_statement) => {
+            expr_validate(&if_statement.condition, function_context, error_list);
+            statement_list_validate(
+                &if_statement.then_statement_list,
+                function_context,
+                error_list,
+            );
+        }
+        data::Statement::ReturnVoid | data::Statement::Continue | data::Statement::Break => {}
+        data::Statement::VariableDefinition(variable_definition) => {
+            variable_definition_validate(variable_definition, function_context, error_list)
+        }
+        data::Statement::FunctionDefinition(function_definition) => {
+            identifer_validate(&function_definition.name, error_list);
+            identifer_list_validate(&function_definition.type_parameter_list, error_list);
+            parameter_with_document_list_validate(&function_definition.parameter_list, error_list);
+            type_validate(&function_definition.return_type, error_list);
+            function_body_validate(
+                &function_definition.statement_list,
+                FunctionContext {
+                    is_async: function_definition.is_async,
+                    is_generator: function_definition.is_generator,
+                },
+                error_list,
+            );
+        }
+        data::Statement::For(for_statement) => {
+            identifer_validate(&for_statement.counter_variable_name, error_list);
+            expr_validate(&for_statement.until_expr, function_context, error_list);
+            statement_list_validate(&for_statement.statement_list, function_context, error_list);
+        }
+        data::Statement::ForOf(for_of_statement) => {
+            if for_of_statement.is_await && !function_context.is_async {
+                error_list.push(ValidationError::ForAwaitOutsideAsync);
+            }
+            binding_pattern_validate(
+                &for_of_statement.element_pattern,
+                function_context,
+                error_list,
+            );
+            expr_validate(
+                &for_of_statement.iterable_expr,
+                function_context,
+                error_list,
+            );
+            statement_list_validate(
+                &for_of_statement.statement_list,
+                function_context,
+                error_list,
+            );
+        }
+        data::Statement::WhileTrue(statement_list) => {
+            statement_list_validate(statement_list, function_context, error_list)
+        }
+        data::Statement::Switch(switch_statement) => {
+            switch_validate(switch_statement, function_context, error_list)
+        }
+        data::Statement::ForLoop(for_loop_statement) => {
+            match &for_loop_statement.init {
+                Some(data::ForInit::VariableDefinition(variable_definition)) => {
+                    variable_definition_validate(variable_definition, function_context, error_list)
+                }
+                Some(data::ForInit::Set(set_statement)) => {
+                    set_statement_validate(set_statement, function_context, error_list)
+                }
+                Some(data::ForInit::Expr(expr)) => {
+                    expr_validate(expr, function_context, error_list)
+                }
+                None => {}
+            }
+            if let Some(test) = &for_loop_statement.test {
+                expr_validate(test, function_context, error_list);
+            }
+            match &for_loop_statement.update {
+                Some(data::ForUpdate::Set(set_statement)) => {
+                    set_statement_validate(set_statement, function_context, error_list)
+                }
+                Some(data::ForUpdate::Expr(expr)) => {
+                    expr_validate(expr, function_context, error_list)
+                }
+                None => {}
+            }
+            statement_list_validate(
+                &for_loop_statement.statement_list,
+                function_context,
+                error_list,
+            );
+        }
+        data::Statement::While(while_statement) | data::Statement::DoWhile(while_statement) => {
+            expr_validate(&while_statement.condition, function_context, error_list);
+            statement_list_validate(
+                &while_statement.statement_list,
+                function_context,
+                error_list,
+            );
+        }
+        data::Statement::ForIn(for_in_statement) => {
+            identifer_validate(&for_in_statement.key_variable_name, error_list);
+            expr_validate(&for_in_statement.object_expr, function_context, error_list);
+            statement_list_validate(
+                &for_in_statement.statement_list,
+                function_context,
+                error_list,
+            );
+        }
+        data::Statement::Labeled(labeled_statement) => {
+            identifer_validate(&labeled_statement.label, error_list);
+            // `label: const a = 0;` は構文エラーになる
+            if crate::to_string::is_declaration(&labeled_statement.statement) {
+                error_list.push(ValidationError::LabeledDeclaration(
+                    labeled_statement.label.get(),
+                ));
+            }
+            statement_validate(&labeled_statement.statement, function_context, error_list);
+        }
+        data::Statement::LabeledBreak(label) | data::Statement::LabeledContinue(label) => {
+            identifer_validate(label, error_list)
+        }
+        data::Statement::Commented(commented_statement) => {
+            statement_validate(&commented_statement.statement, function_context, error_list)
+        }
+    }
+}
+
+fn switch_validate(
+    switch_statement: &data::SwitchStatement,
+    function_context: FunctionContext,
+    error_list: &mut Vec<ValidationError>,
+) {
+    expr_validate(&switch_statement.expr, function_context, error_list);
+    for pattern in &switch_statement.pattern_list {
+        if pattern.case_list.is_empty() {
+            error_list.push(ValidationError::EmptySwitchCaseList);
+        }
+        for case_expr in &pattern.case_list {
+            expr_validate(case_expr, function_context, error_list);
+        }
+        statement_list_validate(&pattern.statement_list, function_context, error_list);
+    }
+    if let Some(default_statement_list) = &switch_statement.default_statement_list {
+        statement_list_validate(default_statement_list, function_context, error_list);
+    }
+    if let Some(exhaustive_type) = &switch_statement.exhaustive_type {
+        type_validate(exhaustive_type, error_list);
+        // default 節で `const exhaustiveCheck: never = expr;` のように もう一度評価する
+        if !is_narrowable_reference(&switch_statement.expr) {
+            error_list.push(ValidationError::NonReferenceExhaustiveSwitch);
+        }
+        if switch_statement
+            .default_statement_list
+            .iter()
+            .flatten()
+            .flat_map(declared_name_list)
+            .any(|name| name == "exhaustiveCheck")
+        {
+            error_list.push(ValidationError::ExhaustiveCheckRedeclared);
+        }
+        // default 節があっても 型の値はすべて case で扱われている必要がある
+        match string_literal_list(exhaustive_type) {
+            Some(value_list) => {
+                let missing_list = value_list
+                    .into_iter()
+                    .filter(|value| {
+                        !switch_statement.pattern_list.iter().any(|pattern| {
+                            pattern.case_list.iter().any(|case_expr| {
+                                matches!(case_expr, data::Expr::StringLiteral(case_string) if case_string == *value)
+                            })
+                        })
+                    })
+                    .cloned()
+                    .collect::<Vec<String>>();
+                if !missing_list.is_empty() {
+                    error_list.push(ValidationError::NonExhaustiveSwitch(missing_list));
+                }
+            }
+            None => error_list.push(ValidationError::InvalidExhaustiveType),
+        }
+    }
+}
+
+/// TypeScript で型を絞り込める 副作用のない参照かどうか. 変数か, 文字列か数値のキーで取得したプロパティ `a.b[0]`
+fn is_narrowable_reference(expr: &data::Expr) -> bool {
+    match expr {
+        data::Expr::Variable(_) => true,
+        data::Expr::Get(get_expr) => {
+            matches!(
+                get_expr.property_expr,
+                data::Expr::StringLiteral(_) | data::Expr::NumberLiteral(_)
+            ) && is_narrowable_reference(&get_expr.expr)
+        }
+        _ => false,
+    }
+}
+
+/// 文で定義される変数や関数の名前. ブロックの中で定義されるものは含まない
+fn declared_name_list(statement: &data::Statement) -> Vec<String> {
+    match statement {
+        data::Statement::VariableDefinition(variable_definition) => {
+            binding_pattern_name_list(&variable_definition.pattern)
+        }
+        data::Statement::FunctionDefinition(function_definition) => {
+            vec![function_definition.name.get()]
+        }
+        data::Statement::Commented(commented_statement) => {
+            declared_name_list(&commented_statement.statement)
+        }
+        _ => vec![],
+    }
+}
+
+fn binding_pattern_name_list(binding_pattern: &data::BindingPattern) -> Vec<String> {
+    match binding_pattern {
+        data::BindingPattern::Identifer(identifer) => vec![identifer.get()],
+        data::BindingPattern::Object(object_pattern) => object_pattern
+            .member_list
+            .iter()
+            .flat_map(|member| binding_pattern_name_list(&member.pattern))
+            .chain(object_pattern.rest.iter().map(|rest| rest.get()))
+            .collect(),
+        data::BindingPattern::Array(array_pattern) => array_pattern
+            .item_list
+            .iter()
+            .flatten()
+            .flat_map(|item| binding_pattern_name_list(&item.pattern))
+            .chain(array_pattern.rest.iter().map(|rest| rest.get()))
+            .collect(),
+    }
+}
+
+/// 文字列リテラル型かそのユニオン型の値. それ以外の型なら `None`
+fn string_literal_list(r#type: &data::Type) -> Option<Vec<&String>> {
+    match r#type {
+        data::Type::StringLiteral(value) => Some(vec![value]),
+        data::Type::Union(type_list) => {
+            let mut value_list = Vec::new();
+            for r#type in type_list.iter() {
+                value_list.extend(string_literal_list(r#type)?);
+            }
+            Some(value_list)
+        }
+        _ => None,
+    }
+}
+
+fn variable_definition_validate(
+    variable_definition: &data::VariableDefinitionStatement,
+    function_context: FunctionContext,
+    error_list: &mut Vec<ValidationError>,
+) {
+    binding_pattern_validate(&variable_definition.pattern, function_context, error_list);
+    type_validate(&variable_definition.r#type, error_list);
+    expr_validate(&variable_definition.expr, function_context, error_list);
+}
+
+fn set_statement_validate(
+    set_statement: &data::SetStatement,
+    function_context: FunctionContext,
+    error_list: &mut Vec<ValidationError>,
+) {
+    if crate::to_string::is_optional_chain(&set_statement.target) {
+        error_list.push(ValidationError::OptionalChainAssignment);
+    }
+    if let Some(operator) = &set_statement.operator_maybe {
+        if !has_compound_assignment(operator) {
+            error_list.push(ValidationError::InvalidCompoundAssignmentOperator(
+                crate::to_string::binary_operator_to_string(operator),
+            ));
+        }
+    }
+    expr_validate(&set_statement.target, function_context, error_list);
+    expr_validate(&set_statement.expr, function_context, error_list);
+}
+
+/// 関数の本体. ラベルは関数の外側からは参照できないので, 関数ごとにラベルを検証する.
+/// `yield` と `for await` を使えるかは 一番内側の関数で決まる
+fn function_body_validate(
+    statement_list: &[data::Statement],
+    function_context: FunctionContext,
+    error_list: &mut Vec<ValidationError>,
+) {
+    statement_list_validate(statement_list, function_context, error_list);
+    for statement in statement_list {
+        label_validate(statement, &mut Vec::new(), error_list);
+    }
+}
+
+/// `LabeledBreak` と `LabeledContinue` のラベルが 外側の文に付けられているかを検証する.
+/// `label_list` は外側のラベルと, それがループに付けられているかどうか
+fn label_validate(
+    statement: &data::Statement,
+    label_list: &mut Vec<(String, bool)>,
+    error_list: &mut Vec<ValidationError>,
+) {
+    let statement_list: &[data::Statement] = match statement {
+        data::Statement::Labeled(labeled_statement) => {
+            label_list.push((
+                labeled_statement.label.get(),
+                is_loop(&labeled_statement.statement),
+            ));
+            label_validate(&labeled_statement.statement, label_list, error_list);
+            label_list.pop();
+            return;
+        }
+        data::Statement::LabeledBreak(label) => {
+            if !label_list.iter().any(|(name, _)| *name == label.get()) {
+                error_list.push(ValidationError::UndefinedLabel(label.get()));
+            }
+            return;
+        }
+        data::Statement::LabeledContinue(label) => {
+            match label_list
+                .iter()
+                .rev()
+                .find(|(name, _)| *name == label.get())
+            {
+                Some((_, true)) => {}
+                Some((_, false)) => {
+                    error_list.push(ValidationError::ContinueToNonLoopLabel(label.get()))
+                }
+                None => error_list.push(ValidationError::UndefinedLabel(label.get())),
+            }
+            return;
+        }
+        data::Statement::If(if_statement) => &if_statement.then_statement_list,
+        data::Statement::For(for_statement) => &for_statement.statement_list,
+        data::Statement::ForOf(for_of_statement) => &for_of_statement.statement_list,
+        data::Statement::WhileTrue(statement_list) => statement_list,
+        data::Statement::ForLoop(for_loop_statement) => &for_loop_statement.statement_list,
+        data::Statement::While(while_statement) | data::Statement::DoWhile(while_statement) => {
+            &while_statement.statement_list
+        }
+        data::Statement::ForIn(for_in_statement) => &for_in_statement.statement_list,
+        data::Statement::Commented(commented_statement) => {
+            label_validate(&commented_statement.statement, label_list, error_list);
+            return;
+        }
+        data::Statement::Switch(switch_statement) => {
+            for statement in switch_statement
+                .pattern_list
+                .iter()
+                .flat_map(|pattern| &pattern.statement_list)
+                .chain(switch_statement.default_statement_list.iter().flatten())
+            {
+                label_validate(statement, label_list, error_list);
+            }
+            return;
+        }
+        _ => return,
+    };
+    for statement in statement_list {
+        label_validate(statement, label_list, error_list);
+    }
+}
+
+/// `continue` の対象にできるループの文かどうか
+fn is_loop(statement: &data::Statement) -> bool {
+    match statement {
+        data::Statement::For(_)
+        | data::Statement::ForOf(_)
+        | data::Statement::WhileTrue(_)
+        | data::Statement::ForLoop(_)
+        | data::Statement::While(_)
+        | data::Statement::DoWhile(_)
+        | data::Statement::ForIn(_) => true,
+        data::Statement::Labeled(labeled_statement) => is_loop(&labeled_statement.statement),
+        data::Statement::Commented(commented_statement) => is_loop(&commented_statement.statement),
+        _ => false,
+    }
+}
+
+/// `a += b` のような複合代入演算子がある演算子かどうか. 比較演算子などは `a <= b` のように別の式になってしまう
+fn has_compound_assignment(operator: &data::BinaryOperator) -> bool {
+    match operator {
+        data::BinaryOperator::Exponentiation
+        | data::BinaryOperator::Multiplication
+        | data::BinaryOperator::Division
+        | data::BinaryOperator::Remainder
+        | data::BinaryOperator::Addition
+        | data::BinaryOperator::Subtraction
+        | data::BinaryOperator::LeftShift
+        | data::BinaryOperator::SignedRightShift
+        | data::BinaryOperator::UnsignedRightShift
+        | data::BinaryOperator::BitwiseAnd
+        | data::BinaryOperator::BitwiseXOr
+        | data::BinaryOperator::BitwiseOr
+        | data::BinaryOperator::LogicalAnd
+        | data::BinaryOperator::LogicalOr
+        | data::BinaryOperator::NullishCoalescing => true,
+        data::BinaryOperator::LessThan
+        | data::BinaryOperator::LessThanOrEqual
+        | data::BinaryOperator::GreaterThan
+        | data::BinaryOperator::GreaterThanOrEqual
+        | data::BinaryOperator::In
+        | data::BinaryOperator::InstanceOf
+        | data::BinaryOperator::Equal
+        | data::BinaryOperator::NotEqual
+        | data::BinaryOperator::LooseEqual
+        | data::BinaryOperator::LooseNotEqual
+        | data::BinaryOperator::Comma => false,
+    }
+}
+
+fn expr_validate(
+    expr: &data::Expr,
+    function_context: FunctionContext,
+    error_list: &mut Vec<ValidationError>,
+) {
+    match expr {
+        data::Expr::NumberLiteral(_)
+        | data::Expr::StringLiteral(_)
+        | data::Expr::BooleanLiteral(_)
+        | data::Expr::NullLiteral
+        | data::Expr::UndefinedLiteral
+        | data::Expr::This => {}
+        data::Expr::UnaryOperator(unary_operator_expr) => {
+            match unary_operator_expr.operator {
+                data::UnaryOperator::PrefixIncrement
+                | data::UnaryOperator::PrefixDecrement
+                | data::UnaryOperator::PostfixIncrement
+                | data::UnaryOperator::PostfixDecrement => {
+                    if !is_update_operand(&unary_operator_expr.expr) {
+                        error_list.push(ValidationError::InvalidUpdateOperand);
+                    }
+                }
+                data::UnaryOperator::Delete => {
+                    if matches!(
+                        unary_operator_expr.expr,
+                        data::Expr::Variable(_) | data::Expr::GlobalObjects(_)
+                    ) {
+                        error_list.push(ValidationError::DeleteVariable);
+                    }
+                }
+                data::UnaryOperator::Minus
+                | data::UnaryOperator::BitwiseNot
+                | data::UnaryOperator::LogicalNot
+                | data::UnaryOperator::Plus
+                | data::UnaryOperator::TypeOf
+                | data::UnaryOperator::Void => {}
+            }
+            expr_validate(&unary_operator_expr.expr, function_context, error_list)
+        }
+        data::Expr::BinaryOperator(binary_operator_expr) => {
+            expr_validate(&binary_operator_expr.left, function_context, error_list);
+            expr_validate(&binary_operator_expr.right, function_context, error_list);
+        }
+        data::Expr::ConditionalOperator(conditional_operator_expr) => {
+            expr_validate(
+                &conditional_operator_expr.condition,
+                function_context,
+                error_list,
+            );
+            expr_validate(
+                &conditional_operator_expr.then_expr,
+                function_context,
+                error_list,
+            );
+            expr_validate(
+                &conditional_operator_expr.else_expr,
+                function_context,
+                error_list,
+            );
+        }
+        data::Expr::ArrayLiteral(item_list) => {
+            for item in item_list.iter() {
+                expr_validate(&item.expr, function_context, error_list);
+            }
+        }
+        data::Expr::ObjectLiteral(member_list) => {
+            for member in member_list.iter() {
+                member_validate(member, function_context, error_list);
+            }
+        }
+        data::Expr::Lambda(lambda_expr) => {
+            lambda_parameter_list_validate(&lambda_expr.parameter_list, error_list);
+            identifer_list_validate(&lambda_expr.type_parameter_list, error_list);
+            type_validate(&lambda_expr.return_type, error_list);
+            function_body_validate(
+                &lambda_expr.statement_list,
+                FunctionContext {
+                    is_async: lambda_expr.is_async,
+                    is_generator: lambda_expr.is_generator,
+                },
+                error_list,
+            );
+        }
+        data::Expr::Variable(identifer) | data::Expr::GlobalObjects(identifer) => {
+            identifer_validate(identifer, error_list)
+        }
+        data::Expr::ImportedVariable(imported_variable) => {
+            identifer_validate(&imported_variable.name, error_list)
+        }
+        data::Expr::ProjectVariable(project_reference) => {
+            identifer_validate(&project_reference.name, error_list)
+        }
+        data::Expr::Get(get_expr) => {
+            expr_validate(&get_expr.expr, function_context, error_list);
+            expr_validate(&get_expr.property_expr, function_context, error_list);
+        }
+        data::Expr::Call(call_expr) | data::Expr::New(call_expr) => {
+            if matches!(expr, data::Expr::New(_)) && call_expr.is_optional_chain {
+                error_list.push(ValidationError::OptionalChainInNew);
+            }
+            expr_validate(&call_expr.expr, function_context, error_list);
+            for parameter in &call_expr.parameter_list {
+                expr_validate(&parameter.expr, function_context, error_list);
+            }
+        }
+        data::Expr::Yield(yield_expr) => {
+            if !function_context.is_generator {
+                error_list.push(ValidationError::YieldOutsideGenerator);
+            }
+            if let Some(expr) = &yield_expr.expr {
+                expr_validate(expr, function_context, error_list);
+            }
+        }
+        data::Expr::Await(expr) => {
+            if !function_context.is_async {
+                error_list.push(ValidationError::AwaitOutsideAsync);
+            }
+            expr_validate(expr, function_context, error_list);
+        }
+        data::Expr::TypeAssertion(type_assertion) => {
+            expr_validate(&type_assertion.expr, function_context, error_list);
+            type_validate(&type_assertion.r#type, error_list);
+        }
+        data::Expr::Class(class_expr) => class_validate(class_expr, function_context, error_list),
+        data::Expr::RegExpLiteral(regexp) => regexp_validate(regexp, error_list),
+    }
+}
+
+/// `++` や `--` の対象にできる式かどうか. 変数か, optional chaining を含まないプロパティの参照
+fn is_update_operand(expr: &data::Expr) -> bool {
+    match expr {
+        data::Expr::Variable(_) => true,
+        data::Expr::Get(_) => !crate::to_string::is_optional_chain(expr),
+        _ => false,
+    }
+}
+
+fn member_validate(
+    member: &data::Member,
+    function_context: FunctionContext,
+    error_list: &mut Vec<ValidationError>,
+) {
+    match member {
+        data::Member::Spread(expr) => expr_validate(expr, function_context, error_list),
+        data::Member::KeyValue(key_value) => {
+            expr_validate(&key_value.value, function_context, error_list)
+        }
+        data::Member::ComputedKeyValue(computed_key_value) => {
+            expr_validate(&computed_key_value.key_expr, function_context, error_list);
+            expr_validate(&computed_key_value.value, function_context, error_list);
+        }
+        data::Member::Method(method) => method_validate(method, function_context, error_list),
+        data::Member::Getter(getter) => getter_validate(getter, function_context, error_list),
+        data::Member::Setter(setter) => setter_validate(setter, function_context, error_list),
+        data::Member::Commented(commented_member) => {
+            member_validate(&commented_member.member, function_context, error_list)
+        }
+    }
+}
+
+/// メソッドを検証する. `function_context` はキーの式を検証するときに使う
+fn method_validate(
+    method: &data::ObjectMethod,
+    function_context: FunctionContext,
+    error_list: &mut Vec<ValidationError>,
+) {
+    property_key_validate(&method.key, function_context, error_list);
+    identifer_list_validate(&method.type_parameter_list, error_list);
+    lambda_parameter_list_validate(&method.parameter_list, error_list);
+    type_validate(&method.return_type, error_list);
+    function_body_validate(
+        &method.statement_list,
+        FunctionContext {
+            is_async: method.is_async,
+            is_generator: method.is_generator,
+        },
+        error_list,
+    );
+}
+
+fn getter_validate(
+    getter: &data::ObjectGetter,
+    function_context: FunctionContext,
+    error_list: &mut Vec<ValidationError>,
+) {
+    property_key_validate(&getter.key, function_context, error_list);
+    type_validate(&getter.return_type, error_list);
+    function_body_validate(&getter.statement_list, FunctionContext::OUTSIDE, error_list);
+}
+
+fn setter_validate(
+    setter: &data::ObjectSetter,
+    function_context: FunctionContext,
+    error_list: &mut Vec<ValidationError>,
+) {
+    property_key_validate(&setter.key, function_context, error_list);
+    // セッターのパラメーターは省略できず, 残余パラメーターにもできない
+    if matches!(
+        setter.parameter.kind,
+        data::ParameterKind::Optional | data::ParameterKind::Rest
+    ) {
+        error_list.push(ValidationError::InvalidSetterParameter);
+    }
+    lambda_parameter_list_validate(std::slice::from_ref(&setter.parameter), error_list);
+    function_body_validate(&setter.statement_list, FunctionContext::OUTSIDE, error_list);
+}
+
+/// クラス式を検証する. `extends` とキーの式は クラスの外側の関数の中として検証する.
+/// フィールドの初期値とコンストラクタは 関数の外側として検証する
+fn class_validate(
+    class_expr: &data::ClassExpr,
+    function_context: FunctionContext,
+    error_list: &mut Vec<ValidationError>,
+) {
+    if let Some(name) = &class_expr.name {
+        identifer_validate(name, error_list);
+    }
+    if let Some(extends) = &class_expr.extends {
+        expr_validate(extends, function_context, error_list);
+    }
+    if let Some(constructor) = &class_expr.constructor {
+        lambda_parameter_list_validate(&constructor.parameter_list, error_list);
+        match (&class_expr.extends, &constructor.super_parameter_list) {
+            (Some(_), None) => error_list.push(ValidationError::MissingSuperCall),
+            (None, Some(_)) => error_list.push(ValidationError::SuperCallWithoutExtends),
+            (Some(_), Some(_)) | (None, None) => {}
+        }
+        for parameter in constructor.super_parameter_list.iter().flatten() {
+            expr_validate(&parameter.expr, FunctionContext::OUTSIDE, error_list);
+        }
+        function_body_validate(
+            &constructor.statement_list,
+            FunctionContext::OUTSIDE,
+            error_list,
+        );
+    }
+    for member in &class_expr.member_list {
+        let key = match &member.kind {
+            data::ClassMemberKind::Field(field) => &field.key,
+            data::ClassMemberKind::Method(method) => &method.key,
+            data::ClassMemberKind::Getter(getter) => &getter.key,
+            data::ClassMemberKind::Setter(setter) => &setter.key,
+        };
+        if member.is_static && *key == data::PropertyKey::Name(String::from("prototype")) {
+            error_list.push(ValidationError::StaticPrototypeMember);
+        }
+        match &member.kind {
+            data::ClassMemberKind::Field(field) => {
+                property_key_validate(&field.key, function_context, error_list);
+                type_validate(&field.r#type, error_list);
+                if let Some(value) = &field.value {
+                    expr_validate(value, FunctionContext::OUTSIDE, error_list);
+                }
+            }
+            data::ClassMemberKind::Method(method) => {
+                method_validate(method, function_context, error_list)
+            }
+            data::ClassMemberKind::Getter(getter) => {
+                getter_validate(getter, function_context, error_list)
+            }
+            data::ClassMemberKind::Setter(setter) => {
+                setter_validate(setter, function_context, error_list)
+            }
+        }
+    }
+}
+
+/// 正規表現リテラルのフラグとパターンを検証する. フラグが正しくない場合は パターンの解釈が決まらないので検証しない
+fn regexp_validate(regexp: &data::RegExpLiteral, error_list: &mut Vec<ValidationError>) {
+    let mut flag_list: Vec<char> = Vec::new();
+    for flag in regexp.flags.chars() {
+        if !"dgimsuvy".contains(flag) || flag_list.contains(&flag) {
+            error_list.push(ValidationError::InvalidRegExpFlags(regexp.flags.clone()));
+            return;
+        }
+        flag_list.push(flag);
+    }
+    if flag_list.contains(&'u') && flag_list.contains(&'v') {
+        error_list.push(ValidationError::InvalidRegExpFlags(regexp.flags.clone()));
+        return;
+    }
+    // `d`, `g`, `y` はパターンの構文に影響しないので regress には渡さない
+    let pattern_flags: String = flag_list
+        .iter()
+        .filter(|flag| "imsuv".contains(**flag))
+        .collect();
+    if let Err(error) = regress::Regex::with_flags(&regexp.pattern, pattern_flags.as_str()) {
+        error_list.push(ValidationError::InvalidRegExpPattern(
+            regexp.pattern.clone(),
+            error.text,
+        ));
+    }
+}
+
+fn binding_pattern_validate(
+    binding_pattern: &data::BindingPattern,
+    function_context: FunctionContext,
+    error_list: &mut Vec<ValidationError>,
+) {
+    match binding_pattern {
+        data::BindingPattern::Identifer(identifer) => identifer_validate(identifer, error_list),
+        data::BindingPattern::Object(object_pattern) => {
+            for member in &object_pattern.member_list {
+                binding_pattern_validate(&member.pattern, function_context, error_list);
+                if let Some(default_expr) = &member.default_expr {
+                    expr_validate(default_expr, function_context, error_list);
+                }
+            }
+            if let Some(rest) = &object_pattern.rest {
+                identifer_validate(rest, error_list);
+            }
+        }
+        data::BindingPattern::Array(array_pattern) => {
+            for item in array_pattern.item_list.iter().flatten() {
+                binding_pattern_validate(&item.pattern, function_context, error_list);
+                if let Some(default_expr) = &item.default_expr {
+                    expr_validate(default_expr, function_context, error_list);
+                }
+            }
+            if let Some(rest) = &array_pattern.rest {
+                identifer_validate(rest, error_list);
+            }
+        }
+    }
+}
+
+fn type_validate(r#type: &data::Type, error_list: &mut Vec<ValidationError>) {
+    match r#type {
+        data::Type::Number
+        | data::Type::String
+        | data::Type::Boolean
+        | data::Type::Undefined
+        | data::Type::Null
+        | data::Type::Never
+        | data::Type::Void
+        | data::Type::StringLiteral(_) => {}
+        data::Type::Object(member_list) => {
+            for member in member_list.iter() {
+                type_validate(&member.r#type, error_list);
+            }
+        }
+        data::Type::Function(function_type) => {
+            identifer_list_validate(&function_type.type_parameter_list, error_list);
+            for parameter in function_type
+                .parameter_list
+                .iter()
+                .chain(&function_type.optional_parameter_list)
+                .chain(&function_type.rest_parameter)
+            {
+                type_validate(parameter, error_list);
+            }
+            type_validate(&function_type.return_type, error_list);
+        }
+        data::Type::WithTypeParameter(type_with_type_parameter) => {
+            type_validate(&type_with_type_parameter.r#type, error_list);
+            for parameter in &type_with_type_parameter.type_parameter_list {
+                type_validate(parameter, error_list);
+            }
+        }
+        data::Type::Union(type_list) => {
+            for r#type in type_list.iter() {
+                type_validate(r#type, error_list);
+            }
+        }
+        data::Type::Intersection(intersection_type) => {
+            type_validate(&intersection_type.left, error_list);
+            type_validate(&intersection_type.right, error_list);
+        }
+        data::Type::ImportedType(imported_type) => {
+            identifer_validate(&imported_type.name, error_list)
+        }
+        data::Type::ProjectType(project_reference) => {
+            identifer_validate(&project_reference.name, error_list)
+        }
+        data::Type::ScopeInFile(identifer) | data::Type::ScopeInGlobal(identifer) => {
+            identifer_validate(identifer, error_list)
+        }
+    }
+}
+
+#[test]
+fn test_validate() {
+    assert_eq!(validate(&crate::sample_code()), vec![]);
+
+    let code = crate::json::from_json(
+        r#"{"formatVersion":4,"code":{"exportDefinitionList":[
+            {"Variable":{"name":"a","document":"","type":"Number","expr":{"Variable":"class"}}},
+            {"TypeAlias":{"name":"a","typeParameterList":[],"document":"","type":"Number"}},
+            {"Variable":{"name":"a","document":"","type":"Number","expr":{"NumberLiteral":0}}}
+        ],"statementList":[]}}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        validate(&code),
+        vec![
+            ValidationError::InvalidIdentifer(String::from("class")),
+            ValidationError::DuplicateExportName(String::from("a")),
+        ]
+    );
+}
+
+#[test]
+fn test_validate_default_export() {
+    let code = crate::json::from_json(
+        r#"{"formatVersion":4,"code":{"exportDefinitionList":[
+            {"Default":{"document":"","type":"Number","expr":{"NumberLiteral":0}}},
+            {"ExportFrom":{"moduleName":"./a","nameList":[{"name":"default","alias":null}],"isTypeOnly":false}}
+        ],"statementList":[]}}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        validate(&code),
+        vec![ValidationError::MultipleDefaultExport]
+    );
+}
+
+#[test]
+fn test_validate_reexport_name() {
+    let code = crate::json::from_json(
+        r#"{"formatVersion":4,"code":{"exportDefinitionList":[
+            {"Variable":{"name":"a","document":"","type":"Number","expr":{"NumberLiteral":0},"visibility":"Private"}},
+            {"ExportFrom":{"moduleName":"./x","nameList":[{"name":"a","alias":null}],"isTypeOnly":false}},
+            {"Variable":{"name":"b","document":"","type":"Number","expr":{"NumberLiteral":0}}},
+            {"ExportFrom":{"moduleName":"./x","nameList":[{"name":"c","alias":"b"}],"isTypeOnly":false}},
+            {"Variable":{"name":"d","document":"","type":"Number","expr":{"NumberLiteral":0},"visibility":"Private"}},
+            {"Variable":{"name":"d","document":"","type":"Number","expr":{"NumberLiteral":0},"visibility":"Private"}},
+            {"Variable":{"name":"e","document":"","type":"Number","expr":{"NumberLiteral":0}}},
+            {"Variable":{"name":"e","document":"","type":"Number","expr":{"NumberLiteral":0}}}
+        ],"statementList":[]}}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        validate(&code),
+        vec![
+            ValidationError::DuplicateExportName(String::from("b")),
+            ValidationError::DuplicateExportName(String::from("d")),
+            ValidationError::DuplicateExportName(String::from("e")),
+        ]
+    );
+}
+
+#[test]
+fn test_validate_parameter_kind() {
+    let code = crate::json::from_json(
+        r#"{"formatVersion":4,"code":{"exportDefinitionList":[
+            {"Function":{"name":"f","document":"","typeParameterList":[],"parameterList":[
+                {"name":"a","document":"","type":"String","kind":"Optional"},
+                {"name":"b","document":"","type":"String"},
+                {"name":"c","document":"","type":"String","kind":"Rest"},
+                {"name":"d","document":"","type":"String","kind":{"Default":{"Variable":"class"}}}
+            ],"returnType":"Void","statementList":[]}}
+        ],"statementList":[]}}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        validate(&code),
+        vec![
+            ValidationError::RequiredParameterAfterOptional(1),
+            ValidationError::RestParameterNotLast(2),
+            ValidationError::InvalidIdentifer(String::from("class")),
+        ]
+    );
+}
+
+#[test]
+fn test_validate_optional_chain() {
+    let code = crate::json::from_json(
+        r#"{"formatVersion":4,"code":{"exportDefinitionList":[],"statementList":[
+            {"Set":{"target":{"Get":{"expr":{"Get":{"expr":{"Variable":"a"},"propertyExpr":{"StringLiteral":"b"},
+                "isOptionalChain":true}},"propertyExpr":{"StringLiteral":"c"}}},"operatorMaybe":null,"expr":"NullLiteral"}},
+            {"EvaluateExpr":{"New":{"expr":{"Variable":"A"},"parameterList":[],"isOptionalChain":true}}}
+        ]}}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        validate(&code),
+        vec![
+            ValidationError::OptionalChainAssignment,
+            ValidationError::OptionalChainInNew,
+        ]
+    );
+}
+
+#[test]
+fn test_validate_operator() {
+    use crate::util;
+    let variable = |name: &str| data::Expr::Variable(data::identifer::from_string(name));
+    let set = |operator: data::BinaryOperator| {
+        data::Statement::Set(data::SetStatement {
+            target: variable("a"),
+            operator_maybe: Some(operator),
+            expr: variable("b"),
+        })
+    };
+    let code = data::Code {
+        export_definition_list: vec![],
+        statement_list: vec![
+            set(data::BinaryOperator::Addition),
+            set(data::BinaryOperator::NullishCoalescing),
+            set(data::BinaryOperator::GreaterThan),
+            set(data::BinaryOperator::Equal),
+            set(data::BinaryOperator::In),
+            set(data::BinaryOperator::Comma),
+            data::Statement::EvaluateExpr(util::prefix_increment(variable("a"))),
+            data::Statement::EvaluateExpr(util::postfix_decrement(util::get(variable("a"), "b"))),
+            data::Statement::EvaluateExpr(util::prefix_increment(data::Expr::NumberLiteral(1))),
+            data::Statement::EvaluateExpr(util::postfix_increment(util::addition(
+                variable("a"),
+                variable("b"),
+            ))),
+            data::Statement::EvaluateExpr(util::postfix_decrement(util::call_method(
+                variable("a"),
+                "f",
+                vec![],
+            ))),
+            data::Statement::EvaluateExpr(util::prefix_decrement(util::optional_get(
+                variable("a"),
+                "b",
+            ))),
+            data::Statement::EvaluateExpr(util::delete(util::get(variable("a"), "b"))),
+            data::Statement::EvaluateExpr(util::delete(variable("a"))),
+        ],
+    };
+    assert_eq!(
+        validate(&code),
+        vec![
+            ValidationError::InvalidCompoundAssignmentOperator(String::from(">")),
+            ValidationError::InvalidCompoundAssignmentOperator(String::from("===")),
+            ValidationError::InvalidCompoundAssignmentOperator(String::from("in")),
+            ValidationError::InvalidCompoundAssignmentOperator(String::from(",")),
+            ValidationError::InvalidUpdateOperand,
+            ValidationError::InvalidUpdateOperand,
+            ValidationError::InvalidUpdateOperand,
+            ValidationError::InvalidUpdateOperand,
+            ValidationError::DeleteVariable,
+        ]
+    );
+}
+
+#[test]
+fn test_validate_label() {
+    let code = crate::json::from_json(
+        r#"{"formatVersion":4,"code":{"exportDefinitionList":[],"statementList":[
+            {"Labeled":{"label":"outer","statement":{"WhileTrue":[
+                {"LabeledContinue":"outer"},
+                {"Labeled":{"label":"block","statement":{"If":{"condition":{"Variable":"a"},"thenStatementList":[
+                    {"LabeledBreak":"block"},
+                    {"LabeledContinue":"block"}
+                ]}}}},
+                {"EvaluateExpr":{"Lambda":{"parameterList":[],"typeParameterList":[],"returnType":"Void",
+                    "statementList":[{"LabeledBreak":"outer"}]}}}
+            ]}}},
+            {"LabeledBreak":"outer"}
+        ]}}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        validate(&code),
+        vec![
+            ValidationError::UndefinedLabel(String::from("outer")),
+            ValidationError::ContinueToNonLoopLabel(String::from("block")),
+            ValidationError::UndefinedLabel(String::from("outer")),
+        ]
+    );
+}
+
+#[test]
+fn test_validate_labeled_declaration() {
+    let code = crate::json::from_json(
+        r#"{"formatVersion":4,"code":{"exportDefinitionList":[],"statementList":[
+            {"Labeled":{"label":"a","statement":{"VariableDefinition":{"pattern":{"Identifer":"x"},"type":"Number",
+                "expr":{"NumberLiteral":0},"isConst":true}}}},
+            {"Labeled":{"label":"b","statement":{"Commented":{"leadingCommentList":[{"Line":"関数"}],
+                "statement":{"FunctionDefinition":{"name":"f","typeParameterList":[],"parameterList":[],
+                    "returnType":"Void","statementList":[]}},"trailingComment":null}}}},
+            {"Labeled":{"label":"c","statement":{"EvaluateExpr":{"Variable":"x"}}}}
+        ]}}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        validate(&code),
+        vec![
+            ValidationError::LabeledDeclaration(String::from("a")),
+            ValidationError::LabeledDeclaration(String::from("b")),
+        ]
+    );
+}
+
+#[test]
+fn test_validate_function_context() {
+    let code = crate::json::from_json(
+        r#"{"formatVersion":4,"code":{"exportDefinitionList":[
+            {"Function":{"name":"f","document":"","typeParameterList":[],"parameterList":[],"returnType":"Void",
+                "statementList":[
+                    {"EvaluateExpr":{"Yield":{"expr":null,"isDelegate":false}}},
+                    {"ForOf":{"elementPattern":{"Identifer":"a"},"iterableExpr":{"Variable":"b"},
+                        "statementList":[],"isAwait":true}},
+                    {"EvaluateExpr":{"Await":{"Variable":"p"}}}
+                ],"isGenerator":true}}
+        ],"statementList":[
+            {"EvaluateExpr":{"Yield":{"expr":null,"isDelegate":false}}},
+            {"ForOf":{"elementPattern":{"Identifer":"a"},"iterableExpr":{"Variable":"b"},
+                "statementList":[],"isAwait":true}},
+            {"EvaluateExpr":{"Await":{"Variable":"p"}}},
+            {"EvaluateExpr":{"Lambda":{"parameterList":[],"typeParameterList":[],"returnType":"Void",
+                "statementList":[
+                    {"ForOf":{"elementPattern":{"Identifer":"a"},"iterableExpr":{"Variable":"b"},
+                        "statementList":[],"isAwait":true}},
+                    {"EvaluateExpr":{"Await":{"Variable":"p"}}},
+                    {"EvaluateExpr":{"Lambda":{"parameterList":[],"typeParameterList":[],"returnType":"Void",
+                        "statementList":[
+                            {"EvaluateExpr":{"Yield":{"expr":null,"isDelegate":false}}},
+                            {"EvaluateExpr":{"Await":{"Variable":"p"}}}
+                        ]}}}
+                ],"isAsync":true,"isGenerator":true}}}
+        ]}}"#,
+    )
+    .unwrap();
+    // 内側の関数の中では 外側の関数が async やジェネレーターでも使えない
+    assert_eq!(
+        validate(&code),
+        vec![
+            ValidationError::ForAwaitOutsideAsync,
+            ValidationError::AwaitOutsideAsync,
+            ValidationError::YieldOutsideGenerator,
+            ValidationError::ForAwaitOutsideAsync,
+            ValidationError::AwaitOutsideAsync,
+            ValidationError::YieldOutsideGenerator,
+            ValidationError::AwaitOutsideAsync,
+        ]
+    );
+}
+
+#[test]
+fn test_validate_class() {
+    let code = crate::json::from_json(
+        r#"{"formatVersion":4,"code":{"exportDefinitionList":[
+            {"Variable":{"name":"A","document":"","type":"Number","expr":{"NumberLiteral":0}}},
+            {"Default":{"document":"","type":"Never","expr":{"Class":{"name":"A","extends":{"Variable":"B"},
+                "constructor":{"parameterList":[],"statementList":[]},
+                "memberList":[
+                    {"isStatic":true,"kind":{"Field":{"key":{"Name":"prototype"},"type":"Number",
+                        "value":{"Await":{"Variable":"p"}}}}},
+                    {"kind":{"Method":{"key":{"Name":"prototype"},"typeParameterList":[],"parameterList":[],
+                        "returnType":"Void","statementList":[{"EvaluateExpr":{"Await":{"Variable":"p"}}}],"isAsync":true}}}
+                ]}}}}
+        ],"statementList":[
+            {"EvaluateExpr":{"Class":{"constructor":{"parameterList":[],"superParameterList":[],"statementList":[]},
+                "memberList":[]}}}
+        ]}}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        validate(&code),
+        vec![
+            ValidationError::DuplicateExportName(String::from("A")),
+            ValidationError::MissingSuperCall,
+            ValidationError::StaticPrototypeMember,
+            ValidationError::AwaitOutsideAsync,
+            ValidationError::SuperCallWithoutExtends,
+        ]
+    );
+}
+
+#[test]
+fn test_validate_switch() {
+    let code = crate::json::from_json(
+        r#"{"formatVersion":4,"code":{"exportDefinitionList":[],"statementList":[
+            {"Switch":{"expr":{"Variable":"a"},"patternList":[
+                {"caseList":[],"statementList":[]},
+                {"caseList":[{"StringLiteral":"x"}],"statementList":[]}
+            ],"exhaustiveType":{"Union":[{"StringLiteral":"x"},{"StringLiteral":"y"},{"StringLiteral":"z"}]}}},
+            {"Switch":{"expr":{"Variable":"a"},"patternList":[],"exhaustiveType":"String"}},
+            {"Switch":{"expr":{"Get":{"expr":{"Variable":"a"},"propertyExpr":{"StringLiteral":"kind"}}},
+                "patternList":[{"caseList":[{"StringLiteral":"x"}],"statementList":[]}],
+                "exhaustiveType":{"StringLiteral":"x"}}},
+            {"Switch":{"expr":{"Call":{"expr":{"Variable":"f"},"parameterList":[]}},
+                "patternList":[{"caseList":[{"StringLiteral":"x"}],"statementList":[]}],
+                "defaultStatementList":[{"VariableDefinition":{"pattern":{"Identifer":"exhaustiveCheck"},
+                    "type":"Number","expr":{"NumberLiteral":0},"isConst":true}}],
+                "exhaustiveType":{"StringLiteral":"x"}}}
+        ]}}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        validate(&code),
+        vec![
+            ValidationError::EmptySwitchCaseList,
+            ValidationError::NonExhaustiveSwitch(vec![String::from("y"), String::from("z")]),
+            ValidationError::InvalidExhaustiveType,
+            ValidationError::NonReferenceExhaustiveSwitch,
+            ValidationError::ExhaustiveCheckRedeclared,
+        ]
+    );
+}
+
+#[test]
+fn test_validate_regexp() {
+    let regexp = |pattern: &str, flags: &str| {
+        data::Statement::EvaluateExpr(crate::util::regexp(pattern, flags))
+    };
+    let code = data::Code {
+        export_definition_list: vec![],
+        statement_list: vec![
+            regexp("^(?<year>\\d{4})-\\k<year>/[/\\]]$", "dgimsy"),
+            regexp("\\p{Script=Greek}", "u"),
+            regexp("[\\p{L}--[a-z]]", "v"),
+            regexp("a(", ""),
+            regexp("\\p{Foo}", "u"),
+            regexp("a", "gg"),
+            regexp("a", "x"),
+            regexp("a", "uv"),
+        ],
+    };
+    assert_eq!(
+        validate(&code),
+        vec![
+            ValidationError::InvalidRegExpPattern(
+                String::from("a("),
+                String::from("Unbalanced parenthesis")
+            ),
+            ValidationError::InvalidRegExpPattern(
+                String::from("\\p{Foo}"),
+                String::from("Invalid property name")
+            ),
+            ValidationError::InvalidRegExpFlags(String::from("gg")),
+            ValidationError::InvalidRegExpFlags(String::from("x")),
+            ValidationError::InvalidRegExpFlags(String::from("uv")),
+        ]
+    );
+}
+
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValidationError::InvalidIdentifer(word) => {
+                write!(f, "{:?} cannot be used as an identifier", word)
+            }
+            ValidationError::DuplicateExportName(name) => {
+                write!(f, "{:?} is defined more than once at the top level", name)
+            }
+            ValidationError::MultipleDefaultExport => {
+                write!(f, "default is exported more than once")
+            }
+            ValidationError::NamedExportWithModuleExports => {
+                write!(
+                    f,
+                    "the default export cannot be assigned to module.exports when other values are exported"
+                )
+            }
+            ValidationError::RequiredParameterAfterOptional(index) => {
+                write!(
+                    f,
+                    "parameter {} is required but follows an optional parameter",
+                    index
+                )
+            }
+            ValidationError::RestParameterNotLast(index) => {
+                write!(f, "rest parameter {} must be the last parameter", index)
+            }
+            ValidationError::OptionalChainAssignment => {
+                write!(f, "an optional chain cannot be the target of an assignment")
+            }
+            ValidationError::OptionalChainInNew => {
+                write!(f, "optional chaining cannot be used with new")
+            }
+            ValidationError::InvalidCompoundAssignmentOperator(operator) => {
+                write!(
+                    f,
+                    "{} cannot be used as a compound assignment operator",
+                    operator
+                )
+            }
+            ValidationError::InvalidUpdateOperand => {
+                write!(
+                    f,
+                    "the operand of ++ or -- must be a variable or a property access"
+                )
+            }
+            ValidationError::DeleteVariable => {
+                write!(f, "a variable cannot be deleted in strict mode")
+            }
+            ValidationError::UndefinedLabel(label) => {
+                write!(
+                    f,
+                    "label {:?} is not defined on an enclosing statement",
+                    label
+                )
+            }
+            ValidationError::ContinueToNonLoopLabel(label) => {
+                write!(
+                    f,
+                    "continue cannot target label {:?} which is not a loop",
+                    label
+                )
+            }
+            ValidationError::LabeledDeclaration(label) => {
+                write!(f, "label {:?} cannot be attached to a declaration", label)
+            }
+            ValidationError::EmptySwitchCaseList => {
+                write!(f, "a switch case must have at least one case expression")
+            }
+            ValidationError::InvalidExhaustiveType => {
+                write!(
+                    f,
+                    "the exhaustive type of a switch must be a union of string literal types"
+                )
+            }
+            ValidationError::NonExhaustiveSwitch(missing_list) => {
+                write!(f, "switch does not handle {:?}", missing_list)
+            }
+            ValidationError::InvalidRegExpPattern(pattern, message) => {
+                write!(f, "invalid regular expression /{}/: {}", pattern, message)
+            }
+            ValidationError::InvalidRegExpFlags(flags) => {
+                write!(f, "invalid regular expression flags {:?}", flags)
+            }
+            ValidationError::NonReferenceExhaustiveSwitch => {
+                write!(
+                    f,
+                    "the expression of an exhaustive switch must be a variable or a property access"
+                )
+            }
+            ValidationError::ExhaustiveCheckRedeclared => {
+                write!(
+                    f,
+                    "exhaustiveCheck cannot be defined in the default clause of an exhaustive switch"
+                )
+            }
+            ValidationError::InvalidSetterParameter => {
+                write!(
+                    f,
+                    "a setter parameter cannot be optional or a rest parameter"
+                )
+            }
+            ValidationError::MissingSuperCall => {
+                write!(
+                    f,
+                    "the constructor of a class with extends must call super()"
+                )
+            }
+            ValidationError::SuperCallWithoutExtends => {
+                write!(f, "super() can only be called in a class with extends")
+            }
+            ValidationError::StaticPrototypeMember => {
+                write!(f, "a static class member cannot be named prototype")
+            }
+            ValidationError::YieldOutsideGenerator => {
+                write!(f, "yield can only be used in a generator function")
+            }
+            ValidationError::ForAwaitOutsideAsync => {
+                write!(f, "for await can only be used in an async function")
+            }
+            ValidationError::AwaitOutsideAsync => {
+                write!(f, "await can only be used in an async function")
+            }
+        }
+    }
+}